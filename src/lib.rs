@@ -3,16 +3,22 @@ extern crate duckdb_loadable_macros;
 extern crate libduckdb_sys;
 
 use duckdb::{
-    core::{DataChunkHandle, Inserter, LogicalTypeHandle, LogicalTypeId},
-    vtab::{BindInfo, InitInfo, TableFunctionInfo, VTab},
+    core::{DataChunkHandle, Inserter, ListVector, LogicalTypeHandle, LogicalTypeId},
+    types::DuckString,
+    vscalar::{ScalarFunctionSignature, VScalar},
+    vtab::{arrow::WritableVector, BindInfo, InitInfo, TableFunctionInfo, VTab},
     Connection, Result,
 };
 use duckdb_loadable_macros::duckdb_entrypoint_c_api;
 use libduckdb_sys as ffi;
+use libduckdb_sys::duckdb_string_t;
 use std::{
     error::Error,
     ffi::CString,
-    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
 };
 use sysinfo::{
     System, Disks, Networks, Components, 
@@ -86,24 +92,300 @@ impl SizeUnit {
     }
 }
 
+/// Temperature unit for the `unit` parameter of temperature functions
+#[derive(Clone, Copy, Debug)]
+enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    fn from_str(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "C" | "CELSIUS" | "" => Some(TemperatureUnit::Celsius),
+            "F" | "FAHRENHEIT" => Some(TemperatureUnit::Fahrenheit),
+            "K" | "KELVIN" => Some(TemperatureUnit::Kelvin),
+            _ => None,
+        }
+    }
+
+    fn convert(&self, celsius: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Kelvin => celsius + 273.15,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "C",
+            TemperatureUnit::Fahrenheit => "F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+}
+
+/// Mount points excluded from sazgar_disks() unless overridden with `exclude_mounts`
+const DEFAULT_EXCLUDED_MOUNTS: [&str; 5] = ["/proc", "/sys", "/dev", "/run", "/snap"];
+
+/// Filesystem types excluded from sazgar_disks() unless overridden with `exclude_fs`
+const DEFAULT_EXCLUDED_FS: [&str; 7] = ["proc", "sysfs", "devfs", "devtmpfs", "tmpfs", "overlay", "squashfs"];
+
 /// Check if a mount point should be filtered (virtual filesystem)
 fn is_virtual_filesystem(mount_point: &str, fs_type: &str) -> bool {
-    let virtual_mount_points = ["/proc", "/sys", "/dev", "/run", "/snap"];
-    let virtual_fs_types = ["proc", "sysfs", "devfs", "devtmpfs", "tmpfs", "overlay", "squashfs"];
+    is_excluded_mount(mount_point, fs_type, &DEFAULT_EXCLUDED_MOUNTS, &DEFAULT_EXCLUDED_FS)
+}
+
+/// Check a mount against exclusion lists. Mount prefixes match whole path
+/// components (/run matches /run/user/1000 but not /running_data) and
+/// filesystem types match exactly, ignoring case.
+fn is_excluded_mount<M: AsRef<str>, F: AsRef<str>>(mount_point: &str, fs_type: &str, excluded_mounts: &[M], excluded_fs: &[F]) -> bool {
+    let under_prefix = |prefix: &str| {
+        let prefix = prefix.trim_end_matches('/');
+        prefix.is_empty()
+            || mount_point == prefix
+            || mount_point.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('/'))
+    };
+    
+    excluded_mounts.iter().any(|prefix| under_prefix(prefix.as_ref()))
+        || excluded_fs.iter().any(|fs| fs.as_ref().eq_ignore_ascii_case(fs_type))
+}
+
+/// Split the VARCHAR rendering of a LIST(VARCHAR) parameter, e.g.
+/// `[tmpfs, 'a, b']`, back into its elements. DuckDB quotes an element
+/// only when needed and escapes `'` and `\` inside quotes with a backslash.
+///
+/// The duckdb crate's `Value` does not expose list children, so this is the
+/// only way to read a list at bind time. It relies on DuckDB quoting every
+/// element that contains `,`, `[` or `]`; an unquoted `a, b` reads as two
+/// elements, because the rendering of `['a, b']` and `['a', 'b']` would then
+/// be the same.
+fn parse_varchar_list(rendered: &str) -> Vec<String> {
+    let inner = rendered.trim();
+    let inner = inner.strip_prefix('[').and_then(|s| s.strip_suffix(']')).unwrap_or(inner);
+    let mut items = Vec::new();
+    let mut chars = inner.chars().peekable();
+    
+    loop {
+        while chars.next_if(|c| *c == ' ').is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+        
+        let mut item = String::new();
+        let mut quoted = false;
+        if chars.next_if_eq(&'\'').is_some() {
+            quoted = true;
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => item.extend(chars.next()),
+                    '\'' => break,
+                    _ => item.push(c),
+                }
+            }
+        }
+        for c in chars.by_ref() {
+            if c == ',' {
+                break;
+            }
+            if !quoted {
+                item.push(c);
+            }
+        }
+        
+        // Unquoted NULL elements carry no value to match against
+        if quoted || item != "NULL" {
+            items.push(item);
+        }
+    }
+    
+    items
+}
+
+/// Read a single integer value from a procfs/sysfs file
+#[cfg(target_os = "linux")]
+fn read_sys_u64(path: &str) -> Option<u64> {
+    std::fs::read_to_string(path).ok()?.trim().parse::<u64>().ok()
+}
+
+/// Read the system-wide open file count from /proc/sys/fs/file-nr, which
+/// holds "allocated unused max"; the first field is the open file count
+#[cfg(target_os = "linux")]
+fn read_file_nr() -> Option<u64> {
+    std::fs::read_to_string("/proc/sys/fs/file-nr").ok()?.split_whitespace().next()?.parse::<u64>().ok()
+}
+
+/// Row window selected by the shared `offset`/`limit` named parameters
+#[derive(Clone, Copy)]
+struct Page {
+    offset: usize,
+    limit: Option<usize>,
+}
+
+impl Page {
+    fn from_bind(bind: &BindInfo) -> Self {
+        let read = |name: &str| {
+            bind.get_named_parameter(name)
+                .and_then(|v| v.to_string().parse::<u64>().ok())
+                .map(|n| usize::try_from(n).unwrap_or(usize::MAX))
+        };
+        Page { offset: read("offset").unwrap_or(0), limit: read("limit") }
+    }
+
+    /// Start and end row indices of the page within `total` rows
+    fn bounds(&self, total: usize) -> (usize, usize) {
+        let start = self.offset.min(total);
+        let end = self.limit.map_or(total, |limit| start.saturating_add(limit).min(total));
+        (start, end)
+    }
+
+    fn named_parameters() -> [(String, LogicalTypeHandle); 2] {
+        [
+            ("offset".to_string(), LogicalTypeHandle::from(LogicalTypeId::UBigint)),
+            ("limit".to_string(), LogicalTypeHandle::from(LogicalTypeId::UBigint)),
+        ]
+    }
+}
+
+/// Write a batch of string lists into a VARCHAR[] output column
+fn insert_varchar_lists(list_vector: &mut ListVector, lists: &[&[String]]) -> Result<(), Box<dyn Error>> {
+    let total: usize = lists.iter().map(|list| list.len()).sum();
+    let child = list_vector.child(total);
+    let mut offset = 0;
+    
+    for (row, list) in lists.iter().enumerate() {
+        for (i, value) in list.iter().enumerate() {
+            child.insert(offset + i, CString::new(value.clone())?);
+        }
+        list_vector.set_entry(row, offset, list.len());
+        offset += list.len();
+    }
+    
+    list_vector.set_len(total);
+    Ok(())
+}
+
+/// Write a batch of integer lists into an INTEGER[] (or UBIGINT[] for u64) output column
+fn insert_integer_lists<T: Copy>(list_vector: &mut ListVector, lists: &[&[T]]) {
+    let total: usize = lists.iter().map(|list| list.len()).sum();
+    let mut child = list_vector.child(total);
+    let values = child.as_mut_slice_with_len::<T>(total);
+    let mut offset = 0;
+    
+    for (row, list) in lists.iter().enumerate() {
+        values[offset..offset + list.len()].copy_from_slice(list);
+        list_vector.set_entry(row, offset, list.len());
+        offset += list.len();
+    }
     
-    for vmp in &virtual_mount_points {
-        if mount_point.starts_with(vmp) {
-            return true;
+    list_vector.set_len(total);
+}
+
+/// Get (total, used, available) bytes for the filesystem containing `path`
+#[cfg(unix)]
+fn statvfs_usage(path: &str) -> Option<(u64, u64, u64)> {
+    let c_path = CString::new(path).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    let fragment_size = stat.f_frsize as u64;
+    let total = stat.f_blocks as u64 * fragment_size;
+    let free = stat.f_bfree as u64 * fragment_size;
+    let available = stat.f_bavail as u64 * fragment_size;
+    Some((total, total.saturating_sub(free), available))
+}
+
+/// Get the memory limit and current usage of this process's cgroup (v2, falling back to v1).
+/// The limit is None when the cgroup is unlimited ("max" in v2).
+#[cfg(target_os = "linux")]
+fn read_cgroup_memory() -> Option<(Option<u64>, u64)> {
+    let cgroups = std::fs::read_to_string("/proc/self/cgroup").ok()?;
+    
+    // Inside a cgroup namespace the process's own cgroup is mounted at the root,
+    // so try the full path first and then the mount root
+    let candidates = |mount: &str, path: &str| -> Vec<String> {
+        let path = path.trim_end_matches('/');
+        vec![format!("{}{}", mount, path), mount.to_string()]
+    };
+    
+    // cgroup v2: "0::/path" with memory.max / memory.current
+    if let Some(path) = cgroups.lines().find_map(|line| line.strip_prefix("0::")) {
+        for dir in candidates("/sys/fs/cgroup", path) {
+            if let Ok(max) = std::fs::read_to_string(format!("{}/memory.max", dir)) {
+                let limit = max.trim().parse::<u64>().ok();
+                let usage = read_sys_u64(&format!("{}/memory.current", dir)).unwrap_or(0);
+                return Some((limit, usage));
+            }
+        }
+    }
+    
+    // cgroup v1: "N:memory:/path" with memory.limit_in_bytes / memory.usage_in_bytes
+    let path = cgroups.lines().find_map(|line| {
+        let mut parts = line.splitn(3, ':');
+        let _id = parts.next()?;
+        let controllers = parts.next()?;
+        let path = parts.next()?;
+        controllers.split(',').any(|c| c == "memory").then_some(path)
+    })?;
+    for dir in candidates("/sys/fs/cgroup/memory", path) {
+        if let Some(limit) = read_sys_u64(&format!("{}/memory.limit_in_bytes", dir)) {
+            let usage = read_sys_u64(&format!("{}/memory.usage_in_bytes", dir)).unwrap_or(0);
+            // v1 reports "unlimited" as a huge page-aligned value near i64::MAX
+            let limit = if limit >= (i64::MAX as u64) & !0xFFF { None } else { Some(limit) };
+            return Some((limit, usage));
+        }
+    }
+    
+    None
+}
+
+/// Read /proc/meminfo into a map of field name to bytes (values reported in kB are converted)
+#[cfg(target_os = "linux")]
+fn read_meminfo() -> std::collections::HashMap<String, u64> {
+    let mut fields = std::collections::HashMap::new();
+    if let Ok(content) = std::fs::read_to_string("/proc/meminfo") {
+        for line in content.lines() {
+            if let Some((key, rest)) = line.split_once(':') {
+                let mut parts = rest.split_whitespace();
+                if let Some(value) = parts.next().and_then(|v| v.parse::<u64>().ok()) {
+                    let bytes = if parts.next() == Some("kB") { value * 1024 } else { value };
+                    fields.insert(key.to_string(), bytes);
+                }
+            }
         }
     }
+    fields
+}
+
+/// Get the system commit limit and currently committed memory in bytes
+fn read_commit_charge() -> Option<(u64, u64)> {
+    #[cfg(target_os = "linux")]
+    {
+        let meminfo = read_meminfo();
+        Some((*meminfo.get("CommitLimit")?, *meminfo.get("Committed_AS")?))
+    }
     
-    for vfs in &virtual_fs_types {
-        if fs_type.to_lowercase().contains(vfs) {
-            return true;
+    #[cfg(windows)]
+    {
+        use windows_sys::Win32::System::ProcessStatus::{GetPerformanceInfo, PERFORMANCE_INFORMATION};
+        
+        let mut info: PERFORMANCE_INFORMATION = unsafe { std::mem::zeroed() };
+        let size = std::mem::size_of::<PERFORMANCE_INFORMATION>() as u32;
+        if unsafe { GetPerformanceInfo(&mut info, size) } == 0 {
+            return None;
         }
+        // Commit values are reported in pages
+        let page_size = info.PageSize as u64;
+        Some((info.CommitLimit as u64 * page_size, info.CommitTotal as u64 * page_size))
     }
     
-    false
+    #[cfg(not(any(target_os = "linux", windows)))]
+    {
+        None
+    }
 }
 
 /// Get system byte order
@@ -137,6 +419,64 @@ struct CpuInfo {
     frequency_mhz: u64,
     brand: String,
     vendor_id: String,
+    features: Vec<String>,
+    /// None where cpufreq is not present
+    min_frequency_mhz: Option<u64>,
+    max_frequency_mhz: Option<u64>,
+    base_frequency_mhz: Option<u64>,
+}
+
+/// Read a cpufreq attribute for one CPU, None where cpufreq is absent
+#[cfg(target_os = "linux")]
+fn read_cpufreq_attribute(cpu: usize, attribute: &str) -> Option<String> {
+    std::fs::read_to_string(format!("/sys/devices/system/cpu/cpu{}/cpufreq/{}", cpu, attribute))
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Read a cpufreq frequency attribute for one CPU, converting kHz to MHz
+#[cfg(target_os = "linux")]
+fn read_cpufreq_mhz(cpu: usize, attribute: &str) -> Option<u64> {
+    read_cpufreq_attribute(cpu, attribute)
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(|khz| khz / 1000)
+}
+
+/// Split /proc/cpuinfo into one feature list per processor block, from the
+/// "flags" line on x86 or the "Features" line on ARM
+#[cfg(target_os = "linux")]
+fn parse_cpuinfo_features(cpuinfo: &str) -> Vec<Vec<String>> {
+    cpuinfo.split("\n\n")
+        .filter_map(|block| {
+            block.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                matches!(key.trim(), "flags" | "Features")
+                    .then(|| value.split_whitespace().map(|flag| flag.to_string()).collect())
+            })
+        })
+        .collect()
+}
+
+/// Probe common x86 features at runtime, named as Linux's cpuinfo flags are
+#[cfg(all(not(target_os = "linux"), any(target_arch = "x86", target_arch = "x86_64")))]
+fn detect_cpu_features() -> Vec<String> {
+    macro_rules! detected {
+        ($($feature:tt => $flag:literal),* $(,)?) => {{
+            let mut features = Vec::new();
+            $(if std::arch::is_x86_feature_detected!($feature) { features.push($flag.to_string()); })*
+            features
+        }};
+    }
+    detected! {
+        "sse" => "sse", "sse2" => "sse2", "sse3" => "pni", "ssse3" => "ssse3",
+        "sse4.1" => "sse4_1", "sse4.2" => "sse4_2", "popcnt" => "popcnt", "aes" => "aes",
+        "pclmulqdq" => "pclmulqdq", "rdrand" => "rdrand", "rdseed" => "rdseed", "sha" => "sha_ni",
+        "avx" => "avx", "avx2" => "avx2", "fma" => "fma", "f16c" => "f16c",
+        "bmi1" => "bmi1", "bmi2" => "bmi2", "lzcnt" => "abm", "adx" => "adx",
+        "avx512f" => "avx512f", "avx512cd" => "avx512cd", "avx512bw" => "avx512bw",
+        "avx512dq" => "avx512dq", "avx512vl" => "avx512vl", "avx512vnni" => "avx512_vnni",
+    }
 }
 
 struct CpuVTab;
@@ -153,6 +493,10 @@ impl VTab for CpuVTab {
         bind.add_result_column("brand", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("vendor_id", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("byte_order", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("features", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar)));
+        bind.add_result_column("min_frequency_mhz", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("max_frequency_mhz", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("base_frequency_mhz", LogicalTypeHandle::from(LogicalTypeId::UBigint));
         Ok(CpuBindData)
     }
 
@@ -163,7 +507,27 @@ impl VTab for CpuVTab {
         std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
         sys.refresh_cpu_all();
         
+        // Cores normally share one feature set, but hybrid parts can differ per core
+        #[cfg(target_os = "linux")]
+        let core_features = std::fs::read_to_string("/proc/cpuinfo")
+            .map(|cpuinfo| parse_cpuinfo_features(&cpuinfo))
+            .unwrap_or_default();
+        #[cfg(all(not(target_os = "linux"), any(target_arch = "x86", target_arch = "x86_64")))]
+        let core_features = [detect_cpu_features()];
+        #[cfg(all(not(target_os = "linux"), not(any(target_arch = "x86", target_arch = "x86_64"))))]
+        let core_features: Vec<Vec<String>> = Vec::new();
+        
         let cpu_data: Vec<CpuInfo> = sys.cpus().iter().enumerate().map(|(idx, cpu)| {
+            // base_frequency is only exposed by intel_pstate
+            #[cfg(target_os = "linux")]
+            let (min_frequency_mhz, max_frequency_mhz, base_frequency_mhz) = (
+                read_cpufreq_mhz(idx, "cpuinfo_min_freq"),
+                read_cpufreq_mhz(idx, "cpuinfo_max_freq"),
+                read_cpufreq_mhz(idx, "base_frequency"),
+            );
+            #[cfg(not(target_os = "linux"))]
+            let (min_frequency_mhz, max_frequency_mhz, base_frequency_mhz) = (None, None, None);
+            
             CpuInfo {
                 core_id: idx,
                 name: cpu.name().to_string(),
@@ -171,6 +535,10 @@ impl VTab for CpuVTab {
                 frequency_mhz: cpu.frequency(),
                 brand: cpu.brand().to_string(),
                 vendor_id: cpu.vendor_id().to_string(),
+                features: core_features.get(idx).or(core_features.first()).cloned().unwrap_or_default(),
+                min_frequency_mhz,
+                max_frequency_mhz,
+                base_frequency_mhz,
             }
         }).collect();
         
@@ -205,8 +573,20 @@ impl VTab for CpuVTab {
             output.flat_vector(4).insert(i, CString::new(cpu.brand.clone())?);
             output.flat_vector(5).insert(i, CString::new(cpu.vendor_id.clone())?);
             output.flat_vector(6).insert(i, CString::new(init_data.byte_order.clone())?);
+            for (column, frequency) in [(8, cpu.min_frequency_mhz), (9, cpu.max_frequency_mhz), (10, cpu.base_frequency_mhz)] {
+                match frequency {
+                    Some(mhz) => output.flat_vector(column).as_mut_slice::<u64>()[i] = mhz,
+                    None => output.flat_vector(column).set_null(i),
+                }
+            }
         }
         
+        let features: Vec<&[String]> = init_data.cpu_data[current..current + batch_size]
+            .iter()
+            .map(|cpu| cpu.features.as_slice())
+            .collect();
+        insert_varchar_lists(&mut output.list_vector(7), &features)?;
+        
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
         output.set_len(batch_size);
         Ok(())
@@ -225,12 +605,14 @@ impl VTab for CpuVTab {
 #[repr(C)]
 struct MemoryBindData {
     unit: SizeUnit,
+    cgroup: bool,
 }
 
 #[repr(C)]
 struct MemoryInitData {
     done: AtomicBool,
     unit: SizeUnit,
+    is_limited: bool,
     total_memory: u64,
     used_memory: u64,
     free_memory: u64,
@@ -238,6 +620,7 @@ struct MemoryInitData {
     total_swap: u64,
     used_swap: u64,
     free_swap: u64,
+    commit_charge: Option<(u64, u64)>,
 }
 
 struct MemoryVTab;
@@ -255,6 +638,11 @@ impl VTab for MemoryVTab {
             SizeUnit::MB
         };
         
+        // Report the cgroup's limit and usage instead of host totals (default: false)
+        let cgroup = bind.get_named_parameter("cgroup")
+            .map(|v| v.to_string().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        
         bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("total_memory", LogicalTypeHandle::from(LogicalTypeId::Double));
         bind.add_result_column("used_memory", LogicalTypeHandle::from(LogicalTypeId::Double));
@@ -265,22 +653,49 @@ impl VTab for MemoryVTab {
         bind.add_result_column("used_swap", LogicalTypeHandle::from(LogicalTypeId::Double));
         bind.add_result_column("free_swap", LogicalTypeHandle::from(LogicalTypeId::Double));
         bind.add_result_column("swap_usage_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
-        Ok(MemoryBindData { unit })
+        bind.add_result_column("is_limited", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("commit_limit", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("committed", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("commit_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        Ok(MemoryBindData { unit, cgroup })
     }
 
     fn init(info: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
         let bind_data = info.get_bind_data::<MemoryBindData>();
         let unit = unsafe { (*bind_data).unit };
+        // Only Linux has cgroups to report
+        #[cfg(target_os = "linux")]
+        let use_cgroup = unsafe { (*bind_data).cgroup };
         
         let mut sys = System::new_with_specifics(
             RefreshKind::new().with_memory(MemoryRefreshKind::everything())
         );
         sys.refresh_memory();
         
-        let total_memory = sys.total_memory();
-        let used_memory = sys.used_memory();
-        let available_memory = sys.available_memory();
-        let free_memory = sys.free_memory();
+        let host_memory = (sys.total_memory(), sys.used_memory(), sys.free_memory(), sys.available_memory());
+        
+        #[cfg(target_os = "linux")]
+        let (is_limited, (total_memory, used_memory, free_memory, available_memory)) = match read_cgroup_memory() {
+            Some((limit, usage)) => {
+                // An unlimited cgroup ("max") is bounded by the host's memory
+                let (host_total, _, _, host_available) = host_memory;
+                let effective_limit = limit.unwrap_or(host_total).min(host_total);
+                let is_limited = effective_limit < host_total;
+                
+                if use_cgroup {
+                    let used = usage.min(effective_limit);
+                    let free = effective_limit - used;
+                    (is_limited, (effective_limit, used, free, free.min(host_available)))
+                } else {
+                    (is_limited, host_memory)
+                }
+            }
+            None => (false, host_memory),
+        };
+        
+        #[cfg(not(target_os = "linux"))]
+        let (is_limited, (total_memory, used_memory, free_memory, available_memory)) = (false, host_memory);
+        
         let total_swap = sys.total_swap();
         let used_swap = sys.used_swap();
         let free_swap = sys.free_swap();
@@ -288,6 +703,7 @@ impl VTab for MemoryVTab {
         Ok(MemoryInitData {
             done: AtomicBool::new(false),
             unit,
+            is_limited,
             total_memory,
             used_memory,
             free_memory,
@@ -295,6 +711,7 @@ impl VTab for MemoryVTab {
             total_swap,
             used_swap,
             free_swap,
+            commit_charge: read_commit_charge(),
         })
     }
 
@@ -330,6 +747,26 @@ impl VTab for MemoryVTab {
         output.flat_vector(7).as_mut_slice::<f64>()[0] = unit.convert(init_data.used_swap);
         output.flat_vector(8).as_mut_slice::<f64>()[0] = unit.convert(init_data.free_swap);
         output.flat_vector(9).as_mut_slice::<f32>()[0] = swap_usage_percent;
+        output.flat_vector(10).as_mut_slice::<bool>()[0] = init_data.is_limited;
+        
+        // Commit charge is not available on every platform (e.g. macOS)
+        match init_data.commit_charge {
+            Some((commit_limit, committed)) => {
+                let commit_percent = if commit_limit > 0 {
+                    (committed as f32 / commit_limit as f32) * 100.0
+                } else {
+                    0.0
+                };
+                output.flat_vector(11).as_mut_slice::<f64>()[0] = unit.convert(commit_limit);
+                output.flat_vector(12).as_mut_slice::<f64>()[0] = unit.convert(committed);
+                output.flat_vector(13).as_mut_slice::<f32>()[0] = commit_percent;
+            }
+            None => {
+                output.flat_vector(11).set_null(0);
+                output.flat_vector(12).set_null(0);
+                output.flat_vector(13).set_null(0);
+            }
+        }
         
         output.set_len(1);
         Ok(())
@@ -342,6 +779,7 @@ impl VTab for MemoryVTab {
     fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
         Some(vec![
             ("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+            ("cgroup".to_string(), LogicalTypeHandle::from(LogicalTypeId::Boolean)),
         ])
     }
 }
@@ -366,6 +804,222 @@ struct OsInitData {
     uptime_seconds: u64,
     boot_time: u64,
     process_count: usize,
+    virtualization: String,
+    container: String,
+    /// None when the source is missing or unreadable
+    machine_id: Option<String>,
+    product_uuid: Option<String>,
+}
+
+/// Name the hypervisor behind a set of DMI strings (vendor, product, board, BIOS)
+#[cfg(target_os = "linux")]
+fn classify_dmi_hypervisor(dmi: &[String]) -> Option<&'static str> {
+    // Physical Surface devices are also "Microsoft Corporation", so Hyper-V needs the product too
+    if dmi.iter().any(|s| s == "Virtual Machine") && dmi.iter().any(|s| s.contains("Microsoft")) {
+        return Some("microsoft");
+    }
+    const VENDORS: [(&str, &str); 11] = [
+        ("KVM", "kvm"),
+        ("OpenStack", "kvm"),
+        ("QEMU", "qemu"),
+        ("VMware", "vmware"),
+        ("VirtualBox", "virtualbox"),
+        ("innotek", "virtualbox"),
+        ("Xen", "xen"),
+        ("Amazon EC2", "amazon"),
+        ("Google Compute Engine", "google"),
+        ("Parallels", "parallels"),
+        ("BHYVE", "bhyve"),
+    ];
+    VENDORS.iter().find(|(needle, _)| dmi.iter().any(|s| s.contains(needle))).map(|(_, name)| *name)
+}
+
+/// Detect the hypervisor from DMI, /proc/xen and /sys/hypervisor, then the
+/// CPU's hypervisor flag, which says "virtual" without saying which
+#[cfg(target_os = "linux")]
+fn detect_virtualization() -> String {
+    let dmi: Vec<String> = ["sys_vendor", "product_name", "board_vendor", "bios_vendor"]
+        .iter()
+        .filter_map(|file| std::fs::read_to_string(format!("/sys/class/dmi/id/{}", file)).ok())
+        .map(|s| s.trim().to_string())
+        .collect();
+    if let Some(hypervisor) = classify_dmi_hypervisor(&dmi) {
+        return hypervisor.to_string();
+    }
+    if std::path::Path::new("/proc/xen").exists() {
+        return "xen".to_string();
+    }
+    if let Some(hypervisor) = std::fs::read_to_string("/sys/hypervisor/type").ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()) {
+        return hypervisor;
+    }
+    
+    // Only x86 has a flags line; without it or DMI there is nothing to go on
+    let cpu_flags = std::fs::read_to_string("/proc/cpuinfo").ok().and_then(|cpuinfo| {
+        cpuinfo.lines().find(|line| line.starts_with("flags")).map(|line| line.to_string())
+    });
+    match cpu_flags {
+        Some(flags) if flags.split_whitespace().any(|flag| flag == "hypervisor") => "unknown".to_string(),
+        Some(_) => "none".to_string(),
+        None if !dmi.is_empty() => "none".to_string(),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Name the container runtime from the cgroup paths in /proc/1/cgroup
+#[cfg(target_os = "linux")]
+fn classify_cgroup_container(cgroup: &str) -> Option<&'static str> {
+    if cgroup.contains("kubepods") {
+        Some("kubernetes")
+    } else if cgroup.contains("libpod") {
+        Some("podman")
+    } else if cgroup.contains("/docker") || cgroup.contains("docker-") {
+        Some("docker")
+    } else if cgroup.contains("/lxc") || cgroup.contains("lxc.payload") {
+        Some("lxc")
+    } else {
+        None
+    }
+}
+
+/// Detect the container runtime from marker files, the container= variable
+/// that LXC, podman and systemd-nspawn give PID 1, and PID 1's cgroup paths
+#[cfg(target_os = "linux")]
+fn detect_container() -> String {
+    if std::path::Path::new("/.dockerenv").exists() {
+        return "docker".to_string();
+    }
+    if std::path::Path::new("/run/.containerenv").exists() {
+        return "podman".to_string();
+    }
+    
+    // PID 1's environment needs the same user; our own environment usually inherits it
+    let container_var = std::fs::read("/proc/1/environ")
+        .ok()
+        .and_then(|environ| {
+            environ.split(|b| *b == 0)
+                .find_map(|var| var.strip_prefix(b"container=").map(|value| String::from_utf8_lossy(value).to_string()))
+        })
+        .or_else(|| std::env::var("container").ok())
+        .filter(|value| !value.is_empty());
+    if let Some(container) = container_var {
+        return container;
+    }
+    
+    std::fs::read_to_string("/proc/1/cgroup")
+        .ok()
+        .and_then(|cgroup| classify_cgroup_container(&cgroup))
+        .unwrap_or("none")
+        .to_string()
+}
+
+/// Read a trimmed, non-empty identifier file such as /etc/machine-id
+#[cfg(target_os = "linux")]
+fn read_id_file(path: &str) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// Read (machine_id, product_uuid). product_uuid is only readable by root.
+#[cfg(target_os = "linux")]
+fn read_host_ids() -> (Option<String>, Option<String>) {
+    let machine_id = read_id_file("/etc/machine-id").or_else(|| read_id_file("/var/lib/dbus/machine-id"));
+    let product_uuid = read_id_file("/sys/class/dmi/id/product_uuid").map(|uuid| uuid.to_lowercase());
+    (machine_id, product_uuid)
+}
+
+/// Pull IOPlatformUUID out of `ioreg -rd1 -c IOPlatformExpertDevice`, whose
+/// lines look like `"IOPlatformUUID" = "564D2A3C-..."`
+#[cfg(any(target_os = "macos", test))]
+fn parse_ioreg_platform_uuid(output: &str) -> Option<String> {
+    output.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "\"IOPlatformUUID\"").then(|| value.trim().trim_matches('"').to_string())
+    }).filter(|uuid| !uuid.is_empty())
+}
+
+/// IOPlatformUUID is the SMBIOS system UUID, so it serves as both identifiers
+#[cfg(target_os = "macos")]
+fn read_host_ids() -> (Option<String>, Option<String>) {
+    let uuid = command_output_with_timeout("ioreg", &["-rd1", "-c", "IOPlatformExpertDevice"], std::time::Duration::from_secs(5))
+        .and_then(|output| parse_ioreg_platform_uuid(&output));
+    (uuid.clone(), uuid.map(|uuid| uuid.to_lowercase()))
+}
+
+/// Find the system UUID in the SMBIOS type 1 (System Information) structure.
+/// Unset UUIDs (all zeros or all 0xFF) give None.
+#[cfg(any(windows, test))]
+fn parse_smbios_system_uuid(table: &[u8]) -> Option<String> {
+    let mut offset = 0;
+    while offset + 4 <= table.len() {
+        let (kind, length) = (table[offset], table[offset + 1] as usize);
+        // Type 127 marks the end of the table
+        if kind == 127 || length < 4 {
+            return None;
+        }
+        if kind == 1 && length >= 0x19 && offset + 0x19 <= table.len() {
+            let uuid = &table[offset + 8..offset + 0x18];
+            if uuid.iter().all(|&b| b == 0) || uuid.iter().all(|&b| b == 0xff) {
+                return None;
+            }
+            // The first three fields are little-endian since SMBIOS 2.6
+            return Some(format!(
+                "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+                u32::from_le_bytes([uuid[0], uuid[1], uuid[2], uuid[3]]),
+                u16::from_le_bytes([uuid[4], uuid[5]]),
+                u16::from_le_bytes([uuid[6], uuid[7]]),
+                uuid[8], uuid[9], uuid[10], uuid[11], uuid[12], uuid[13], uuid[14], uuid[15],
+            ));
+        }
+        // The formatted area is followed by strings ending in a double NUL
+        let strings = table.get(offset + length..)?;
+        offset += length + strings.windows(2).position(|pair| pair == [0, 0])? + 2;
+    }
+    None
+}
+
+/// Read MachineGuid from the registry and the SMBIOS system UUID from the firmware table
+#[cfg(windows)]
+fn read_host_ids() -> (Option<String>, Option<String>) {
+    use windows_sys::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ, RRF_SUBKEY_WOW6464KEY};
+    use windows_sys::Win32::System::SystemInformation::GetSystemFirmwareTable;
+    
+    let wide = |s: &str| s.encode_utf16().chain(std::iter::once(0)).collect::<Vec<u16>>();
+    let (subkey, value) = (wide("SOFTWARE\\Microsoft\\Cryptography"), wide("MachineGuid"));
+    let mut buffer = [0u16; 64];
+    let mut size = std::mem::size_of_val(&buffer) as u32;
+    // WOW6464KEY so a 32-bit DuckDB still sees the 64-bit hive
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_LOCAL_MACHINE,
+            subkey.as_ptr(),
+            value.as_ptr(),
+            RRF_RT_REG_SZ | RRF_SUBKEY_WOW6464KEY,
+            std::ptr::null_mut(),
+            buffer.as_mut_ptr() as *mut _,
+            &mut size,
+        )
+    };
+    let machine_id = (status == 0).then(|| {
+        let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        String::from_utf16_lossy(&buffer[..end])
+    }).filter(|id| !id.is_empty());
+    
+    // 'RSMB' returns a RawSMBIOSData: an 8-byte header followed by the table
+    let provider = u32::from_be_bytes(*b"RSMB");
+    let size = unsafe { GetSystemFirmwareTable(provider, 0, std::ptr::null_mut(), 0) };
+    let product_uuid = if size > 8 {
+        let mut table = vec![0u8; size as usize];
+        let written = unsafe { GetSystemFirmwareTable(provider, 0, table.as_mut_ptr() as *mut _, size) };
+        (written == size).then(|| parse_smbios_system_uuid(&table[8..])).flatten()
+    } else {
+        None
+    };
+    
+    (machine_id, product_uuid)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn read_host_ids() -> (Option<String>, Option<String>) {
+    (None, None)
 }
 
 struct OsVTab;
@@ -384,6 +1038,10 @@ impl VTab for OsVTab {
         bind.add_result_column("uptime_seconds", LogicalTypeHandle::from(LogicalTypeId::UBigint));
         bind.add_result_column("boot_time", LogicalTypeHandle::from(LogicalTypeId::UBigint));
         bind.add_result_column("process_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("virtualization", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("container", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("machine_id", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("product_uuid", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         Ok(OsBindData)
     }
 
@@ -392,6 +1050,12 @@ impl VTab for OsVTab {
             RefreshKind::new().with_processes(ProcessRefreshKind::everything())
         );
         
+        #[cfg(target_os = "linux")]
+        let (virtualization, container) = (detect_virtualization(), detect_container());
+        #[cfg(not(target_os = "linux"))]
+        let (virtualization, container) = ("unknown".to_string(), "unknown".to_string());
+        let (machine_id, product_uuid) = read_host_ids();
+        
         Ok(OsInitData {
             done: AtomicBool::new(false),
             os_name: System::name().unwrap_or_else(|| "Unknown".to_string()),
@@ -403,6 +1067,10 @@ impl VTab for OsVTab {
             uptime_seconds: System::uptime(),
             boot_time: System::boot_time(),
             process_count: sys.processes().len(),
+            virtualization,
+            container,
+            machine_id,
+            product_uuid,
         })
     }
 
@@ -423,6 +1091,14 @@ impl VTab for OsVTab {
         output.flat_vector(6).as_mut_slice::<u64>()[0] = init_data.uptime_seconds;
         output.flat_vector(7).as_mut_slice::<u64>()[0] = init_data.boot_time;
         output.flat_vector(8).as_mut_slice::<u64>()[0] = init_data.process_count as u64;
+        output.flat_vector(9).insert(0, CString::new(init_data.virtualization.clone())?);
+        output.flat_vector(10).insert(0, CString::new(init_data.container.clone())?);
+        for (column, id) in [(11, &init_data.machine_id), (12, &init_data.product_uuid)] {
+            match id {
+                Some(id) => output.flat_vector(column).insert(0, CString::new(id.clone())?),
+                None => output.flat_vector(column).set_null(0),
+            }
+        }
         
         output.set_len(1);
         Ok(())
@@ -441,6 +1117,7 @@ impl VTab for OsVTab {
 #[repr(C)]
 struct SystemBindData {
     unit: SizeUnit,
+    cpu_interval: std::time::Duration,
 }
 
 #[repr(C)]
@@ -494,12 +1171,20 @@ impl VTab for SystemVTab {
         bind.add_result_column("process_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
         bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         
-        Ok(SystemBindData { unit })
+        // CPU sampling window, clamped to 50..10000 ms like the other sampling
+        // functions and never shorter than sysinfo's minimum update interval
+        let cpu_interval = bind.get_named_parameter("interval_ms")
+            .map(|v| std::time::Duration::from_millis(v.to_int64().clamp(50, 10_000) as u64))
+            .unwrap_or(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL)
+            .max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        
+        Ok(SystemBindData { unit, cpu_interval })
     }
 
     fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
         let bind_data = init.get_bind_data::<SystemBindData>();
         let unit = unsafe { (*bind_data).unit };
+        let cpu_interval = unsafe { (*bind_data).cpu_interval };
         
         let mut sys = System::new_with_specifics(
             RefreshKind::new()
@@ -507,7 +1192,7 @@ impl VTab for SystemVTab {
                 .with_memory(MemoryRefreshKind::everything())
                 .with_processes(ProcessRefreshKind::everything())
         );
-        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        std::thread::sleep(cpu_interval);
         sys.refresh_all();
         
         let total_memory = sys.total_memory();
@@ -579,7 +1264,10 @@ impl VTab for SystemVTab {
     }
     
     fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
-        Some(vec![("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+        Some(vec![
+            ("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+            ("interval_ms".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+        ])
     }
 }
 
@@ -591,6 +1279,10 @@ impl VTab for SystemVTab {
 #[repr(C)]
 struct DisksBindData {
     unit: SizeUnit,
+    near_full_threshold: f32,
+    dedupe: bool,
+    exclude_mounts: Vec<String>,
+    exclude_fs: Vec<String>,
 }
 
 #[repr(C)]
@@ -599,6 +1291,7 @@ struct DisksInitData {
     disk_count: usize,
     disk_data: Vec<DiskInfo>,
     unit: SizeUnit,
+    near_full_threshold: f32,
 }
 
 struct DiskInfo {
@@ -609,6 +1302,137 @@ struct DiskInfo {
     available_bytes: u64,
     is_removable: bool,
     kind: String,
+    is_readonly: bool,
+    mount_options: String,
+    device_model: Option<String>,
+    device_serial: Option<String>,
+    real_device: Option<String>,
+    pool_id: String,
+}
+
+/// The backing store a volume draws space from: APFS volumes (disk3s1, disk3s5s1, ...)
+/// share their container disk3, while other filesystems (including btrfs subvolumes,
+/// which all report the same source device) are identified by the device itself
+fn disk_pool_base(name: &str, file_system: &str) -> String {
+    if file_system.eq_ignore_ascii_case("apfs") {
+        if let Some(start) = name.find("disk") {
+            let digits = name[start + 4..].chars().take_while(|c| c.is_ascii_digit()).count();
+            if digits > 0 {
+                return name[..start + 4 + digits].to_string();
+            }
+        }
+    }
+    name.to_string()
+}
+
+/// Assign each disk a pool_id. Volumes only share a pool when they have the same backing
+/// device and report the same total size, as volumes carved from one pool do.
+fn assign_pool_ids(disks: &mut [DiskInfo]) {
+    let mut members: std::collections::HashMap<(String, u64), usize> = std::collections::HashMap::new();
+    for disk in disks.iter() {
+        *members.entry((disk_pool_base(&disk.name, &disk.file_system), disk.total_bytes)).or_insert(0) += 1;
+    }
+    for disk in disks.iter_mut() {
+        let base = disk_pool_base(&disk.name, &disk.file_system);
+        disk.pool_id = if members[&(base.clone(), disk.total_bytes)] > 1 { base } else { disk.name.clone() };
+    }
+}
+
+/// Resolve a mount source such as /dev/mapper/vg-root or /dev/nvme0n1p2 to the
+/// physical disk underneath it, following partitions and dm/md slaves
+#[cfg(target_os = "linux")]
+fn resolve_physical_disk(source: &str) -> Option<String> {
+    let device = std::fs::canonicalize(source).ok()?;
+    let mut name = device.file_name()?.to_string_lossy().to_string();
+    
+    // Bounded walk down the stack, e.g. dm-1 -> dm-0 -> sda2 -> sda
+    for _ in 0..8 {
+        let base = format!("/sys/class/block/{}", name);
+        if std::path::Path::new(&format!("{}/partition", base)).exists() {
+            // A partition's sysfs directory sits inside its disk's directory
+            let sys_path = std::fs::canonicalize(&base).ok()?;
+            name = sys_path.parent()?.file_name()?.to_string_lossy().to_string();
+            continue;
+        }
+        match read_sys_dir_names(&format!("{}/slaves", base)).into_iter().min() {
+            Some(slave) => name = slave,
+            None => break,
+        }
+    }
+    
+    std::path::Path::new(&format!("/sys/block/{}", name)).exists().then_some(name)
+}
+
+/// Read a disk's model and serial number from sysfs; virtual devices have neither
+#[cfg(target_os = "linux")]
+fn read_disk_identity(disk: &str) -> (Option<String>, Option<String>) {
+    let read = |path: String| {
+        std::fs::read_to_string(path).ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let model = read(format!("/sys/block/{}/device/model", disk));
+    // NVMe and SCSI expose the serial on the device, virtio on the block device
+    let serial = read(format!("/sys/block/{}/device/serial", disk))
+        .or_else(|| read(format!("/sys/block/{}/serial", disk)));
+    (model, serial)
+}
+
+/// Read the media name of a disk from `diskutil info` on macOS (serials are not reported)
+#[cfg(target_os = "macos")]
+fn read_disk_identity(source: &str) -> (Option<String>, Option<String>) {
+    let model = std::process::Command::new("diskutil")
+        .args(["info", source])
+        .output()
+        .ok()
+        .and_then(|output| {
+            String::from_utf8_lossy(&output.stdout).lines()
+                .find_map(|line| line.trim().strip_prefix("Device / Media Name:").map(|v| v.trim().to_string()))
+        })
+        .filter(|model| !model.is_empty());
+    (model, None)
+}
+
+/// Describe a Windows volume's filesystem flags in mount-option style, e.g. "rw,acls,quotas"
+#[cfg(windows)]
+fn windows_volume_options(root: &str) -> String {
+    use windows_sys::Win32::Storage::FileSystem::GetVolumeInformationW;
+    use windows_sys::Win32::System::SystemServices::{
+        FILE_CASE_SENSITIVE_SEARCH, FILE_PERSISTENT_ACLS, FILE_READ_ONLY_VOLUME,
+        FILE_SUPPORTS_ENCRYPTION, FILE_VOLUME_IS_COMPRESSED, FILE_VOLUME_QUOTAS,
+    };
+    
+    let wide: Vec<u16> = root.encode_utf16().chain(std::iter::once(0)).collect();
+    let mut flags: u32 = 0;
+    let ok = unsafe {
+        GetVolumeInformationW(
+            wide.as_ptr(),
+            std::ptr::null_mut(),
+            0,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            &mut flags,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+    if ok == 0 {
+        return String::new();
+    }
+    
+    let mut options = vec![if flags & FILE_READ_ONLY_VOLUME != 0 { "ro" } else { "rw" }];
+    for (flag, name) in [
+        (FILE_VOLUME_IS_COMPRESSED, "compressed"),
+        (FILE_SUPPORTS_ENCRYPTION, "encryption"),
+        (FILE_PERSISTENT_ACLS, "acls"),
+        (FILE_VOLUME_QUOTAS, "quotas"),
+        (FILE_CASE_SENSITIVE_SEARCH, "case_sensitive"),
+    ] {
+        if flags & flag != 0 {
+            options.push(name);
+        }
+    }
+    options.join(",")
 }
 
 struct DisksVTab;
@@ -635,34 +1459,132 @@ impl VTab for DisksVTab {
         bind.add_result_column("usage_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
         bind.add_result_column("is_removable", LogicalTypeHandle::from(LogicalTypeId::Boolean));
         bind.add_result_column("kind", LogicalTypeHandle::from(LogicalTypeId::Varchar));
-        Ok(DisksBindData { unit })
+        bind.add_result_column("is_readonly", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("near_full", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("mount_options", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("device_model", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("device_serial", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("real_device", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("pool_id", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        // Usage percentage at which a disk counts as near full (default: 90)
+        let near_full_threshold = bind.get_named_parameter("near_full_threshold")
+            .and_then(|v| v.to_string().parse::<f32>().ok())
+            .unwrap_or(90.0);
+        
+        // Keep one row per pool so that SUM(used_space) counts shared space once
+        let dedupe = bind.get_named_parameter("dedupe")
+            .map(|v| v.to_string().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        
+        // Virtual filesystem exclusions; passing an empty list disables that filter
+        let exclude_mounts = bind.get_named_parameter("exclude_mounts")
+            .map(|v| parse_varchar_list(&v.to_string()))
+            .unwrap_or_else(|| DEFAULT_EXCLUDED_MOUNTS.iter().map(|s| s.to_string()).collect());
+        let exclude_fs = bind.get_named_parameter("exclude_fs")
+            .map(|v| parse_varchar_list(&v.to_string()))
+            .unwrap_or_else(|| DEFAULT_EXCLUDED_FS.iter().map(|s| s.to_string()).collect());
+        
+        Ok(DisksBindData { unit, near_full_threshold, dedupe, exclude_mounts, exclude_fs })
     }
 
     fn init(info: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
         let bind_data = info.get_bind_data::<DisksBindData>();
         let unit = unsafe { (*bind_data).unit };
+        let near_full_threshold = unsafe { (*bind_data).near_full_threshold };
+        let dedupe = unsafe { (*bind_data).dedupe };
+        let (exclude_mounts, exclude_fs) = unsafe {
+            ((*bind_data).exclude_mounts.clone(), (*bind_data).exclude_fs.clone())
+        };
         
         let disks = Disks::new_with_refreshed_list();
         
+        // Options come from the live mount table so remounts are reflected;
+        // for stacked mounts the last entry is the one in effect
+        #[cfg(not(windows))]
+        let mounts: std::collections::HashMap<String, MountInfo> = read_mounts()
+            .into_iter()
+            .map(|mount| (mount.mount_point.clone(), mount))
+            .collect();
+        
         // Filter out virtual filesystems
-        let disk_data: Vec<DiskInfo> = disks.iter()
+        let mut disk_data: Vec<DiskInfo> = disks.iter()
             .filter(|disk| {
                 let mount_point = disk.mount_point().to_string_lossy().to_string();
                 let fs_type = disk.file_system().to_string_lossy().to_string();
-                !is_virtual_filesystem(&mount_point, &fs_type)
+                !is_excluded_mount(&mount_point, &fs_type, &exclude_mounts, &exclude_fs)
             })
             .map(|disk| {
+                let mount_point = disk.mount_point().to_string_lossy().to_string();
+                
+                // Read-only state comes from the same live flags as mount_options
+                // (ro in /proc/self/mountinfo, MNT_RDONLY as reported by `mount` on
+                // macOS, FILE_READ_ONLY_VOLUME on Windows), so a filesystem remounted
+                // read-only after an I/O error shows up immediately
+                #[cfg(not(windows))]
+                let (mount_options, is_readonly) = match mounts.get(&mount_point) {
+                    Some(mount) => (mount.options.join(","), mount.is_readonly),
+                    None => (String::new(), disk.is_read_only()),
+                };
+                
+                #[cfg(windows)]
+                let mount_options = windows_volume_options(&mount_point);
+                
+                #[cfg(windows)]
+                let is_readonly = match mount_options.split(',').next() {
+                    Some("ro") => true,
+                    Some("rw") => false,
+                    _ => disk.is_read_only(),
+                };
+                
+                let name = disk.name().to_string_lossy().to_string();
+                
+                #[cfg(target_os = "linux")]
+                let physical_disk = resolve_physical_disk(&name);
+                
+                #[cfg(target_os = "linux")]
+                let (device_model, device_serial) = physical_disk.as_deref()
+                    .map(read_disk_identity)
+                    .unwrap_or((None, None));
+                
+                #[cfg(target_os = "linux")]
+                let real_device = physical_disk.map(|physical| format!("/dev/{}", physical));
+                
+                #[cfg(target_os = "macos")]
+                let (device_model, device_serial) = read_disk_identity(&name);
+                
+                #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+                let (device_model, device_serial): (Option<String>, Option<String>) = (None, None);
+                
+                #[cfg(not(target_os = "linux"))]
+                let real_device: Option<String> = None;
+                
                 DiskInfo {
-                    name: disk.name().to_string_lossy().to_string(),
-                    mount_point: disk.mount_point().to_string_lossy().to_string(),
+                    name,
+                    mount_point,
                     file_system: disk.file_system().to_string_lossy().to_string(),
                     total_bytes: disk.total_space(),
                     available_bytes: disk.available_space(),
                     is_removable: disk.is_removable(),
                     kind: format!("{:?}", disk.kind()),
+                    is_readonly,
+                    mount_options,
+                    device_model,
+                    device_serial,
+                    real_device,
+                    pool_id: String::new(),
                 }
             }).collect();
         
+        assign_pool_ids(&mut disk_data);
+        
+        if dedupe {
+            // The shortest mount point represents the pool (e.g. / over /home)
+            disk_data.sort_by(|a, b| a.mount_point.len().cmp(&b.mount_point.len()).then_with(|| a.mount_point.cmp(&b.mount_point)));
+            let mut seen = std::collections::HashSet::new();
+            disk_data.retain(|disk| seen.insert(disk.pool_id.clone()));
+        }
+        
         let disk_count = disk_data.len();
         
         Ok(DisksInitData {
@@ -670,6 +1592,7 @@ impl VTab for DisksVTab {
             disk_count,
             disk_data,
             unit,
+            near_full_threshold,
         })
     }
 
@@ -704,6 +1627,22 @@ impl VTab for DisksVTab {
             output.flat_vector(7).as_mut_slice::<f32>()[i] = usage_percent;
             output.flat_vector(8).as_mut_slice::<bool>()[i] = disk.is_removable;
             output.flat_vector(9).insert(i, CString::new(disk.kind.clone())?);
+            output.flat_vector(10).as_mut_slice::<bool>()[i] = disk.is_readonly;
+            output.flat_vector(11).as_mut_slice::<bool>()[i] = usage_percent >= init_data.near_full_threshold;
+            output.flat_vector(12).insert(i, CString::new(disk.mount_options.clone())?);
+            match &disk.device_model {
+                Some(model) => output.flat_vector(13).insert(i, CString::new(model.clone())?),
+                None => output.flat_vector(13).set_null(i),
+            }
+            match &disk.device_serial {
+                Some(serial) => output.flat_vector(14).insert(i, CString::new(serial.clone())?),
+                None => output.flat_vector(14).set_null(i),
+            }
+            match &disk.real_device {
+                Some(device) => output.flat_vector(15).insert(i, CString::new(device.clone())?),
+                None => output.flat_vector(15).set_null(i),
+            }
+            output.flat_vector(16).insert(i, CString::new(disk.pool_id.clone())?);
         }
         
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
@@ -718,6 +1657,10 @@ impl VTab for DisksVTab {
     fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
         Some(vec![
             ("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+            ("near_full_threshold".to_string(), LogicalTypeHandle::from(LogicalTypeId::Double)),
+            ("dedupe".to_string(), LogicalTypeHandle::from(LogicalTypeId::Boolean)),
+            ("exclude_mounts".to_string(), LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar))),
+            ("exclude_fs".to_string(), LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar))),
         ])
     }
 }
@@ -730,6 +1673,9 @@ impl VTab for DisksVTab {
 #[repr(C)]
 struct NetworkBindData {
     unit: SizeUnit,
+    interval_ms: Option<u64>,
+    interface: Option<globset::GlobMatcher>,
+    exclude_loopback: bool,
 }
 
 #[repr(C)]
@@ -738,65 +1684,373 @@ struct NetworkInitData {
     network_count: usize,
     network_data: Vec<NetworkInfo>,
     unit: SizeUnit,
+    with_rates: bool,
+}
+
+/// Per-second rates over the sampling interval of sazgar_network(interval_ms := ...)
+#[derive(Default)]
+struct NetworkRates {
+    rx_bytes_per_sec: f64,
+    tx_bytes_per_sec: f64,
+    rx_packets_per_sec: f64,
+    tx_packets_per_sec: f64,
 }
 
 struct NetworkInfo {
     interface_name: String,
-    mac_address: String,
+    mac_address: Option<String>,
     rx_bytes: u64,
     tx_bytes: u64,
     rx_packets: u64,
     tx_packets: u64,
     rx_errors: u64,
     tx_errors: u64,
+    ip_addresses: Vec<String>,
+    link: LinkInfo,
+    rates: NetworkRates,
 }
 
-struct NetworkVTab;
+/// Normalize a MAC address to lowercase, colon-separated octets. The all-zero
+/// address (loopback, tun, wireguard) and broadcast address mean "no MAC" and
+/// give None, as does anything that isn't six hex octets.
+fn normalize_mac_address(mac: &str) -> Option<String> {
+    let octets: Vec<u8> = mac.trim()
+        .split([':', '-'])
+        .map(|octet| u8::from_str_radix(octet, 16).ok())
+        .collect::<Option<_>>()?;
+    if octets.len() != 6 || octets.iter().all(|&o| o == 0) || octets.iter().all(|&o| o == 0xff) {
+        return None;
+    }
+    Some(octets.iter().map(|o| format!("{:02x}", o)).collect::<Vec<_>>().join(":"))
+}
 
-impl VTab for NetworkVTab {
-    type InitData = NetworkInitData;
-    type BindData = NetworkBindData;
+/// Link-layer settings and OS-only counters of one interface
+#[derive(Default)]
+struct LinkInfo {
+    mtu: Option<i32>,
+    speed_mbps: Option<i32>,
+    is_up: Option<bool>,
+    operstate: Option<String>,
+    is_loopback: Option<bool>,
+    rx_dropped: Option<u64>,
+    tx_dropped: Option<u64>,
+    multicast: Option<u64>,
+}
 
-    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
-        // Parse unit parameter (default: MB)
-        let unit = if bind.get_named_parameter("unit").is_some() {
-            let unit_str = bind.get_named_parameter("unit").unwrap().to_string();
-            SizeUnit::from_str(&unit_str).unwrap_or(SizeUnit::MB)
-        } else {
-            SizeUnit::MB
-        };
-        
-        bind.add_result_column("interface_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
-        bind.add_result_column("mac_address", LogicalTypeHandle::from(LogicalTypeId::Varchar));
-        bind.add_result_column("rx", LogicalTypeHandle::from(LogicalTypeId::Double));
-        bind.add_result_column("tx", LogicalTypeHandle::from(LogicalTypeId::Double));
-        bind.add_result_column("rx_packets", LogicalTypeHandle::from(LogicalTypeId::UBigint));
-        bind.add_result_column("tx_packets", LogicalTypeHandle::from(LogicalTypeId::UBigint));
-        bind.add_result_column("rx_errors", LogicalTypeHandle::from(LogicalTypeId::UBigint));
-        bind.add_result_column("tx_errors", LogicalTypeHandle::from(LogicalTypeId::UBigint));
-        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+/// Read MTU, speed and state from /sys/class/net/<if>/{mtu,speed,flags,operstate},
+/// and the drop/multicast counters sysinfo lacks from its statistics directory
+#[cfg(target_os = "linux")]
+fn read_link_info() -> std::collections::HashMap<String, LinkInfo> {
+    read_sys_dir_names("/sys/class/net")
+        .into_iter()
+        .map(|name| {
+            let base = format!("/sys/class/net/{}", name);
+            // speed is -1 or unreadable (EINVAL) for wireless, virtual and down links
+            let speed_mbps = std::fs::read_to_string(format!("{}/speed", base))
+                .ok()
+                .and_then(|s| s.trim().parse::<i64>().ok())
+                .filter(|speed| *speed > 0)
+                .and_then(|speed| i32::try_from(speed).ok());
+            let flags = std::fs::read_to_string(format!("{}/flags", base))
+                .ok()
+                .and_then(|s| u32::from_str_radix(s.trim().trim_start_matches("0x"), 16).ok());
+            let info = LinkInfo {
+                mtu: read_sys_u64(&format!("{}/mtu", base)).and_then(|mtu| i32::try_from(mtu).ok()),
+                speed_mbps,
+                is_up: flags.map(|flags| flags & libc::IFF_UP as u32 != 0),
+                operstate: std::fs::read_to_string(format!("{}/operstate", base)).ok().map(|s| s.trim().to_string()),
+                is_loopback: flags.map(|flags| flags & libc::IFF_LOOPBACK as u32 != 0),
+                rx_dropped: read_sys_u64(&format!("{}/statistics/rx_dropped", base)),
+                tx_dropped: read_sys_u64(&format!("{}/statistics/tx_dropped", base)),
+                multicast: read_sys_u64(&format!("{}/statistics/multicast", base)),
+            };
+            (name, info)
+        })
+        .collect()
+}
+
+/// Read MTU, baud rate, flags and input drop/multicast counters from the
+/// AF_LINK entries of getifaddrs (if_data has no output drop counter)
+#[cfg(target_os = "macos")]
+fn read_link_info() -> std::collections::HashMap<String, LinkInfo> {
+    let mut links = std::collections::HashMap::new();
+    
+    let mut addrs: *mut libc::ifaddrs = std::ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut addrs) } != 0 {
+        return links;
+    }
+    
+    let mut cursor = addrs;
+    while !cursor.is_null() {
+        let ifa = unsafe { &*cursor };
+        cursor = ifa.ifa_next;
+        
+        let is_link = !ifa.ifa_addr.is_null() && unsafe { (*ifa.ifa_addr).sa_family } as i32 == libc::AF_LINK;
+        if !is_link || ifa.ifa_data.is_null() {
+            continue;
+        }
+        let name = unsafe { std::ffi::CStr::from_ptr(ifa.ifa_name) }.to_string_lossy().to_string();
+        let data = unsafe { &*(ifa.ifa_data as *const libc::if_data) };
+        let flags = ifa.ifa_flags as i32;
+        
+        links.insert(name, LinkInfo {
+            mtu: i32::try_from(data.ifi_mtu).ok(),
+            // A baud rate of 0 means unknown
+            speed_mbps: Some((data.ifi_baudrate as u64 / 1_000_000) as i32).filter(|speed| *speed > 0),
+            is_up: Some(flags & libc::IFF_UP != 0),
+            operstate: Some(if flags & libc::IFF_RUNNING != 0 { "up" } else { "down" }.to_string()),
+            is_loopback: Some(flags & libc::IFF_LOOPBACK != 0),
+            rx_dropped: Some(data.ifi_iqdrops as u64),
+            tx_dropped: None,
+            multicast: Some(data.ifi_imcasts as u64),
+        });
+    }
+    
+    unsafe { libc::freeifaddrs(addrs) };
+    links
+}
+
+/// Decode a NUL-terminated UTF-16 string such as an adapter's FriendlyName;
+/// a null pointer gives an empty string
+#[cfg(windows)]
+fn wide_ptr_to_string(wide: *const u16) -> String {
+    if wide.is_null() {
+        return String::new();
+    }
+    let len = (0..).take_while(|&i| unsafe { *wide.add(i) } != 0).count();
+    String::from_utf16_lossy(unsafe { std::slice::from_raw_parts(wide, len) })
+}
+
+/// The adapter list from GetAdaptersAddresses. It is a linked list stored in
+/// the caller's buffer, so the buffer is sized first and kept alive here.
+#[cfg(windows)]
+struct AdapterList {
+    buffer: Vec<u64>,
+}
+
+#[cfg(windows)]
+impl AdapterList {
+    /// Read every adapter with `flags` (GAA_FLAG_*); an empty list on failure
+    fn read(flags: u32) -> Self {
+        use windows_sys::Win32::NetworkManagement::IpHelper::{GetAdaptersAddresses, IP_ADAPTER_ADDRESSES_LH};
+        use windows_sys::Win32::Networking::WinSock::AF_UNSPEC;
+        
+        let mut size: u32 = 0;
+        unsafe { GetAdaptersAddresses(AF_UNSPEC as u32, flags, std::ptr::null(), std::ptr::null_mut(), &mut size) };
+        if size == 0 {
+            return AdapterList { buffer: Vec::new() };
+        }
+        let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+        let first = buffer.as_mut_ptr() as *mut IP_ADAPTER_ADDRESSES_LH;
+        if unsafe { GetAdaptersAddresses(AF_UNSPEC as u32, flags, std::ptr::null(), first, &mut size) } != 0 {
+            buffer.clear();
+        }
+        AdapterList { buffer }
+    }
+    
+    fn iter(&self) -> impl Iterator<Item = &windows_sys::Win32::NetworkManagement::IpHelper::IP_ADAPTER_ADDRESSES_LH> {
+        use windows_sys::Win32::NetworkManagement::IpHelper::IP_ADAPTER_ADDRESSES_LH;
+        
+        let first = if self.buffer.is_empty() {
+            None
+        } else {
+            unsafe { (self.buffer.as_ptr() as *const IP_ADAPTER_ADDRESSES_LH).as_ref() }
+        };
+        std::iter::successors(first, |adapter| unsafe { adapter.Next.as_ref() })
+    }
+}
+
+/// Read MTU, link speed and operational status from GetAdaptersAddresses and
+/// drop counters from GetIfTable2, keyed by friendly name as sysinfo names
+/// interfaces. Windows only counts multicast bytes, not packets, so
+/// `multicast` stays unset.
+#[cfg(windows)]
+fn read_link_info() -> std::collections::HashMap<String, LinkInfo> {
+    use windows_sys::Win32::NetworkManagement::IpHelper::{
+        FreeMibTable, GetIfTable2, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST,
+        IF_TYPE_SOFTWARE_LOOPBACK, MIB_IF_TABLE2,
+    };
+    
+    let mut links: std::collections::HashMap<String, LinkInfo> = std::collections::HashMap::new();
+    let adapters = AdapterList::read(GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_DNS_SERVER);
+    
+    for info in adapters.iter() {
+        if info.FriendlyName.is_null() {
+            continue;
+        }
+        let name = wide_ptr_to_string(info.FriendlyName);
+        
+        // IF_OPER_STATUS values from RFC 2863
+        let operstate = match info.OperStatus {
+            1 => "up",
+            2 => "down",
+            3 => "testing",
+            5 => "dormant",
+            6 => "notpresent",
+            7 => "lowerlayerdown",
+            _ => "unknown",
+        };
+        links.insert(name, LinkInfo {
+            mtu: i32::try_from(info.Mtu).ok(),
+            // u64::MAX means the speed is unknown
+            speed_mbps: Some(info.TransmitLinkSpeed)
+                .filter(|speed| *speed != u64::MAX && *speed > 0)
+                .map(|speed| (speed / 1_000_000) as i32),
+            is_up: Some(info.OperStatus == 1),
+            operstate: Some(operstate.to_string()),
+            is_loopback: Some(info.IfType == IF_TYPE_SOFTWARE_LOOPBACK),
+            ..Default::default()
+        });
+    }
+    
+    // sysinfo lists interfaces from this same table, so each of them gets drop counters
+    let mut table: *mut MIB_IF_TABLE2 = std::ptr::null_mut();
+    if unsafe { GetIfTable2(&mut table) } == 0 && !table.is_null() {
+        let rows = unsafe { std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as usize) };
+        for row in rows {
+            let end = row.Alias.iter().position(|&c| c == 0).unwrap_or(row.Alias.len());
+            let link = links.entry(String::from_utf16_lossy(&row.Alias[..end])).or_default();
+            link.rx_dropped = Some(row.InDiscards);
+            link.tx_dropped = Some(row.OutDiscards);
+        }
+        unsafe { FreeMibTable(table as *const _) };
+    }
+    
+    links
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn read_link_info() -> std::collections::HashMap<String, LinkInfo> {
+    std::collections::HashMap::new()
+}
+
+/// Sample every interface's counters twice, `interval_ms` apart, refreshing
+/// `networks` in place. Counters that wrap or reset during the interval
+/// clamp to a rate of zero instead of going negative.
+fn sample_network_rates(networks: &mut Networks, interval_ms: u64) -> std::collections::HashMap<String, NetworkRates> {
+    let baseline: std::collections::HashMap<String, [u64; 4]> = networks.iter()
+        .map(|(name, data)| (name.clone(), [
+            data.total_received(),
+            data.total_transmitted(),
+            data.total_packets_received(),
+            data.total_packets_transmitted(),
+        ]))
+        .collect();
+    
+    let start = std::time::Instant::now();
+    std::thread::sleep(std::time::Duration::from_millis(interval_ms));
+    networks.refresh();
+    let elapsed_secs = start.elapsed().as_secs_f64();
+    
+    networks.iter()
+        .filter_map(|(name, data)| {
+            let [rx_bytes, tx_bytes, rx_packets, tx_packets] = baseline.get(name)?;
+            Some((name.clone(), NetworkRates {
+                rx_bytes_per_sec: data.total_received().saturating_sub(*rx_bytes) as f64 / elapsed_secs,
+                tx_bytes_per_sec: data.total_transmitted().saturating_sub(*tx_bytes) as f64 / elapsed_secs,
+                rx_packets_per_sec: data.total_packets_received().saturating_sub(*rx_packets) as f64 / elapsed_secs,
+                tx_packets_per_sec: data.total_packets_transmitted().saturating_sub(*tx_packets) as f64 / elapsed_secs,
+            }))
+        })
+        .collect()
+}
+
+struct NetworkVTab;
+
+impl VTab for NetworkVTab {
+    type InitData = NetworkInitData;
+    type BindData = NetworkBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        // Parse unit parameter (default: MB)
+        let unit = if bind.get_named_parameter("unit").is_some() {
+            let unit_str = bind.get_named_parameter("unit").unwrap().to_string();
+            SizeUnit::from_str(&unit_str).unwrap_or(SizeUnit::MB)
+        } else {
+            SizeUnit::MB
+        };
+        
+        bind.add_result_column("interface_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("mac_address", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("rx", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("tx", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("rx_packets", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("tx_packets", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("rx_errors", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("tx_errors", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("rx_dropped", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("tx_dropped", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("multicast", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("ip_addresses", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar)));
+        bind.add_result_column("mtu", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("speed_mbps", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("is_up", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("operstate", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        // Sampling window, clamped to 50..10000 ms; without it only lifetime totals are returned
+        let interval_ms = bind.get_named_parameter("interval_ms")
+            .map(|v| v.to_int64().clamp(50, 10_000) as u64);
+        
+        if interval_ms.is_some() {
+            bind.add_result_column("rx_bytes_per_sec", LogicalTypeHandle::from(LogicalTypeId::Double));
+            bind.add_result_column("tx_bytes_per_sec", LogicalTypeHandle::from(LogicalTypeId::Double));
+            bind.add_result_column("rx_packets_per_sec", LogicalTypeHandle::from(LogicalTypeId::Double));
+            bind.add_result_column("tx_packets_per_sec", LogicalTypeHandle::from(LogicalTypeId::Double));
+        }
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        // Exact interface name, or a glob such as 'veth*'
+        let interface = bind.get_named_parameter("interface")
+            .map(|v| compile_glob("sazgar_network", &v.to_string()))
+            .transpose()?;
         
-        Ok(NetworkBindData { unit })
+        let exclude_loopback = bind.get_named_parameter("exclude_loopback")
+            .map(|v| v.to_string().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        
+        Ok(NetworkBindData { unit, interval_ms, interface, exclude_loopback })
     }
 
     fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
         let bind_data = init.get_bind_data::<NetworkBindData>();
         let unit = unsafe { (*bind_data).unit };
+        let interval_ms = unsafe { (*bind_data).interval_ms };
+        let interface = unsafe { (*bind_data).interface.clone() };
+        let exclude_loopback = unsafe { (*bind_data).exclude_loopback };
         
-        let networks = Networks::new_with_refreshed_list();
+        let mut networks = Networks::new_with_refreshed_list();
         
-        let network_data: Vec<NetworkInfo> = networks.iter().map(|(name, data)| {
-            NetworkInfo {
-                interface_name: name.clone(),
-                mac_address: data.mac_address().to_string(),
-                rx_bytes: data.total_received(),
-                tx_bytes: data.total_transmitted(),
-                rx_packets: data.total_packets_received(),
-                tx_packets: data.total_packets_transmitted(),
-                rx_errors: data.total_errors_on_received(),
-                tx_errors: data.total_errors_on_transmitted(),
-            }
-        }).collect();
+        let mut rates = match interval_ms {
+            Some(interval_ms) => sample_network_rates(&mut networks, interval_ms),
+            None => std::collections::HashMap::new(),
+        };
+        let mut links = read_link_info();
+        
+        let network_data: Vec<NetworkInfo> = networks.iter()
+            .filter(|(name, _)| interface.as_ref().is_none_or(|pattern| pattern.is_match(name)))
+            .filter_map(|(name, data)| {
+                let link = links.remove(name).unwrap_or_default();
+                // Fall back to the conventional names when the OS flags weren't readable
+                let is_loopback = link.is_loopback.unwrap_or(name == "lo" || name == "lo0");
+                if exclude_loopback && is_loopback {
+                    return None;
+                }
+                Some(NetworkInfo {
+                    interface_name: name.clone(),
+                    mac_address: normalize_mac_address(&data.mac_address().to_string()),
+                    rx_bytes: data.total_received(),
+                    tx_bytes: data.total_transmitted(),
+                    rx_packets: data.total_packets_received(),
+                    tx_packets: data.total_packets_transmitted(),
+                    rx_errors: data.total_errors_on_received(),
+                    tx_errors: data.total_errors_on_transmitted(),
+                    // CIDR notation, e.g. "192.168.1.10/24" and "fe80::1/64"
+                    ip_addresses: data.ip_networks().iter().map(|network| network.to_string()).collect(),
+                    link,
+                    rates: rates.remove(name).unwrap_or_default(),
+                })
+            })
+            .collect();
         
         let network_count = network_data.len();
         
@@ -805,6 +2059,7 @@ impl VTab for NetworkVTab {
             network_count,
             network_data,
             unit,
+            with_rates: interval_ms.is_some(),
         })
     }
 
@@ -824,16 +2079,55 @@ impl VTab for NetworkVTab {
             let net = &init_data.network_data[current + i];
             
             output.flat_vector(0).insert(i, CString::new(net.interface_name.clone())?);
-            output.flat_vector(1).insert(i, CString::new(net.mac_address.clone())?);
+            match &net.mac_address {
+                Some(mac) => output.flat_vector(1).insert(i, CString::new(mac.clone())?),
+                None => output.flat_vector(1).set_null(i),
+            }
             output.flat_vector(2).as_mut_slice::<f64>()[i] = unit.convert(net.rx_bytes);
             output.flat_vector(3).as_mut_slice::<f64>()[i] = unit.convert(net.tx_bytes);
             output.flat_vector(4).as_mut_slice::<u64>()[i] = net.rx_packets;
             output.flat_vector(5).as_mut_slice::<u64>()[i] = net.tx_packets;
             output.flat_vector(6).as_mut_slice::<u64>()[i] = net.rx_errors;
             output.flat_vector(7).as_mut_slice::<u64>()[i] = net.tx_errors;
-            output.flat_vector(8).insert(i, CString::new(unit.name())?);
+            for (col, counter) in [net.link.rx_dropped, net.link.tx_dropped, net.link.multicast].into_iter().enumerate() {
+                match counter {
+                    Some(value) => output.flat_vector(8 + col).as_mut_slice::<u64>()[i] = value,
+                    None => output.flat_vector(8 + col).set_null(i),
+                }
+            }
+            match net.link.mtu {
+                Some(mtu) => output.flat_vector(12).as_mut_slice::<i32>()[i] = mtu,
+                None => output.flat_vector(12).set_null(i),
+            }
+            match net.link.speed_mbps {
+                Some(speed) => output.flat_vector(13).as_mut_slice::<i32>()[i] = speed,
+                None => output.flat_vector(13).set_null(i),
+            }
+            match net.link.is_up {
+                Some(is_up) => output.flat_vector(14).as_mut_slice::<bool>()[i] = is_up,
+                None => output.flat_vector(14).set_null(i),
+            }
+            match &net.link.operstate {
+                Some(operstate) => output.flat_vector(15).insert(i, CString::new(operstate.clone())?),
+                None => output.flat_vector(15).set_null(i),
+            }
+            if init_data.with_rates {
+                output.flat_vector(16).as_mut_slice::<f64>()[i] = net.rates.rx_bytes_per_sec;
+                output.flat_vector(17).as_mut_slice::<f64>()[i] = net.rates.tx_bytes_per_sec;
+                output.flat_vector(18).as_mut_slice::<f64>()[i] = net.rates.rx_packets_per_sec;
+                output.flat_vector(19).as_mut_slice::<f64>()[i] = net.rates.tx_packets_per_sec;
+                output.flat_vector(20).insert(i, CString::new(unit.name())?);
+            } else {
+                output.flat_vector(16).insert(i, CString::new(unit.name())?);
+            }
         }
         
+        let ip_addresses: Vec<&[String]> = init_data.network_data[current..current + batch_size]
+            .iter()
+            .map(|net| net.ip_addresses.as_slice())
+            .collect();
+        insert_varchar_lists(&mut output.list_vector(11), &ip_addresses)?;
+        
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
         output.set_len(batch_size);
         Ok(())
@@ -844,7 +2138,12 @@ impl VTab for NetworkVTab {
     }
     
     fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
-        Some(vec![("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+        Some(vec![
+            ("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+            ("interval_ms".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+            ("interface".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+            ("exclude_loopback".to_string(), LogicalTypeHandle::from(LogicalTypeId::Boolean)),
+        ])
     }
 }
 
@@ -856,6 +2155,7 @@ impl VTab for NetworkVTab {
 #[repr(C)]
 struct ProcessesBindData {
     unit: SizeUnit,
+    page: Page,
 }
 
 #[repr(C)]
@@ -864,6 +2164,7 @@ struct ProcessesInitData {
     process_count: usize,
     process_data: Vec<ProcessInfo>,
     total_memory: u64,
+    cgroup_memory_limit: Option<u64>,
     unit: SizeUnit,
 }
 
@@ -877,6 +2178,45 @@ struct ProcessInfo {
     start_time: u64,
     run_time: u64,
     user: String,
+    child_count: u32,
+    cmd: Vec<String>,
+    listening_ports: Vec<i32>,
+    security_context: Option<String>,
+}
+
+/// Read a process's SELinux label, e.g. "system_u:system_r:sshd_t:s0-s0:c0.c1023"
+#[cfg(target_os = "linux")]
+fn read_security_context(pid: u32) -> Option<String> {
+    let raw = std::fs::read(format!("/proc/{}/attr/current", pid)).ok()?;
+    let context = String::from_utf8_lossy(&raw).trim_end_matches(['\0', '\n']).to_string();
+    if context.is_empty() { None } else { Some(context) }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_security_context(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Quote an argument for a POSIX shell, leaving plain words untouched
+fn shell_quote(arg: &str) -> String {
+    let is_plain = !arg.is_empty()
+        && arg.chars().all(|c| c.is_ascii_alphanumeric() || "_@%+=:,./-".contains(c));
+    if is_plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Identify a process across snapshots, even after its PID is recycled.
+/// FNV-1a over pid and start time, so the value is stable across sessions and builds.
+fn process_snapshot_key(pid: u32, start_time: u64) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in pid.to_le_bytes().iter().chain(start_time.to_le_bytes().iter()) {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
 }
 
 struct ProcessesVTab;
@@ -904,14 +2244,22 @@ impl VTab for ProcessesVTab {
         bind.add_result_column("start_time", LogicalTypeHandle::from(LogicalTypeId::UBigint));
         bind.add_result_column("run_time_seconds", LogicalTypeHandle::from(LogicalTypeId::UBigint));
         bind.add_result_column("user", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("child_count", LogicalTypeHandle::from(LogicalTypeId::UInteger));
+        bind.add_result_column("cgroup_memory_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("memory_snapshot_key", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("command_line", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("full_command", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("listening_ports", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Integer)));
+        bind.add_result_column("security_context", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         
-        Ok(ProcessesBindData { unit })
+        Ok(ProcessesBindData { unit, page: Page::from_bind(bind) })
     }
 
     fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
         let bind_data = init.get_bind_data::<ProcessesBindData>();
         let unit = unsafe { (*bind_data).unit };
+        let page = unsafe { (*bind_data).page };
         
         let mut sys = System::new_with_specifics(
             RefreshKind::new()
@@ -924,7 +2272,48 @@ impl VTab for ProcessesVTab {
         
         let total_memory = sys.total_memory();
         
-        let process_data: Vec<ProcessInfo> = sys.processes().iter().map(|(pid, proc)| {
+        // Memory limit of the cgroup we run in, if any (e.g. inside a container)
+        #[cfg(target_os = "linux")]
+        let cgroup_memory_limit = read_cgroup_memory().and_then(|(limit, _)| limit);
+        
+        #[cfg(not(target_os = "linux"))]
+        let cgroup_memory_limit: Option<u64> = None;
+        
+        // Count direct children per parent PID
+        let mut child_counts: std::collections::HashMap<sysinfo::Pid, u32> = std::collections::HashMap::new();
+        for proc in sys.processes().values() {
+            if let Some(parent) = proc.parent() {
+                *child_counts.entry(parent).or_insert(0) += 1;
+            }
+        }
+        
+        // TCP ports each process listens on, from the socket table's owning PIDs
+        let mut listening_ports: std::collections::HashMap<u32, std::collections::BTreeSet<u16>> = std::collections::HashMap::new();
+        {
+            use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo, TcpState};
+            
+            let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+            if let Ok(sockets) = get_sockets_info(af_flags, ProtocolFlags::TCP) {
+                for socket in sockets {
+                    if let ProtocolSocketInfo::Tcp(tcp) = &socket.protocol_socket_info {
+                        if tcp.state != TcpState::Listen {
+                            continue;
+                        }
+                        for pid in &socket.associated_pids {
+                            listening_ports.entry(*pid).or_default().insert(tcp.local_port);
+                        }
+                    }
+                }
+            }
+        }
+        
+        // attr/current also holds AppArmor/Smack labels, so only trust it under SELinux
+        #[cfg(target_os = "linux")]
+        let selinux_enabled = std::path::Path::new("/sys/fs/selinux/enforce").exists();
+        #[cfg(not(target_os = "linux"))]
+        let selinux_enabled = false;
+        
+        let mut process_data: Vec<ProcessInfo> = sys.processes().iter().map(|(pid, proc)| {
             let status_str = match proc.status() {
                 ProcessStatus::Run => "Running",
                 ProcessStatus::Sleep => "Sleeping",
@@ -949,16 +2338,25 @@ impl VTab for ProcessesVTab {
                 start_time: proc.start_time(),
                 run_time: proc.run_time(),
                 user: user_str,
+                child_count: child_counts.get(pid).copied().unwrap_or(0),
+                cmd: proc.cmd().iter().map(|arg| arg.to_string_lossy().to_string()).collect(),
+                listening_ports: listening_ports.get(&pid.as_u32())
+                    .map(|ports| ports.iter().map(|&port| port as i32).collect())
+                    .unwrap_or_default(),
+                security_context: if selinux_enabled { read_security_context(pid.as_u32()) } else { None },
             }
         }).collect();
         
-        let process_count = process_data.len();
+        // Stable order so that offset/limit pages line up across queries
+        process_data.sort_unstable_by_key(|proc| proc.pid);
+        let (start, process_count) = page.bounds(process_data.len());
         
         Ok(ProcessesInitData {
-            current_idx: AtomicUsize::new(0),
+            current_idx: AtomicUsize::new(start),
             process_count,
             process_data,
             total_memory,
+            cgroup_memory_limit,
             unit,
         })
     }
@@ -993,9 +2391,36 @@ impl VTab for ProcessesVTab {
             output.flat_vector(7).as_mut_slice::<u64>()[i] = proc.start_time;
             output.flat_vector(8).as_mut_slice::<u64>()[i] = proc.run_time;
             output.flat_vector(9).insert(i, CString::new(proc.user.clone())?);
-            output.flat_vector(10).insert(i, CString::new(unit.name())?);
+            output.flat_vector(10).as_mut_slice::<u32>()[i] = proc.child_count;
+            match init_data.cgroup_memory_limit {
+                Some(limit) if limit > 0 => {
+                    output.flat_vector(11).as_mut_slice::<f32>()[i] = (proc.memory_bytes as f32 / limit as f32) * 100.0;
+                }
+                _ => output.flat_vector(11).set_null(i),
+            }
+            output.flat_vector(12).as_mut_slice::<u64>()[i] = process_snapshot_key(proc.pid, proc.start_time);
+            // Kernel threads and processes we may not inspect have no argv
+            if proc.cmd.is_empty() {
+                output.flat_vector(13).set_null(i);
+                output.flat_vector(14).set_null(i);
+            } else {
+                output.flat_vector(13).insert(i, CString::new(proc.cmd.join(" "))?);
+                let quoted: Vec<String> = proc.cmd.iter().map(|arg| shell_quote(arg)).collect();
+                output.flat_vector(14).insert(i, CString::new(quoted.join(" "))?);
+            }
+            match &proc.security_context {
+                Some(context) => output.flat_vector(16).insert(i, CString::new(context.clone())?),
+                None => output.flat_vector(16).set_null(i),
+            }
+            output.flat_vector(17).insert(i, CString::new(unit.name())?);
         }
         
+        let listening_ports: Vec<&[i32]> = init_data.process_data[current..current + batch_size]
+            .iter()
+            .map(|proc| proc.listening_ports.as_slice())
+            .collect();
+        insert_integer_lists(&mut output.list_vector(15), &listening_ports);
+        
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
         output.set_len(batch_size);
         Ok(())
@@ -1006,7 +2431,9 @@ impl VTab for ProcessesVTab {
     }
     
     fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
-        Some(vec![("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+        let mut params = vec![("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))];
+        params.extend(Page::named_parameters());
+        Some(params)
     }
 }
 
@@ -1024,6 +2451,7 @@ struct LoadInitData {
     load_1: f64,
     load_5: f64,
     load_15: f64,
+    cpu_count: usize,
 }
 
 struct LoadVTab;
@@ -1036,17 +2464,26 @@ impl VTab for LoadVTab {
         bind.add_result_column("load_1min", LogicalTypeHandle::from(LogicalTypeId::Double));
         bind.add_result_column("load_5min", LogicalTypeHandle::from(LogicalTypeId::Double));
         bind.add_result_column("load_15min", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("cpu_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("load_1min_per_cpu", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("load_5min_per_cpu", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("load_15min_per_cpu", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("saturated", LogicalTypeHandle::from(LogicalTypeId::Boolean));
         Ok(LoadBindData)
     }
 
     fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
         let load = System::load_average();
+        let sys = System::new_with_specifics(
+            RefreshKind::new().with_cpu(CpuRefreshKind::new())
+        );
         
         Ok(LoadInitData {
             done: AtomicBool::new(false),
             load_1: load.one,
             load_5: load.five,
             load_15: load.fifteen,
+            cpu_count: sys.cpus().len(),
         })
     }
 
@@ -1061,6 +2498,20 @@ impl VTab for LoadVTab {
         output.flat_vector(0).as_mut_slice::<f64>()[0] = init_data.load_1;
         output.flat_vector(1).as_mut_slice::<f64>()[0] = init_data.load_5;
         output.flat_vector(2).as_mut_slice::<f64>()[0] = init_data.load_15;
+        output.flat_vector(3).as_mut_slice::<u64>()[0] = init_data.cpu_count as u64;
+        
+        if init_data.cpu_count > 0 {
+            let cpus = init_data.cpu_count as f64;
+            output.flat_vector(4).as_mut_slice::<f64>()[0] = init_data.load_1 / cpus;
+            output.flat_vector(5).as_mut_slice::<f64>()[0] = init_data.load_5 / cpus;
+            output.flat_vector(6).as_mut_slice::<f64>()[0] = init_data.load_15 / cpus;
+            // More runnable tasks than logical CPUs over the last minute
+            output.flat_vector(7).as_mut_slice::<bool>()[0] = init_data.load_1 / cpus > 1.0;
+        } else {
+            for col in 4..8 {
+                output.flat_vector(col).set_null(0);
+            }
+        }
         
         output.set_len(1);
         Ok(())
@@ -1090,6 +2541,34 @@ struct UserInfo {
     uid: String,
     gid: String,
     name: String,
+    is_logged_in: bool,
+}
+
+/// Get the names of users that currently have a login session, from the same
+/// utmp records as sazgar_sessions()
+#[cfg(target_os = "linux")]
+fn logged_in_user_names() -> std::collections::HashSet<String> {
+    read_sessions().into_iter().map(|session| session.user).collect()
+}
+
+/// Get the names of users that currently have a login session (via `who`;
+/// utmp-rs only understands the Linux utmp format)
+#[cfg(not(target_os = "linux"))]
+fn logged_in_user_names() -> std::collections::HashSet<String> {
+    #[cfg(unix)]
+    {
+        if let Ok(output) = std::process::Command::new("who").output() {
+            if output.status.success() {
+                return String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .filter_map(|line| line.split_whitespace().next())
+                    .map(|name| name.to_string())
+                    .collect();
+            }
+        }
+    }
+    
+    std::collections::HashSet::new()
 }
 
 struct UsersVTab;
@@ -1102,17 +2581,20 @@ impl VTab for UsersVTab {
         bind.add_result_column("uid", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("gid", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("is_logged_in", LogicalTypeHandle::from(LogicalTypeId::Boolean));
         Ok(UsersBindData)
     }
 
     fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
         let users = sysinfo::Users::new_with_refreshed_list();
+        let logged_in = logged_in_user_names();
         
         let user_data: Vec<UserInfo> = users.iter().map(|user| {
             UserInfo {
                 uid: user.id().to_string(),
                 gid: user.group_id().to_string(),
                 name: user.name().to_string(),
+                is_logged_in: logged_in.contains(user.name()),
             }
         }).collect();
         
@@ -1142,6 +2624,7 @@ impl VTab for UsersVTab {
             output.flat_vector(0).insert(i, CString::new(user.uid.clone())?);
             output.flat_vector(1).insert(i, CString::new(user.gid.clone())?);
             output.flat_vector(2).insert(i, CString::new(user.name.clone())?);
+            output.flat_vector(3).as_mut_slice::<bool>()[i] = user.is_logged_in;
         }
         
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
@@ -1249,6 +2732,8 @@ impl VTab for ComponentsVTab {
 #[repr(C)]
 struct EnvironmentBindData {
     filter: Option<String>,
+    exact: bool,
+    pattern: Option<globset::GlobMatcher>,
 }
 
 struct EnvVar {
@@ -1281,20 +2766,34 @@ impl VTab for EnvironmentVTab {
             None
         };
         
-        Ok(EnvironmentBindData { filter })
+        // Match the whole name, case-sensitively, instead of any substring (default: false)
+        let exact = bind.get_named_parameter("exact")
+            .map(|v| v.to_string().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        
+        // Glob on the whole name, e.g. `AWS_*`; combined with the filter when both are given
+        let pattern = bind.get_named_parameter("pattern")
+            .map(|v| compile_glob("sazgar_environment", &v.to_string()))
+            .transpose()?;
+        
+        Ok(EnvironmentBindData { filter, exact, pattern })
     }
 
     fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
         let bind_data = init.get_bind_data::<EnvironmentBindData>();
         let filter = unsafe { (*bind_data).filter.clone() };
+        let exact = unsafe { (*bind_data).exact };
+        let pattern = unsafe { (*bind_data).pattern.clone() };
         
         let env_data: Vec<EnvVar> = std::env::vars()
             .filter(|(name, _)| {
                 match &filter {
+                    Some(f) if exact => name == f,
                     Some(f) => name.to_lowercase().contains(&f.to_lowercase()),
                     None => true,
                 }
             })
+            .filter(|(name, _)| pattern.as_ref().is_none_or(|p| p.is_match(name)))
             .map(|(name, value)| EnvVar { name, value })
             .collect();
         
@@ -1332,6 +2831,13 @@ impl VTab for EnvironmentVTab {
     fn parameters() -> Option<Vec<LogicalTypeHandle>> {
         Some(vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)])
     }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("exact".to_string(), LogicalTypeHandle::from(LogicalTypeId::Boolean)),
+            ("pattern".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+        ])
+    }
 }
 
 // ============================================================================
@@ -1414,6 +2920,44 @@ impl VTab for UptimeVTab {
 #[repr(C)]
 struct PortsBindData {
     protocol_filter: Option<String>,
+    listening_only: bool,
+    port_min: i64,
+    port_max: i64,
+    remote_ports: Option<std::ops::RangeInclusive<u16>>,
+    page: Page,
+}
+
+/// Normalize a sazgar_ports() protocol filter to `TCP`/`UDP`; empty means both
+fn parse_protocol_filter(value: &str) -> Result<Option<String>, String> {
+    let cleaned = value.trim_matches('"').trim().to_uppercase();
+    match cleaned.as_str() {
+        "" => Ok(None),
+        "TCP" | "UDP" => Ok(Some(cleaned)),
+        _ => Err(format!("sazgar_ports: protocol must be 'tcp', 'udp' or '' for both, got '{}'", value.trim_matches('"'))),
+    }
+}
+
+/// Parse a sazgar_ports() port filter: a single port (`5432`) or an
+/// inclusive range (`8000-8100`), each end within 1-65535
+fn parse_port_filter(name: &str, value: &str) -> Result<std::ops::RangeInclusive<u16>, String> {
+    let value = value.trim();
+    let parse_port = |text: &str| -> Result<u16, String> {
+        match text.trim().parse::<i64>() {
+            Ok(port) if (1..=65535).contains(&port) => Ok(port as u16),
+            Ok(port) => Err(format!("sazgar_ports: {} must be between 1 and 65535, got {}", name, port)),
+            Err(_) => Err(format!("sazgar_ports: {} must be a port like 5432 or a range like '8000-8100', got '{}'", name, value)),
+        }
+    };
+    match value.split_once('-') {
+        Some((low, high)) => {
+            let (low, high) = (parse_port(low)?, parse_port(high)?);
+            if low > high {
+                return Err(format!("sazgar_ports: {} range must not be reversed, got '{}'", name, value));
+            }
+            Ok(low..=high)
+        }
+        None => parse_port(value).map(|port| port..=port),
+    }
 }
 
 struct PortInfo {
@@ -1425,6 +2969,8 @@ struct PortInfo {
     state: String,
     pid: Option<u32>,
     process_name: String,
+    address_family: &'static str,
+    all_pids: Vec<i32>,
 }
 
 #[repr(C)]
@@ -1434,6 +2980,125 @@ struct PortsInitData {
     port_data: Vec<PortInfo>,
 }
 
+/// State reported for UDP sockets, which are connectionless: every socket
+/// netstat2 lists is bound to a local port and can receive datagrams
+const UDP_SOCKET_STATE: &str = "Bound";
+
+/// Enumerate TCP/UDP sockets with their owning processes, applying the
+/// sazgar_ports() filters while streaming so that sockets filtered out
+/// (e.g. tens of thousands of TIME_WAIT entries) are never materialized
+fn read_ports(
+    protocol_filter: Option<&str>,
+    listening_only: bool,
+    port_range: std::ops::RangeInclusive<i64>,
+    remote_ports: Option<std::ops::RangeInclusive<u16>>,
+) -> Vec<PortInfo> {
+    use netstat2::{iterate_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo, TcpState};
+    
+    // Get process info for name lookup
+    let sys = System::new_with_specifics(
+        RefreshKind::new().with_processes(ProcessRefreshKind::new())
+    );
+    
+    let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+    // Only ask the kernel for the protocols that survive the filter
+    let proto_flags = match protocol_filter {
+        Some("TCP") => ProtocolFlags::TCP,
+        Some("UDP") => ProtocolFlags::UDP,
+        Some(_) => return Vec::new(),
+        None => ProtocolFlags::TCP | ProtocolFlags::UDP,
+    };
+    
+    let mut port_data: Vec<PortInfo> = Vec::new();
+    
+    let Ok(sockets) = iterate_sockets_info(af_flags, proto_flags) else {
+        return port_data;
+    };
+    for socket in sockets.flatten() {
+        let (protocol, local_port) = match &socket.protocol_socket_info {
+            ProtocolSocketInfo::Tcp(tcp) => {
+                if listening_only && tcp.state != TcpState::Listen { continue; }
+                ("TCP", tcp.local_port)
+            }
+            ProtocolSocketInfo::Udp(udp) => ("UDP", udp.local_port),
+        };
+        if !port_range.contains(&(local_port as i64)) {
+            continue;
+        }
+        
+        let (remote_address, remote_port, state) = match &socket.protocol_socket_info {
+            ProtocolSocketInfo::Tcp(tcp) => (tcp.remote_addr.to_string(), tcp.remote_port, format!("{:?}", tcp.state)),
+            ProtocolSocketInfo::Udp(_) => (String::new(), 0, UDP_SOCKET_STATE.to_string()),
+        };
+        // UDP sockets have no remote end, so a remote port filter drops them
+        if remote_ports.as_ref().is_some_and(|ports| !ports.contains(&remote_port)) {
+            continue;
+        }
+        
+        let pids = &socket.associated_pids;
+        let pid = pids.first().copied();
+        
+        let process_name = pid
+            .and_then(|p| sys.process(sysinfo::Pid::from_u32(p)))
+            .map(|proc| proc.name().to_string_lossy().to_string())
+            .unwrap_or_default();
+        
+        let address_family = if socket.local_addr().is_ipv4() { "IPv4" } else { "IPv6" };
+        
+        port_data.push(PortInfo {
+            protocol: protocol.to_string(),
+            local_address: socket.local_addr().to_string(),
+            local_port,
+            remote_address,
+            remote_port,
+            state,
+            pid,
+            process_name,
+            address_family,
+            // Forked servers share one listening socket across many processes
+            all_pids: pids.iter().map(|&p| p as i32).collect(),
+        });
+    }
+    
+    port_data
+}
+
+/// Emit the next batch of sazgar_ports() rows, shared with sazgar_ports_legacy()
+fn write_port_rows(init_data: &PortsInitData, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+    let current = init_data.current_idx.load(Ordering::Relaxed);
+    
+    if current >= init_data.port_count {
+        output.set_len(0);
+        return Ok(());
+    }
+    
+    let batch_size = std::cmp::min(2048, init_data.port_count - current);
+    
+    for i in 0..batch_size {
+        let port = &init_data.port_data[current + i];
+        
+        output.flat_vector(0).insert(i, CString::new(port.protocol.clone())?);
+        output.flat_vector(1).insert(i, CString::new(port.local_address.clone())?);
+        output.flat_vector(2).as_mut_slice::<i32>()[i] = port.local_port as i32;
+        output.flat_vector(3).insert(i, CString::new(port.remote_address.clone())?);
+        output.flat_vector(4).as_mut_slice::<i32>()[i] = port.remote_port as i32;
+        output.flat_vector(5).insert(i, CString::new(port.state.clone())?);
+        output.flat_vector(6).as_mut_slice::<i32>()[i] = port.pid.unwrap_or(0) as i32;
+        output.flat_vector(7).insert(i, CString::new(port.process_name.clone())?);
+        output.flat_vector(8).insert(i, CString::new(port.address_family)?);
+    }
+    
+    let all_pids: Vec<&[i32]> = init_data.port_data[current..current + batch_size]
+        .iter()
+        .map(|port| port.all_pids.as_slice())
+        .collect();
+    insert_integer_lists(&mut output.list_vector(9), &all_pids);
+    
+    init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+    output.set_len(batch_size);
+    Ok(())
+}
+
 struct PortsVTab;
 
 impl VTab for PortsVTab {
@@ -1449,149 +3114,401 @@ impl VTab for PortsVTab {
         bind.add_result_column("state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("pid", LogicalTypeHandle::from(LogicalTypeId::Integer));
         bind.add_result_column("process_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("address_family", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("all_pids", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Integer)));
         
-        let protocol_filter = if bind.get_parameter_count() > 0 {
-            let param = bind.get_parameter(0).to_string();
-            let cleaned = param.trim_matches('"').to_uppercase();
-            if cleaned.is_empty() { None } else { Some(cleaned) }
-        } else {
-            None
+        let protocol_filter = match bind.get_named_parameter("protocol") {
+            Some(v) => parse_protocol_filter(&v.to_string())?,
+            None => None,
+        };
+        
+        // TCP sockets must be listening; UDP is connectionless so every socket counts
+        let listening_only = bind.get_named_parameter("listening_only")
+            .map(|v| v.to_string().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        
+        // Inclusive local port range; a missing bound is open-ended
+        let port_min = bind.get_named_parameter("port_min").map(|v| v.to_int64()).unwrap_or(i64::MIN);
+        let port_max = bind.get_named_parameter("port_max").map(|v| v.to_int64()).unwrap_or(i64::MAX);
+        
+        // `port := 5432` or `port := '8000-8100'` narrows the local range further
+        let (port_min, port_max) = match bind.get_named_parameter("port") {
+            Some(v) => {
+                let ports = parse_port_filter("port", &v.to_string())?;
+                (port_min.max(*ports.start() as i64), port_max.min(*ports.end() as i64))
+            }
+            None => (port_min, port_max),
         };
+        let remote_ports = bind.get_named_parameter("remote_port")
+            .map(|v| parse_port_filter("remote_port", &v.to_string()))
+            .transpose()?;
         
-        Ok(PortsBindData { protocol_filter })
+        Ok(PortsBindData { protocol_filter, listening_only, port_min, port_max, remote_ports, page: Page::from_bind(bind) })
     }
 
     fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
-        use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
-        
         let bind_data = init.get_bind_data::<PortsBindData>();
         let protocol_filter = unsafe { (*bind_data).protocol_filter.clone() };
+        let listening_only = unsafe { (*bind_data).listening_only };
+        let port_range = unsafe { (*bind_data).port_min..=(*bind_data).port_max };
+        let remote_ports = unsafe { (*bind_data).remote_ports.clone() };
+        let page = unsafe { (*bind_data).page };
         
-        // Get process info for name lookup
-        let sys = System::new_with_specifics(
-            RefreshKind::new().with_processes(ProcessRefreshKind::new())
-        );
-        
-        let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
-        let proto_flags = ProtocolFlags::TCP | ProtocolFlags::UDP;
-        
-        let mut port_data: Vec<PortInfo> = Vec::new();
+        let mut port_data = read_ports(protocol_filter.as_deref(), listening_only, port_range, remote_ports);
         
-        if let Ok(sockets) = get_sockets_info(af_flags, proto_flags) {
-            for socket in sockets {
-                let (protocol, local_addr, local_port, remote_addr, remote_port, state) = 
-                    match &socket.protocol_socket_info {
-                        ProtocolSocketInfo::Tcp(tcp) => {
-                            if let Some(ref filter) = protocol_filter {
-                                if filter != "TCP" { continue; }
-                            }
-                            (
-                                "TCP".to_string(),
-                                tcp.local_addr.to_string(),
-                                tcp.local_port,
-                                tcp.remote_addr.to_string(),
-                                tcp.remote_port,
-                                format!("{:?}", tcp.state),
-                            )
-                        }
-                        ProtocolSocketInfo::Udp(udp) => {
-                            if let Some(ref filter) = protocol_filter {
-                                if filter != "UDP" { continue; }
-                            }
-                            (
-                                "UDP".to_string(),
-                                udp.local_addr.to_string(),
-                                udp.local_port,
-                                "".to_string(),
-                                0,
-                                "".to_string(),
-                            )
-                        }
-                    };
-                
-                let pids = &socket.associated_pids;
-                let pid = pids.first().copied();
-                
-                let process_name = pid
-                    .and_then(|p| sys.process(sysinfo::Pid::from_u32(p)))
-                    .map(|proc| proc.name().to_string_lossy().to_string())
-                    .unwrap_or_default();
-                
-                port_data.push(PortInfo {
-                    protocol,
-                    local_address: local_addr,
-                    local_port,
-                    remote_address: remote_addr,
-                    remote_port,
-                    state,
-                    pid,
-                    process_name,
-                });
-            }
-        }
-        
-        let port_count = port_data.len();
+        // Stable order so that offset/limit pages line up across queries
+        port_data.sort_by(|a, b| {
+            (&a.protocol, a.local_port, &a.local_address, &a.remote_address, a.remote_port)
+                .cmp(&(&b.protocol, b.local_port, &b.local_address, &b.remote_address, b.remote_port))
+        });
+        let (start, port_count) = page.bounds(port_data.len());
         
         Ok(PortsInitData {
-            current_idx: AtomicUsize::new(0),
+            current_idx: AtomicUsize::new(start),
             port_count,
             port_data,
         })
     }
 
     fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
-        let init_data = func.get_init_data();
-        let current = init_data.current_idx.load(Ordering::Relaxed);
-        
-        if current >= init_data.port_count {
-            output.set_len(0);
-            return Ok(());
-        }
-        
-        let batch_size = std::cmp::min(2048, init_data.port_count - current);
-        
-        for i in 0..batch_size {
-            let port = &init_data.port_data[current + i];
-            
-            output.flat_vector(0).insert(i, CString::new(port.protocol.clone())?);
-            output.flat_vector(1).insert(i, CString::new(port.local_address.clone())?);
-            output.flat_vector(2).as_mut_slice::<i32>()[i] = port.local_port as i32;
-            output.flat_vector(3).insert(i, CString::new(port.remote_address.clone())?);
-            output.flat_vector(4).as_mut_slice::<i32>()[i] = port.remote_port as i32;
-            output.flat_vector(5).insert(i, CString::new(port.state.clone())?);
-            output.flat_vector(6).as_mut_slice::<i32>()[i] = port.pid.unwrap_or(0) as i32;
-            output.flat_vector(7).insert(i, CString::new(port.process_name.clone())?);
-        }
-        
-        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
-        output.set_len(batch_size);
-        Ok(())
+        write_port_rows(func.get_init_data(), output)
     }
 
-    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
-        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)])
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        let mut params = vec![
+            ("protocol".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+            ("listening_only".to_string(), LogicalTypeHandle::from(LogicalTypeId::Boolean)),
+            ("port_min".to_string(), LogicalTypeHandle::from(LogicalTypeId::Integer)),
+            ("port_max".to_string(), LogicalTypeHandle::from(LogicalTypeId::Integer)),
+            ("port".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+            ("remote_port".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+        ];
+        params.extend(Page::named_parameters());
+        Some(params)
     }
 }
 
-// ============================================================================
-// GPU Table Function - sazgar_gpu() 
-// Returns GPU information (NVIDIA GPUs when feature enabled)
-// ============================================================================
+/// sazgar_ports_legacy(protocol_filter): the old positional form of
+/// sazgar_ports(), kept for one release. DuckDB can't register a table
+/// function with an optional positional, so it needs a name of its own.
+struct PortsLegacyVTab;
 
-#[repr(C)]
-struct GpuBindData;
+impl VTab for PortsLegacyVTab {
+    type InitData = PortsInitData;
+    type BindData = PortsBindData;
 
-struct GpuInfo {
-    index: u32,
-    name: String,
-    driver_version: String,
-    memory_total_mb: u64,
-    memory_used_mb: u64,
-    memory_free_mb: u64,
-    temperature_celsius: Option<u32>,
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        let positional = parse_protocol_filter(&bind.get_parameter(0).to_string())?;
+        let mut bind_data = PortsVTab::bind(bind)?;
+        match (positional, &bind_data.protocol_filter) {
+            (Some(positional), Some(named)) if positional != *named => {
+                return Err(format!(
+                    "sazgar_ports_legacy: positional filter '{}' conflicts with protocol := '{}'",
+                    positional.to_lowercase(), named.to_lowercase()
+                ).into());
+            }
+            (Some(positional), None) => bind_data.protocol_filter = Some(positional),
+            _ => {}
+        }
+        Ok(bind_data)
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        PortsVTab::init(init)
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        write_port_rows(func.get_init_data(), output)
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)])
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        PortsVTab::named_parameters()
+    }
+}
+
+// ============================================================================
+// Socket Summary Table Function - sazgar_socket_summary()
+// Returns socket counts per protocol and state, like `ss -s`
+// ============================================================================
+
+#[repr(C)]
+struct SocketSummaryBindData;
+
+struct SocketSummaryInfo {
+    protocol: String,
+    state: String,
+    ipv4_count: u64,
+    ipv6_count: u64,
+}
+
+#[repr(C)]
+struct SocketSummaryInitData {
+    current_idx: AtomicUsize,
+    summary_count: usize,
+    summary_data: Vec<SocketSummaryInfo>,
+}
+
+/// Count sockets per (protocol, state), followed by TOTAL rows for listening
+/// sockets (TCP in Listen plus every UDP socket) and established connections
+fn summarize_sockets(ports: &[PortInfo]) -> Vec<SocketSummaryInfo> {
+    let mut counts: std::collections::BTreeMap<(&str, &str), (u64, u64)> = std::collections::BTreeMap::new();
+    let (mut listening, mut established) = ((0, 0), (0, 0));
+    
+    for port in ports {
+        let is_ipv4 = port.address_family == "IPv4";
+        let add = |count: &mut (u64, u64)| if is_ipv4 { count.0 += 1 } else { count.1 += 1 };
+        add(counts.entry((port.protocol.as_str(), port.state.as_str())).or_default());
+        match (port.protocol.as_str(), port.state.as_str()) {
+            ("UDP", _) | ("TCP", "Listen") => add(&mut listening),
+            ("TCP", "Established") => add(&mut established),
+            _ => {}
+        }
+    }
+    
+    let mut summary: Vec<SocketSummaryInfo> = counts.into_iter().map(|((protocol, state), (ipv4_count, ipv6_count))| {
+        SocketSummaryInfo { protocol: protocol.to_string(), state: state.to_string(), ipv4_count, ipv6_count }
+    }).collect();
+    for (state, (ipv4_count, ipv6_count)) in [("Listen", listening), ("Established", established)] {
+        summary.push(SocketSummaryInfo { protocol: "TOTAL".to_string(), state: state.to_string(), ipv4_count, ipv6_count });
+    }
+    summary
+}
+
+struct SocketSummaryVTab;
+
+impl VTab for SocketSummaryVTab {
+    type InitData = SocketSummaryInitData;
+    type BindData = SocketSummaryBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("protocol", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("ipv4_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("ipv6_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        Ok(SocketSummaryBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let summary_data = summarize_sockets(&read_ports(None, false, i64::MIN..=i64::MAX, None));
+        let summary_count = summary_data.len();
+        
+        Ok(SocketSummaryInitData {
+            current_idx: AtomicUsize::new(0),
+            summary_count,
+            summary_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.summary_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.summary_count - current);
+        
+        for i in 0..batch_size {
+            let summary = &init_data.summary_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(summary.protocol.clone())?);
+            output.flat_vector(1).insert(i, CString::new(summary.state.clone())?);
+            output.flat_vector(2).as_mut_slice::<u64>()[i] = summary.ipv4_count + summary.ipv6_count;
+            output.flat_vector(3).as_mut_slice::<u64>()[i] = summary.ipv4_count;
+            output.flat_vector(4).as_mut_slice::<u64>()[i] = summary.ipv6_count;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Connections By Remote Table Function - sazgar_connections_by_remote()
+// Returns TCP connections grouped by remote address
+// ============================================================================
+
+#[repr(C)]
+struct ConnectionsByRemoteBindData {
+    min_count: u64,
+}
+
+struct RemoteConnectionsInfo {
+    remote_address: String,
+    connection_count: u64,
+    established_count: u64,
+    time_wait_count: u64,
+    distinct_local_ports: u64,
+    processes: Vec<String>,
+}
+
+#[repr(C)]
+struct ConnectionsByRemoteInitData {
+    current_idx: AtomicUsize,
+    remote_count: usize,
+    remote_data: Vec<RemoteConnectionsInfo>,
+}
+
+/// Group TCP connections by remote address, busiest first. Listening sockets
+/// have no remote end, so they are skipped.
+fn group_connections_by_remote(ports: &[PortInfo], min_count: u64) -> Vec<RemoteConnectionsInfo> {
+    #[derive(Default)]
+    struct Group<'a> {
+        connection_count: u64,
+        established_count: u64,
+        time_wait_count: u64,
+        local_ports: std::collections::BTreeSet<u16>,
+        processes: std::collections::BTreeSet<&'a str>,
+    }
+    
+    let mut groups: std::collections::HashMap<&str, Group> = std::collections::HashMap::new();
+    for port in ports.iter().filter(|port| port.protocol == "TCP" && port.state != "Listen") {
+        let is_unspecified = port.remote_address.parse::<std::net::IpAddr>().is_ok_and(|ip| ip.is_unspecified());
+        if port.remote_address.is_empty() || is_unspecified {
+            continue;
+        }
+        
+        let group = groups.entry(port.remote_address.as_str()).or_default();
+        group.connection_count += 1;
+        match port.state.as_str() {
+            "Established" => group.established_count += 1,
+            "TimeWait" => group.time_wait_count += 1,
+            _ => {}
+        }
+        group.local_ports.insert(port.local_port);
+        if !port.process_name.is_empty() {
+            group.processes.insert(port.process_name.as_str());
+        }
+    }
+    
+    let mut remotes: Vec<RemoteConnectionsInfo> = groups.into_iter()
+        .filter(|(_, group)| group.connection_count >= min_count)
+        .map(|(remote_address, group)| RemoteConnectionsInfo {
+            remote_address: remote_address.to_string(),
+            connection_count: group.connection_count,
+            established_count: group.established_count,
+            time_wait_count: group.time_wait_count,
+            distinct_local_ports: group.local_ports.len() as u64,
+            processes: group.processes.into_iter().map(|name| name.to_string()).collect(),
+        })
+        .collect();
+    remotes.sort_by(|a, b| b.connection_count.cmp(&a.connection_count).then_with(|| a.remote_address.cmp(&b.remote_address)));
+    remotes
+}
+
+struct ConnectionsByRemoteVTab;
+
+impl VTab for ConnectionsByRemoteVTab {
+    type InitData = ConnectionsByRemoteInitData;
+    type BindData = ConnectionsByRemoteBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("remote_address", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("connection_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("established_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("time_wait_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("distinct_local_ports", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("processes", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar)));
+        
+        // Drop remotes with fewer connections than this (default: keep all)
+        let min_count = bind.get_named_parameter("min_count").map(|v| v.to_int64()).unwrap_or(1);
+        if min_count < 0 {
+            return Err(format!("sazgar_connections_by_remote: min_count must not be negative, got {}", min_count).into());
+        }
+        
+        Ok(ConnectionsByRemoteBindData { min_count: min_count as u64 })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<ConnectionsByRemoteBindData>();
+        let min_count = unsafe { (*bind_data).min_count };
+        
+        let remote_data = group_connections_by_remote(&read_ports(Some("TCP"), false, i64::MIN..=i64::MAX, None), min_count);
+        let remote_count = remote_data.len();
+        
+        Ok(ConnectionsByRemoteInitData {
+            current_idx: AtomicUsize::new(0),
+            remote_count,
+            remote_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.remote_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.remote_count - current);
+        
+        for i in 0..batch_size {
+            let remote = &init_data.remote_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(remote.remote_address.clone())?);
+            output.flat_vector(1).as_mut_slice::<u64>()[i] = remote.connection_count;
+            output.flat_vector(2).as_mut_slice::<u64>()[i] = remote.established_count;
+            output.flat_vector(3).as_mut_slice::<u64>()[i] = remote.time_wait_count;
+            output.flat_vector(4).as_mut_slice::<u64>()[i] = remote.distinct_local_ports;
+        }
+        
+        let processes: Vec<&[String]> = init_data.remote_data[current..current + batch_size]
+            .iter()
+            .map(|remote| remote.processes.as_slice())
+            .collect();
+        insert_varchar_lists(&mut output.list_vector(5), &processes)?;
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("min_count".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+        ])
+    }
+}
+
+// ============================================================================
+// GPU Table Function - sazgar_gpu() 
+// Returns GPU information (NVIDIA GPUs when feature enabled)
+// ============================================================================
+
+#[repr(C)]
+struct GpuBindData;
+
+struct GpuInfo {
+    index: u32,
+    name: String,
+    driver_version: String,
+    memory_total_mb: u64,
+    memory_used_mb: u64,
+    memory_free_mb: u64,
+    temperature_celsius: Option<u32>,
     power_usage_watts: Option<u32>,
     utilization_gpu_percent: Option<u32>,
     utilization_memory_percent: Option<u32>,
+    thermal_violation_ms: Option<u64>,
+    total_throttle_ms: Option<u64>,
 }
 
 #[repr(C)]
@@ -1616,8 +3533,10 @@ impl VTab for GpuVTab {
         bind.add_result_column("memory_free_mb", LogicalTypeHandle::from(LogicalTypeId::Bigint));
         bind.add_result_column("temperature_celsius", LogicalTypeHandle::from(LogicalTypeId::Integer));
         bind.add_result_column("power_usage_watts", LogicalTypeHandle::from(LogicalTypeId::Integer));
-        bind.add_result_column("utilization_gpu_percent", LogicalTypeHandle::from(LogicalTypeId::Integer));
-        bind.add_result_column("utilization_memory_percent", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("utilization_gpu_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("utilization_memory_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("thermal_violation_ms", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("total_throttle_ms", LogicalTypeHandle::from(LogicalTypeId::UBigint));
         Ok(GpuBindData)
     }
 
@@ -1628,6 +3547,7 @@ impl VTab for GpuVTab {
         #[cfg(feature = "nvidia")]
         {
             use nvml_wrapper::Nvml;
+            use nvml_wrapper::enum_wrappers::device::PerformancePolicy;
             
             if let Ok(nvml) = Nvml::init() {
                 let driver_version = nvml.sys_driver_version().unwrap_or_else(|_| "unknown".to_string());
@@ -1655,6 +3575,14 @@ impl VTab for GpuVTab {
                                     (None, None)
                                 };
                             
+                            // Cumulative time clocks were held down, since the driver loaded.
+                            // Not every GPU exposes violation counters.
+                            let violation_ms = |policy| {
+                                device.violation_status(policy).ok().map(|v| v.violation_time / 1_000_000)
+                            };
+                            let thermal_violation_ms = violation_ms(PerformancePolicy::Thermal);
+                            let total_throttle_ms = violation_ms(PerformancePolicy::TotalAppClocks);
+                            
                             gpu_data.push(GpuInfo {
                                 index: idx,
                                 name,
@@ -1666,6 +3594,8 @@ impl VTab for GpuVTab {
                                 power_usage_watts,
                                 utilization_gpu_percent,
                                 utilization_memory_percent,
+                                thermal_violation_ms,
+                                total_throttle_ms,
                             });
                         }
                     }
@@ -1705,8 +3635,16 @@ impl VTab for GpuVTab {
             output.flat_vector(5).as_mut_slice::<i64>()[i] = gpu.memory_free_mb as i64;
             output.flat_vector(6).as_mut_slice::<i32>()[i] = gpu.temperature_celsius.unwrap_or(0) as i32;
             output.flat_vector(7).as_mut_slice::<i32>()[i] = gpu.power_usage_watts.unwrap_or(0) as i32;
-            output.flat_vector(8).as_mut_slice::<i32>()[i] = gpu.utilization_gpu_percent.unwrap_or(0) as i32;
-            output.flat_vector(9).as_mut_slice::<i32>()[i] = gpu.utilization_memory_percent.unwrap_or(0) as i32;
+            output.flat_vector(8).as_mut_slice::<f32>()[i] = gpu.utilization_gpu_percent.unwrap_or(0) as f32;
+            output.flat_vector(9).as_mut_slice::<f32>()[i] = gpu.utilization_memory_percent.unwrap_or(0) as f32;
+            match gpu.thermal_violation_ms {
+                Some(ms) => output.flat_vector(10).as_mut_slice::<u64>()[i] = ms,
+                None => output.flat_vector(10).set_null(i),
+            }
+            match gpu.total_throttle_ms {
+                Some(ms) => output.flat_vector(11).as_mut_slice::<u64>()[i] = ms,
+                None => output.flat_vector(11).set_null(i),
+            }
         }
         
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
@@ -1753,7 +3691,7 @@ impl VTab for SwapVTab {
         bind.add_result_column("total_swap", LogicalTypeHandle::from(LogicalTypeId::Double));
         bind.add_result_column("used_swap", LogicalTypeHandle::from(LogicalTypeId::Double));
         bind.add_result_column("free_swap", LogicalTypeHandle::from(LogicalTypeId::Double));
-        bind.add_result_column("swap_usage_percent", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("swap_usage_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
         bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         
         Ok(SwapBindData { unit })
@@ -1784,7 +3722,7 @@ impl VTab for SwapVTab {
         let used_swap = sys.used_swap();
         let free_swap = sys.free_swap();
         let usage_percent = if total_swap > 0 {
-            (used_swap as f64 / total_swap as f64) * 100.0
+            (used_swap as f32 / total_swap as f32) * 100.0
         } else {
             0.0
         };
@@ -1794,7 +3732,7 @@ impl VTab for SwapVTab {
         output.flat_vector(0).as_mut_slice::<f64>()[0] = unit.convert(total_swap);
         output.flat_vector(1).as_mut_slice::<f64>()[0] = unit.convert(used_swap);
         output.flat_vector(2).as_mut_slice::<f64>()[0] = unit.convert(free_swap);
-        output.flat_vector(3).as_mut_slice::<f64>()[0] = usage_percent;
+        output.flat_vector(3).as_mut_slice::<f32>()[0] = usage_percent;
         output.flat_vector(4).insert(0, CString::new(unit.name())?);
         
         output.set_len(1);
@@ -1824,6 +3762,7 @@ struct CpuCoreInfo {
     frequency_mhz: u64,
     vendor: String,
     brand: String,
+    governor: Option<String>,
 }
 
 #[repr(C)]
@@ -1845,6 +3784,7 @@ impl VTab for CpuCoresVTab {
         bind.add_result_column("frequency_mhz", LogicalTypeHandle::from(LogicalTypeId::Bigint));
         bind.add_result_column("vendor", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("brand", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("governor", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         Ok(CpuCoresBindData)
     }
 
@@ -1863,6 +3803,10 @@ impl VTab for CpuCoresVTab {
                 frequency_mhz: cpu.frequency(),
                 vendor: cpu.vendor_id().to_string(),
                 brand: cpu.brand().to_string(),
+                #[cfg(target_os = "linux")]
+                governor: read_cpufreq_attribute(idx, "scaling_governor"),
+                #[cfg(not(target_os = "linux"))]
+                governor: None,
             }
         }).collect();
         
@@ -1894,6 +3838,10 @@ impl VTab for CpuCoresVTab {
             output.flat_vector(2).as_mut_slice::<i64>()[i] = core.frequency_mhz as i64;
             output.flat_vector(3).insert(i, CString::new(core.vendor.clone())?);
             output.flat_vector(4).insert(i, CString::new(core.brand.clone())?);
+            match &core.governor {
+                Some(governor) => output.flat_vector(5).insert(i, CString::new(governor.clone())?),
+                None => output.flat_vector(5).set_null(i),
+            }
         }
         
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
@@ -1908,7 +3856,7 @@ impl VTab for CpuCoresVTab {
 
 // ============================================================================
 // File Descriptors Table Function - sazgar_fds()
-// Returns open file descriptors for processes (Linux/macOS)
+// Returns open file descriptors for processes (Linux via /proc, macOS via lsof)
 // ============================================================================
 
 #[repr(C)]
@@ -1919,7 +3867,9 @@ struct FdsBindData {
 struct FdInfo {
     pid: u32,
     process_name: String,
-    fd_count: usize,
+    fd_count: Option<usize>,
+    soft_limit: Option<u64>,
+    hard_limit: Option<u64>,
 }
 
 #[repr(C)]
@@ -1929,6 +3879,50 @@ struct FdsInitData {
     fd_data: Vec<FdInfo>,
 }
 
+/// Count open file descriptors per process from `lsof -F pf` output on macOS
+#[cfg(target_os = "macos")]
+fn lsof_fd_counts(pid_filter: Option<u32>) -> Option<std::collections::HashMap<u32, usize>> {
+    let mut cmd = std::process::Command::new("lsof");
+    cmd.args(["-n", "-P", "-F", "pf"]);
+    if let Some(pid) = pid_filter {
+        cmd.args(["-p", &pid.to_string()]);
+    }
+    let output = cmd.output().ok()?;
+    // lsof exits non-zero when some processes could not be inspected, so only
+    // treat a failure with no output at all as unavailable
+    if output.stdout.is_empty() && !output.status.success() {
+        return None;
+    }
+
+    let mut counts = std::collections::HashMap::new();
+    let mut current_pid: Option<u32> = None;
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        if let Some(pid) = line.strip_prefix('p') {
+            current_pid = pid.parse().ok();
+            if let Some(pid) = current_pid {
+                counts.entry(pid).or_insert(0);
+            }
+        } else if let Some(fd) = line.strip_prefix('f') {
+            // Only numeric descriptors count; skip cwd, txt, mem and the like
+            if let (Some(pid), true) = (current_pid, fd.parse::<u32>().is_ok()) {
+                *counts.entry(pid).or_insert(0) += 1;
+            }
+        }
+    }
+    Some(counts)
+}
+
+/// Read the "Max open files" soft and hard limits from /proc/<pid>/limits.
+/// A limit of "unlimited" is reported as None.
+#[cfg(target_os = "linux")]
+fn read_fd_limits(pid: u32) -> (Option<u64>, Option<u64>) {
+    std::fs::read_to_string(format!("/proc/{}/limits", pid))
+        .ok()
+        .and_then(|content| parse_proc_limits(&content).into_iter().find(|limit| limit.resource == "Max open files"))
+        .map(|limit| (limit.soft_limit, limit.hard_limit))
+        .unwrap_or((None, None))
+}
+
 struct FdsVTab;
 
 impl VTab for FdsVTab {
@@ -1939,6 +3933,8 @@ impl VTab for FdsVTab {
         bind.add_result_column("pid", LogicalTypeHandle::from(LogicalTypeId::Integer));
         bind.add_result_column("process_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("fd_count", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("soft_limit", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("hard_limit", LogicalTypeHandle::from(LogicalTypeId::UBigint));
         
         let pid_filter = if bind.get_parameter_count() > 0 {
             let param = bind.get_parameter(0).to_string();
@@ -1959,6 +3955,10 @@ impl VTab for FdsVTab {
             RefreshKind::new().with_processes(ProcessRefreshKind::new())
         );
         
+        // On macOS a single lsof run covers every process
+        #[cfg(target_os = "macos")]
+        let lsof_counts = lsof_fd_counts(pid_filter);
+        
         let fd_data: Vec<FdInfo> = sys.processes()
             .iter()
             .filter(|(pid, _)| {
@@ -1970,17 +3970,30 @@ impl VTab for FdsVTab {
             .map(|(pid, proc)| {
                 // Get fd count from /proc/<pid>/fd on Linux
                 #[cfg(target_os = "linux")]
-                let fd_count = std::fs::read_dir(format!("/proc/{}/fd", pid.as_u32()))
+                let fd_count = Some(std::fs::read_dir(format!("/proc/{}/fd", pid.as_u32()))
                     .map(|dir| dir.count())
-                    .unwrap_or(0);
+                    .unwrap_or(0));
+                
+                // Processes lsof could not inspect stay unknown
+                #[cfg(target_os = "macos")]
+                let fd_count = lsof_counts.as_ref()
+                    .and_then(|counts| counts.get(&pid.as_u32()).copied());
+                
+                #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+                let fd_count: Option<usize> = None;
+                
+                #[cfg(target_os = "linux")]
+                let (soft_limit, hard_limit) = read_fd_limits(pid.as_u32());
                 
                 #[cfg(not(target_os = "linux"))]
-                let fd_count = 0usize;
+                let (soft_limit, hard_limit): (Option<u64>, Option<u64>) = (None, None);
                 
                 FdInfo {
                     pid: pid.as_u32(),
                     process_name: proc.name().to_string_lossy().to_string(),
                     fd_count,
+                    soft_limit,
+                    hard_limit,
                 }
             })
             .collect();
@@ -2010,7 +4023,18 @@ impl VTab for FdsVTab {
             
             output.flat_vector(0).as_mut_slice::<i32>()[i] = fd.pid as i32;
             output.flat_vector(1).insert(i, CString::new(fd.process_name.clone())?);
-            output.flat_vector(2).as_mut_slice::<i32>()[i] = fd.fd_count as i32;
+            match fd.fd_count {
+                Some(count) => output.flat_vector(2).as_mut_slice::<i32>()[i] = count as i32,
+                None => output.flat_vector(2).set_null(i),
+            }
+            match fd.soft_limit {
+                Some(limit) => output.flat_vector(3).as_mut_slice::<u64>()[i] = limit,
+                None => output.flat_vector(3).set_null(i),
+            }
+            match fd.hard_limit {
+                Some(limit) => output.flat_vector(4).as_mut_slice::<u64>()[i] = limit,
+                None => output.flat_vector(4).set_null(i),
+            }
         }
         
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
@@ -2029,7 +4053,9 @@ impl VTab for FdsVTab {
 // ============================================================================
 
 #[repr(C)]
-struct DockerBindData;
+struct DockerBindData {
+    runtime: &'static str,
+}
 
 struct DockerContainerInfo {
     id: String,
@@ -2038,6 +4064,349 @@ struct DockerContainerInfo {
     status: String,
     state: String,
     created: String,
+    cpu_percent: Option<f32>,
+    memory_usage_bytes: Option<u64>,
+    memory_limit_bytes: Option<u64>,
+    ports: Vec<String>,
+    labels: String,
+}
+
+/// Render container labels as a JSON object with keys in sorted order
+#[cfg(any(target_os = "linux", target_os = "macos", feature = "docker-api", test))]
+fn labels_to_json(labels: &[(String, String)]) -> String {
+    let sorted: std::collections::BTreeMap<&str, &str> = labels.iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+    serde_json::to_string(&sorted).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Live resource usage of a running container, from `docker stats`
+#[cfg(any(target_os = "linux", target_os = "macos", feature = "docker-api"))]
+struct DockerStats {
+    cpu_percent: Option<f32>,
+    memory_usage_bytes: Option<u64>,
+    memory_limit_bytes: Option<u64>,
+}
+
+/// How long `docker stats` may take before its columns are left NULL
+#[cfg(any(target_os = "linux", target_os = "macos", feature = "docker-api"))]
+const DOCKER_STATS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How long listing containers may take, through the Engine API (before the
+/// CLI is tried) or through `docker ps`
+#[cfg(any(target_os = "linux", target_os = "macos", feature = "docker-api"))]
+const DOCKER_LIST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Run a command and collect its stdout, killing it if it does not finish within `timeout`
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn command_output_with_timeout(program: &str, args: &[&str], timeout: std::time::Duration) -> Option<String> {
+    let (status, stdout) = command_run_with_timeout(program, args, timeout)?;
+    status.success().then_some(stdout)
+}
+
+/// Like `command_output_with_timeout`, but returns stdout along with the exit status for
+/// tools such as smartctl that report findings through a non-zero status
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn command_run_with_timeout(
+    program: &str,
+    args: &[&str],
+    timeout: std::time::Duration,
+) -> Option<(std::process::ExitStatus, String)> {
+    use std::io::Read;
+    
+    let mut child = std::process::Command::new(program)
+        .args(args)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .ok()?;
+    
+    // Drain stdout on a separate thread so a full pipe cannot stall the child
+    let mut stdout = child.stdout.take()?;
+    let reader = std::thread::spawn(move || {
+        let mut buf = String::new();
+        stdout.read_to_string(&mut buf).map(|_| buf)
+    });
+    
+    let start = std::time::Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() < timeout => std::thread::sleep(std::time::Duration::from_millis(50)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    };
+    
+    let stdout = reader.join().ok()?.ok()?;
+    Some((status, stdout))
+}
+
+/// Parse a docker size such as "12.5MiB", "1.944GiB" or "512kB" into bytes
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn parse_docker_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let split = value.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+    let (number, suffix) = value.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let multiplier: f64 = match suffix.trim() {
+        "B" => 1.0,
+        "kB" | "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "TiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some((number * multiplier) as u64)
+}
+
+/// Collect live stats for running containers, keyed by short container ID
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn read_docker_stats(runtime: &str) -> std::collections::HashMap<String, DockerStats> {
+    let mut stats = std::collections::HashMap::new();
+    let output = match command_output_with_timeout(
+        runtime,
+        &["stats", "--no-stream", "--format", "{{.ID}}|{{.CPUPerc}}|{{.MemUsage}}"],
+        DOCKER_STATS_TIMEOUT,
+    ) {
+        Some(output) => output,
+        None => return stats,
+    };
+    
+    for line in output.lines() {
+        let parts: Vec<&str> = line.split('|').collect();
+        if parts.len() < 3 {
+            continue;
+        }
+        let (usage, limit) = match parts[2].split_once('/') {
+            Some((usage, limit)) => (parse_docker_size(usage), parse_docker_size(limit)),
+            None => (None, None),
+        };
+        stats.insert(parts[0].to_string(), DockerStats {
+            cpu_percent: parts[1].trim().trim_end_matches('%').parse().ok(),
+            memory_usage_bytes: usage,
+            memory_limit_bytes: limit,
+        });
+    }
+    stats
+}
+
+/// Decode the `{{json .Labels}}` field of `docker ps` or `podman ps`. Podman
+/// gives a JSON object; docker gives a JSON string of comma-separated
+/// `key=value` pairs, where a piece without `=` continues the previous value
+/// (a comma inside a label value).
+#[cfg(any(target_os = "linux", target_os = "macos", test))]
+fn parse_docker_cli_labels(field: &str) -> Vec<(String, String)> {
+    match serde_json::from_str::<serde_json::Value>(field.trim()) {
+        Ok(serde_json::Value::Object(map)) => map.into_iter()
+            .map(|(key, value)| (key, value.as_str().map(|v| v.to_string()).unwrap_or_else(|| value.to_string())))
+            .collect(),
+        Ok(serde_json::Value::String(joined)) => {
+            let mut labels: Vec<(String, String)> = Vec::new();
+            for piece in joined.split(',').filter(|piece| !piece.is_empty()) {
+                match (piece.split_once('='), labels.last_mut()) {
+                    (Some((key, value)), _) => labels.push((key.to_string(), value.to_string())),
+                    (None, Some((_, value))) => {
+                        value.push(',');
+                        value.push_str(piece);
+                    }
+                    (None, None) => {}
+                }
+            }
+            labels
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// List containers by parsing `docker ps` (or `podman ps`) output
+#[cfg_attr(not(any(target_os = "linux", target_os = "macos")), allow(unused_variables))]
+fn read_docker_containers_cli(runtime: &str) -> Vec<DockerContainerInfo> {
+    #[allow(unused_mut)]
+    let mut container_data: Vec<DockerContainerInfo> = Vec::new();
+    
+    // Try to get containers using the docker or podman CLI
+    // This is a simple approach that doesn't require additional dependencies
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    {
+        // Both runtimes JSON-encode labels, which keeps escapes and commas in values intact
+        let format = "{{.ID}}|{{.Names}}|{{.Image}}|{{.Status}}|{{.State}}|{{.CreatedAt}}|{{.Ports}}|{{json .Labels}}";
+        if let Some(stdout) = command_output_with_timeout(runtime, &["ps", "-a", "--format", format], DOCKER_LIST_TIMEOUT) {
+            // Only running containers have stats; the rest stay NULL
+            let stats = read_docker_stats(runtime);
+            for line in stdout.lines() {
+                // Labels come last so a '|' inside a label value cannot shift the other fields
+                let parts: Vec<&str> = line.splitn(8, '|').collect();
+                if parts.len() >= 6 {
+                    let container_stats = stats.get(parts[0]);
+                    // e.g. "0.0.0.0:5432->5432/tcp, :::5432->5432/tcp"
+                    let ports: Vec<String> = parts.get(6)
+                        .map(|p| p.split(", ").map(|port| port.trim().to_string()).filter(|port| !port.is_empty()).collect())
+                        .unwrap_or_default();
+                    let labels = parts.get(7).map(|l| parse_docker_cli_labels(l)).unwrap_or_default();
+                    container_data.push(DockerContainerInfo {
+                        id: parts[0].to_string(),
+                        name: parts[1].to_string(),
+                        image: parts[2].to_string(),
+                        status: parts[3].to_string(),
+                        state: parts[4].to_string(),
+                        created: parts[5].to_string(),
+                        cpu_percent: container_stats.and_then(|s| s.cpu_percent),
+                        memory_usage_bytes: container_stats.and_then(|s| s.memory_usage_bytes),
+                        memory_limit_bytes: container_stats.and_then(|s| s.memory_limit_bytes),
+                        ports,
+                        labels: labels_to_json(&labels),
+                    });
+                }
+            }
+        }
+    }
+    
+    container_data
+}
+
+/// Format a Unix timestamp the way `docker ps` prints creation times
+#[cfg(feature = "docker-api")]
+fn format_docker_timestamp(secs: i64) -> String {
+    // Civil date from days since the epoch (Howard Hinnant's algorithm)
+    let days = secs.div_euclid(86400);
+    let rem = secs.rem_euclid(86400);
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} +0000 UTC",
+        year, month, day, rem / 3600, (rem % 3600) / 60, rem % 60
+    )
+}
+
+/// Compute live stats from a single Engine API stats sample
+#[cfg(feature = "docker-api")]
+fn docker_api_stats(stats: &bollard::models::ContainerStatsResponse) -> DockerStats {
+    // Same calculation as `docker stats`: container CPU delta over system CPU delta
+    let cpu_percent = (|| {
+        let cpu = stats.cpu_stats.as_ref()?;
+        let precpu = stats.precpu_stats.as_ref()?;
+        let cpu_delta = cpu.cpu_usage.as_ref()?.total_usage?
+            .checked_sub(precpu.cpu_usage.as_ref()?.total_usage?)?;
+        let system_delta = cpu.system_cpu_usage?.checked_sub(precpu.system_cpu_usage?)?;
+        if system_delta == 0 {
+            return None;
+        }
+        let online_cpus = cpu.online_cpus.unwrap_or(1) as f64;
+        Some((cpu_delta as f64 / system_delta as f64 * online_cpus * 100.0) as f32)
+    })();
+    
+    // Like the CLI, exclude reclaimable page cache from memory usage
+    let memory = stats.memory_stats.as_ref();
+    let memory_usage_bytes = memory.and_then(|m| {
+        let cache = m.stats.as_ref()
+            .and_then(|s| s.get("inactive_file").or_else(|| s.get("total_inactive_file")).copied())
+            .unwrap_or(0);
+        m.usage.map(|usage| usage.saturating_sub(cache))
+    });
+    
+    DockerStats {
+        cpu_percent,
+        memory_usage_bytes,
+        memory_limit_bytes: memory.and_then(|m| m.limit),
+    }
+}
+
+/// List containers through the Docker Engine API (honours `DOCKER_HOST`).
+/// Returns None when the daemon cannot be reached so the CLI can be tried instead.
+#[cfg(feature = "docker-api")]
+fn read_docker_containers_api() -> Option<Vec<DockerContainerInfo>> {
+    use bollard::query_parameters::{ListContainersOptions, StatsOptions};
+    use futures_util::StreamExt;
+    
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .ok()?;
+    
+    runtime.block_on(async {
+        let docker = bollard::Docker::connect_with_defaults().ok()?;
+        // A daemon that accepts the connection but never answers falls back to the CLI
+        let containers = tokio::time::timeout(
+            DOCKER_LIST_TIMEOUT,
+            docker.list_containers(Some(ListContainersOptions { all: true, ..Default::default() })),
+        )
+            .await
+            .ok()?
+            .ok()?;
+        
+        // Sample stats of running containers concurrently, bounded by the stats timeout
+        let mut tasks = tokio::task::JoinSet::new();
+        for container in &containers {
+            let running = matches!(container.state, Some(bollard::models::ContainerSummaryStateEnum::RUNNING));
+            if let (true, Some(id)) = (running, container.id.clone()) {
+                let docker = docker.clone();
+                tasks.spawn(async move {
+                    let options = StatsOptions { stream: false, one_shot: false };
+                    let sample = docker.stats(&id, Some(options)).next().await;
+                    (id, sample.and_then(|s| s.ok()).map(|s| docker_api_stats(&s)))
+                });
+            }
+        }
+        let mut stats = std::collections::HashMap::new();
+        let _ = tokio::time::timeout(DOCKER_STATS_TIMEOUT, async {
+            while let Some(Ok((id, sample))) = tasks.join_next().await {
+                if let Some(sample) = sample {
+                    stats.insert(id, sample);
+                }
+            }
+        }).await;
+        
+        let container_data = containers.into_iter().map(|container| {
+            let id = container.id.unwrap_or_default();
+            let container_stats = stats.get(&id);
+            // Same shape as the CLI: "0.0.0.0:5432->5432/tcp" or "5432/tcp" when unpublished
+            let ports = container.ports.unwrap_or_default().iter().map(|port| {
+                let proto = port.typ.map(|t| t.to_string()).unwrap_or_else(|| "tcp".to_string());
+                match port.public_port {
+                    Some(public) => format!(
+                        "{}:{}->{}/{}",
+                        port.ip.as_deref().unwrap_or(""), public, port.private_port, proto
+                    ),
+                    None => format!("{}/{}", port.private_port, proto),
+                }
+            }).collect();
+            let labels: Vec<(String, String)> = container.labels.unwrap_or_default().into_iter().collect();
+            DockerContainerInfo {
+                // Match the short IDs and names shown by `docker ps`
+                id: id.chars().take(12).collect(),
+                name: container.names.unwrap_or_default()
+                    .first()
+                    .map(|name| name.trim_start_matches('/').to_string())
+                    .unwrap_or_default(),
+                image: container.image.unwrap_or_default(),
+                status: container.status.unwrap_or_default(),
+                state: container.state.map(|state| state.to_string()).unwrap_or_default(),
+                created: container.created.map(format_docker_timestamp).unwrap_or_default(),
+                cpu_percent: container_stats.and_then(|s| s.cpu_percent),
+                memory_usage_bytes: container_stats.and_then(|s| s.memory_usage_bytes),
+                memory_limit_bytes: container_stats.and_then(|s| s.memory_limit_bytes),
+                ports,
+                labels: labels_to_json(&labels),
+            }
+        }).collect();
+        
+        Some(container_data)
+    })
 }
 
 #[repr(C)]
@@ -2060,32 +4429,254 @@ impl VTab for DockerVTab {
         bind.add_result_column("status", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
         bind.add_result_column("created", LogicalTypeHandle::from(LogicalTypeId::Varchar));
-        Ok(DockerBindData)
+        bind.add_result_column("cpu_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("memory_usage_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("memory_limit_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("ports", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar)));
+        bind.add_result_column("labels", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        // Container runtime CLI to invoke (default: docker)
+        let runtime = match bind.get_named_parameter("runtime")
+            .map(|v| v.to_string().trim_matches('"').to_lowercase())
+            .as_deref()
+        {
+            None | Some("docker") => "docker",
+            Some("podman") => "podman",
+            Some(other) => {
+                return Err(format!("Invalid runtime: {} (expected 'docker' or 'podman')", other).into());
+            }
+        };
+        
+        Ok(DockerBindData { runtime })
     }
 
-    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
-        let mut container_data: Vec<DockerContainerInfo> = Vec::new();
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<DockerBindData>();
+        let runtime = unsafe { (*bind_data).runtime };
+        
+        // Prefer the Engine API when built with it, falling back to the CLI.
+        // The API path only speaks to Docker; podman always goes through its CLI.
+        #[cfg(feature = "docker-api")]
+        let container_data = match runtime {
+            "docker" => read_docker_containers_api().unwrap_or_else(|| read_docker_containers_cli(runtime)),
+            _ => read_docker_containers_cli(runtime),
+        };
         
-        // Try to get Docker containers using docker CLI
-        // This is a simple approach that doesn't require additional dependencies
-        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        #[cfg(not(feature = "docker-api"))]
+        let container_data = read_docker_containers_cli(runtime);
+        
+        let container_count = container_data.len();
+        
+        Ok(DockerInitData {
+            current_idx: AtomicUsize::new(0),
+            container_count,
+            container_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.container_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.container_count - current);
+        
+        for i in 0..batch_size {
+            let container = &init_data.container_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(container.id.clone())?);
+            output.flat_vector(1).insert(i, CString::new(container.name.clone())?);
+            output.flat_vector(2).insert(i, CString::new(container.image.clone())?);
+            output.flat_vector(3).insert(i, CString::new(container.status.clone())?);
+            output.flat_vector(4).insert(i, CString::new(container.state.clone())?);
+            output.flat_vector(5).insert(i, CString::new(container.created.clone())?);
+            match container.cpu_percent {
+                Some(cpu) => output.flat_vector(6).as_mut_slice::<f32>()[i] = cpu,
+                None => output.flat_vector(6).set_null(i),
+            }
+            match container.memory_usage_bytes {
+                Some(bytes) => output.flat_vector(7).as_mut_slice::<u64>()[i] = bytes,
+                None => output.flat_vector(7).set_null(i),
+            }
+            match container.memory_limit_bytes {
+                Some(bytes) => output.flat_vector(8).as_mut_slice::<u64>()[i] = bytes,
+                None => output.flat_vector(8).set_null(i),
+            }
+            output.flat_vector(10).insert(i, CString::new(container.labels.clone())?);
+        }
+        
+        let ports: Vec<&[String]> = init_data.container_data[current..current + batch_size]
+            .iter()
+            .map(|c| c.ports.as_slice())
+            .collect();
+        insert_varchar_lists(&mut output.list_vector(9), &ports)?;
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("runtime".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+    }
+}
+
+// ============================================================================
+// Services Table Function - sazgar_services()
+// Returns running system services (platform-specific)
+// ============================================================================
+
+#[repr(C)]
+struct ServicesBindData {
+    pattern: Option<globset::GlobMatcher>,
+}
+
+struct ServiceInfo {
+    name: String,
+    load_state: String,
+    active_state: String,
+    sub_state: String,
+    description: String,
+    enabled: Option<String>,
+}
+
+#[repr(C)]
+struct ServicesInitData {
+    current_idx: AtomicUsize,
+    service_count: usize,
+    service_data: Vec<ServiceInfo>,
+}
+
+/// Compile the glob every `pattern`-style parameter uses, so a pattern means
+/// the same thing in every function. `\` escapes on all platforms.
+fn compile_glob(function: &str, pattern: &str) -> Result<globset::GlobMatcher, String> {
+    globset::GlobBuilder::new(pattern)
+        .backslash_escape(true)
+        .build()
+        .map(|glob| glob.compile_matcher())
+        .map_err(|e| format!("{}: invalid pattern '{}': {}", function, pattern, e))
+}
+
+/// One row of `systemctl list-units --plain --no-legend`
+#[cfg(any(target_os = "linux", test))]
+struct ListedUnit {
+    /// Full unit name, e.g. `ssh.service`
+    unit: String,
+    load_state: String,
+    active_state: String,
+    sub_state: String,
+    description: String,
+}
+
+/// Parse one `systemctl list-units --plain --no-legend` line: UNIT LOAD ACTIVE
+/// SUB DESCRIPTION. Failed or not-found units carry a leading "●" (or "*"
+/// without UTF-8) on some systemd versions, which would otherwise shift them.
+#[cfg(any(target_os = "linux", test))]
+fn parse_list_units_line(line: &str) -> Option<ListedUnit> {
+    let line = line.trim_start().trim_start_matches(['●', '*']);
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 4 {
+        return None;
+    }
+    Some(ListedUnit {
+        unit: parts[0].to_string(),
+        load_state: parts[1].to_string(),
+        active_state: parts[2].to_string(),
+        sub_state: parts[3].to_string(),
+        description: parts[4..].join(" "),
+    })
+}
+
+/// List every loaded or referenced systemd service unit
+#[cfg(target_os = "linux")]
+fn list_service_units() -> Vec<ListedUnit> {
+    match std::process::Command::new("systemctl")
+        .args(["list-units", "--type=service", "--all", "--no-pager", "--plain", "--no-legend"])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).lines().filter_map(parse_list_units_line).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Get the unit file state (enabled, disabled, static, masked, ...) of every systemd service
+#[cfg(target_os = "linux")]
+fn read_service_enablement() -> std::collections::HashMap<String, String> {
+    let mut states = std::collections::HashMap::new();
+    if let Ok(output) = std::process::Command::new("systemctl")
+        .args(["list-unit-files", "--type=service", "--no-pager", "--plain", "--no-legend"])
+        .output()
+    {
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() >= 2 {
+                states.insert(parts[0].trim_end_matches(".service").to_string(), parts[1].to_string());
+            }
+        }
+    }
+    states
+}
+
+struct ServicesVTab;
+
+impl VTab for ServicesVTab {
+    type InitData = ServicesInitData;
+    type BindData = ServicesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("load_state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("active_state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("sub_state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("description", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("enabled", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        let pattern = bind.get_named_parameter("pattern")
+            .map(|v| v.to_string().trim_matches('"').to_string())
+            .filter(|p| !p.is_empty())
+            .map(|p| compile_glob("sazgar_services", &p))
+            .transpose()?;
+        
+        Ok(ServicesBindData { pattern })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<ServicesBindData>();
+        let pattern = unsafe { (*bind_data).pattern.clone() };
+        
+        let mut service_data: Vec<ServiceInfo> = Vec::new();
+        
+        // macOS: Use launchctl
+        #[cfg(target_os = "macos")]
         {
-            if let Ok(output) = std::process::Command::new("docker")
-                .args(["ps", "-a", "--format", "{{.ID}}|{{.Names}}|{{.Image}}|{{.Status}}|{{.State}}|{{.CreatedAt}}"])
+            if let Ok(output) = std::process::Command::new("launchctl")
+                .args(["list"])
                 .output()
             {
                 if output.status.success() {
                     let stdout = String::from_utf8_lossy(&output.stdout);
-                    for line in stdout.lines() {
-                        let parts: Vec<&str> = line.split('|').collect();
-                        if parts.len() >= 6 {
-                            container_data.push(DockerContainerInfo {
-                                id: parts[0].to_string(),
-                                name: parts[1].to_string(),
-                                image: parts[2].to_string(),
-                                status: parts[3].to_string(),
-                                state: parts[4].to_string(),
-                                created: parts[5].to_string(),
+                    for line in stdout.lines().skip(1) {  // Skip header
+                        let parts: Vec<&str> = line.split_whitespace().collect();
+                        if parts.len() >= 3 {
+                            // launchctl only lists loaded jobs; a PID of "-" means not running
+                            let running = parts[0] != "-";
+                            service_data.push(ServiceInfo {
+                                name: parts[2].to_string(),
+                                load_state: "loaded".to_string(),
+                                active_state: if running { "active" } else { "inactive" }.to_string(),
+                                sub_state: if running { "running" } else { "dead" }.to_string(),
+                                description: "".to_string(),
+                                enabled: None,
                             });
                         }
                     }
@@ -2093,12 +4684,7752 @@ impl VTab for DockerVTab {
             }
         }
         
-        let container_count = container_data.len();
+        // Linux: Use systemctl
+        #[cfg(target_os = "linux")]
+        {
+            let enablement = read_service_enablement();
+            for unit in list_service_units() {
+                let name = unit.unit.trim_end_matches(".service").to_string();
+                // Template instances such as getty@tty1 take the state of getty@
+                let enabled = enablement.get(&name)
+                    .or_else(|| name.split_once('@').and_then(|(template, _)| enablement.get(&format!("{}@", template))))
+                    .cloned();
+                service_data.push(ServiceInfo {
+                    name,
+                    load_state: unit.load_state,
+                    active_state: unit.active_state,
+                    sub_state: unit.sub_state,
+                    description: unit.description,
+                    enabled,
+                });
+            }
+        }
         
-        Ok(DockerInitData {
+        if let Some(pattern) = &pattern {
+            service_data.retain(|service| pattern.is_match(&service.name));
+        }
+        
+        let service_count = service_data.len();
+        
+        Ok(ServicesInitData {
             current_idx: AtomicUsize::new(0),
-            container_count,
-            container_data,
+            service_count,
+            service_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.service_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.service_count - current);
+        
+        for i in 0..batch_size {
+            let service = &init_data.service_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(service.name.clone())?);
+            output.flat_vector(1).insert(i, CString::new(service.load_state.clone())?);
+            output.flat_vector(2).insert(i, CString::new(service.active_state.clone())?);
+            output.flat_vector(3).insert(i, CString::new(service.sub_state.clone())?);
+            output.flat_vector(4).insert(i, CString::new(service.description.clone())?);
+            match &service.enabled {
+                Some(enabled) => output.flat_vector(5).insert(i, CString::new(enabled.clone())?),
+                None => output.flat_vector(5).set_null(i),
+            }
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("pattern".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+    }
+}
+
+// ============================================================================
+// Hugepages Table Function - sazgar_hugepages()
+// Returns hugepage pool counts per page size (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct HugepagesBindData;
+
+struct HugepageInfo {
+    page_size: String,
+    page_size_bytes: u64,
+    is_default: bool,
+    total_pages: u64,
+    free_pages: u64,
+    reserved_pages: u64,
+    surplus_pages: u64,
+}
+
+#[repr(C)]
+struct HugepagesInitData {
+    current_idx: AtomicUsize,
+    hugepage_count: usize,
+    hugepage_data: Vec<HugepageInfo>,
+    thp_enabled: String,
+}
+
+/// Get the selected value of a sysfs choice file, e.g. "always [madvise] never" -> "madvise"
+#[cfg(target_os = "linux")]
+fn read_sys_selected(path: &str) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let start = content.find('[')?;
+    let end = content[start..].find(']')? + start;
+    Some(content[start + 1..end].to_string())
+}
+
+struct HugepagesVTab;
+
+impl VTab for HugepagesVTab {
+    type InitData = HugepagesInitData;
+    type BindData = HugepagesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("page_size", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("page_size_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("is_default", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("total_pages", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("free_pages", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("reserved_pages", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("surplus_pages", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("thp_enabled", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(HugepagesBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[allow(unused_mut)]
+        let mut hugepage_data: Vec<HugepageInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            // Default hugepage size from /proc/meminfo ("Hugepagesize:    2048 kB")
+            let default_size_kb = std::fs::read_to_string("/proc/meminfo")
+                .ok()
+                .and_then(|content| {
+                    content.lines()
+                        .find(|line| line.starts_with("Hugepagesize:"))
+                        .and_then(|line| line.split_whitespace().nth(1))
+                        .and_then(|value| value.parse::<u64>().ok())
+                });
+            
+            if let Ok(entries) = std::fs::read_dir("/sys/kernel/mm/hugepages") {
+                for entry in entries.flatten() {
+                    let dir_name = entry.file_name().to_string_lossy().to_string();
+                    // Directories are named like "hugepages-2048kB"
+                    let size_kb = match dir_name
+                        .strip_prefix("hugepages-")
+                        .and_then(|s| s.strip_suffix("kB"))
+                        .and_then(|s| s.parse::<u64>().ok())
+                    {
+                        Some(kb) => kb,
+                        None => continue,
+                    };
+                    
+                    let dir = entry.path().to_string_lossy().to_string();
+                    let page_size = if size_kb >= 1_048_576 && size_kb % 1_048_576 == 0 {
+                        format!("{}GiB", size_kb / 1_048_576)
+                    } else if size_kb >= 1024 && size_kb % 1024 == 0 {
+                        format!("{}MiB", size_kb / 1024)
+                    } else {
+                        format!("{}KiB", size_kb)
+                    };
+                    
+                    hugepage_data.push(HugepageInfo {
+                        page_size,
+                        page_size_bytes: size_kb * 1024,
+                        is_default: default_size_kb == Some(size_kb),
+                        total_pages: read_sys_u64(&format!("{}/nr_hugepages", dir)).unwrap_or(0),
+                        free_pages: read_sys_u64(&format!("{}/free_hugepages", dir)).unwrap_or(0),
+                        reserved_pages: read_sys_u64(&format!("{}/resv_hugepages", dir)).unwrap_or(0),
+                        surplus_pages: read_sys_u64(&format!("{}/surplus_hugepages", dir)).unwrap_or(0),
+                    });
+                }
+            }
+            
+            hugepage_data.sort_by_key(|h| h.page_size_bytes);
+        }
+        
+        #[cfg(target_os = "linux")]
+        let thp_enabled = read_sys_selected("/sys/kernel/mm/transparent_hugepage/enabled")
+            .unwrap_or_default();
+        
+        #[cfg(not(target_os = "linux"))]
+        let thp_enabled = String::new();
+        
+        let hugepage_count = hugepage_data.len();
+        
+        Ok(HugepagesInitData {
+            current_idx: AtomicUsize::new(0),
+            hugepage_count,
+            hugepage_data,
+            thp_enabled,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.hugepage_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.hugepage_count - current);
+        
+        for i in 0..batch_size {
+            let hp = &init_data.hugepage_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(hp.page_size.clone())?);
+            output.flat_vector(1).as_mut_slice::<u64>()[i] = hp.page_size_bytes;
+            output.flat_vector(2).as_mut_slice::<bool>()[i] = hp.is_default;
+            output.flat_vector(3).as_mut_slice::<u64>()[i] = hp.total_pages;
+            output.flat_vector(4).as_mut_slice::<u64>()[i] = hp.free_pages;
+            output.flat_vector(5).as_mut_slice::<u64>()[i] = hp.reserved_pages;
+            output.flat_vector(6).as_mut_slice::<u64>()[i] = hp.surplus_pages;
+            output.flat_vector(7).insert(i, CString::new(init_data.thp_enabled.clone())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// System Limits Table Function - sazgar_sys_limits()
+// Returns system-wide kernel limits from /proc/sys (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct SysLimitsBindData;
+
+struct SysLimitsInfo {
+    file_max: u64,
+    file_nr: u64,
+    nr_open: u64,
+    pid_max: u64,
+    threads_max: u64,
+}
+
+#[repr(C)]
+struct SysLimitsInitData {
+    done: AtomicBool,
+    limits: Option<SysLimitsInfo>,
+}
+
+struct SysLimitsVTab;
+
+impl VTab for SysLimitsVTab {
+    type InitData = SysLimitsInitData;
+    type BindData = SysLimitsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("file_max", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("file_nr", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("nr_open", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("pid_max", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("threads_max", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        Ok(SysLimitsBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "linux")]
+        let limits = Some(SysLimitsInfo {
+            file_max: read_sys_u64("/proc/sys/fs/file-max").unwrap_or(0),
+            file_nr: read_file_nr().unwrap_or(0),
+            nr_open: read_sys_u64("/proc/sys/fs/nr_open").unwrap_or(0),
+            pid_max: read_sys_u64("/proc/sys/kernel/pid_max").unwrap_or(0),
+            threads_max: read_sys_u64("/proc/sys/kernel/threads-max").unwrap_or(0),
+        });
+        
+        #[cfg(not(target_os = "linux"))]
+        let limits = None;
+        
+        Ok(SysLimitsInitData {
+            done: AtomicBool::new(false),
+            limits,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        
+        if init_data.done.swap(true, Ordering::Relaxed) {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let limits = match &init_data.limits {
+            Some(limits) => limits,
+            None => {
+                output.set_len(0);
+                return Ok(());
+            }
+        };
+        
+        output.flat_vector(0).as_mut_slice::<u64>()[0] = limits.file_max;
+        output.flat_vector(1).as_mut_slice::<u64>()[0] = limits.file_nr;
+        output.flat_vector(2).as_mut_slice::<u64>()[0] = limits.nr_open;
+        output.flat_vector(3).as_mut_slice::<u64>()[0] = limits.pid_max;
+        output.flat_vector(4).as_mut_slice::<u64>()[0] = limits.threads_max;
+        
+        output.set_len(1);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Sessions Table Function - sazgar_sessions()
+// Returns active login sessions from utmp (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct SessionsBindData;
+
+struct SessionInfo {
+    user: String,
+    tty: String,
+    host: String,
+    login_time_micros: i64,
+    pid: i32,
+}
+
+#[repr(C)]
+struct SessionsInitData {
+    current_idx: AtomicUsize,
+    session_count: usize,
+    session_data: Vec<SessionInfo>,
+}
+
+/// Read the live login sessions from utmp
+#[cfg(target_os = "linux")]
+fn read_sessions() -> Vec<SessionInfo> {
+    use utmp_rs::UtmpEntry;
+    
+    let Ok(entries) = utmp_rs::parse_from_path("/var/run/utmp") else {
+        return Vec::new();
+    };
+    // Only USER_PROCESS records describe live logins
+    entries.into_iter()
+        .filter_map(|entry| match entry {
+            UtmpEntry::UserProcess { pid, line, user, host, time, .. } => Some(SessionInfo {
+                user,
+                tty: line,
+                host,
+                login_time_micros: (time.unix_timestamp_nanos() / 1_000) as i64,
+                pid,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_sessions() -> Vec<SessionInfo> {
+    Vec::new()
+}
+
+struct SessionsVTab;
+
+impl VTab for SessionsVTab {
+    type InitData = SessionsInitData;
+    type BindData = SessionsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("user", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("tty", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("host", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("login_time", LogicalTypeHandle::from(LogicalTypeId::Timestamp));
+        bind.add_result_column("pid", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        Ok(SessionsBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let session_data = read_sessions();
+        let session_count = session_data.len();
+        
+        Ok(SessionsInitData {
+            current_idx: AtomicUsize::new(0),
+            session_count,
+            session_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.session_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.session_count - current);
+        
+        for i in 0..batch_size {
+            let session = &init_data.session_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(session.user.clone())?);
+            output.flat_vector(1).insert(i, CString::new(session.tty.clone())?);
+            output.flat_vector(2).insert(i, CString::new(session.host.clone())?);
+            output.flat_vector(3).as_mut_slice::<i64>()[i] = session.login_time_micros;
+            output.flat_vector(4).as_mut_slice::<i32>()[i] = session.pid;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Pressure Table Function - sazgar_pressure()
+// Returns Pressure Stall Information for cpu, memory and io (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct PressureBindData;
+
+struct PressureInfo {
+    resource: String,
+    kind: String,
+    avg10: f64,
+    avg60: f64,
+    avg300: f64,
+    total_stall_us: u64,
+}
+
+#[repr(C)]
+struct PressureInitData {
+    current_idx: AtomicUsize,
+    pressure_count: usize,
+    pressure_data: Vec<PressureInfo>,
+}
+
+struct PressureVTab;
+
+impl VTab for PressureVTab {
+    type InitData = PressureInitData;
+    type BindData = PressureBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("resource", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("kind", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("avg10", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("avg60", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("avg300", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("total_stall_us", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        Ok(PressureBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[allow(unused_mut)]
+        let mut pressure_data: Vec<PressureInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            // Each file has "some" and (except cpu on older kernels) "full" lines:
+            // some avg10=0.00 avg60=0.00 avg300=0.00 total=12345
+            for resource in ["cpu", "memory", "io"] {
+                let content = match std::fs::read_to_string(format!("/proc/pressure/{}", resource)) {
+                    Ok(content) => content,
+                    Err(_) => continue,
+                };
+                
+                for line in content.lines() {
+                    let mut parts = line.split_whitespace();
+                    let kind = match parts.next() {
+                        Some(kind) => kind.to_string(),
+                        None => continue,
+                    };
+                    
+                    let mut info = PressureInfo {
+                        resource: resource.to_string(),
+                        kind,
+                        avg10: 0.0,
+                        avg60: 0.0,
+                        avg300: 0.0,
+                        total_stall_us: 0,
+                    };
+                    
+                    for field in parts {
+                        if let Some((key, value)) = field.split_once('=') {
+                            match key {
+                                "avg10" => info.avg10 = value.parse().unwrap_or(0.0),
+                                "avg60" => info.avg60 = value.parse().unwrap_or(0.0),
+                                "avg300" => info.avg300 = value.parse().unwrap_or(0.0),
+                                "total" => info.total_stall_us = value.parse().unwrap_or(0),
+                                _ => {}
+                            }
+                        }
+                    }
+                    
+                    pressure_data.push(info);
+                }
+            }
+        }
+        
+        let pressure_count = pressure_data.len();
+        
+        Ok(PressureInitData {
+            current_idx: AtomicUsize::new(0),
+            pressure_count,
+            pressure_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.pressure_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.pressure_count - current);
+        
+        for i in 0..batch_size {
+            let pressure = &init_data.pressure_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(pressure.resource.clone())?);
+            output.flat_vector(1).insert(i, CString::new(pressure.kind.clone())?);
+            output.flat_vector(2).as_mut_slice::<f64>()[i] = pressure.avg10;
+            output.flat_vector(3).as_mut_slice::<f64>()[i] = pressure.avg60;
+            output.flat_vector(4).as_mut_slice::<f64>()[i] = pressure.avg300;
+            output.flat_vector(5).as_mut_slice::<u64>()[i] = pressure.total_stall_us;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Kernel Modules Table Function - sazgar_kernel_modules()
+// Returns loaded kernel modules from /proc/modules (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct ModulesBindData;
+
+struct ModuleInfo {
+    name: String,
+    size_bytes: u64,
+    ref_count: i64,
+    used_by: Vec<String>,
+    state: String,
+}
+
+#[repr(C)]
+struct ModulesInitData {
+    current_idx: AtomicUsize,
+    module_count: usize,
+    module_data: Vec<ModuleInfo>,
+}
+
+struct ModulesVTab;
+
+impl VTab for ModulesVTab {
+    type InitData = ModulesInitData;
+    type BindData = ModulesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("size_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("ref_count", LogicalTypeHandle::from(LogicalTypeId::Bigint));
+        bind.add_result_column("used_by", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar)));
+        bind.add_result_column("state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(ModulesBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[allow(unused_mut)]
+        let mut module_data: Vec<ModuleInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            // Format: name size ref_count used_by state address
+            // e.g. "nf_nat 49152 2 xt_MASQUERADE,nft_chain_nat, Live 0x0000000000000000"
+            if let Ok(content) = std::fs::read_to_string("/proc/modules") {
+                for line in content.lines() {
+                    let parts: Vec<&str> = line.split_whitespace().collect();
+                    if parts.len() >= 5 {
+                        let used_by = if parts[3] == "-" {
+                            Vec::new()
+                        } else {
+                            parts[3].split(',')
+                                .filter(|m| !m.is_empty())
+                                .map(|m| m.to_string())
+                                .collect()
+                        };
+                        
+                        module_data.push(ModuleInfo {
+                            name: parts[0].to_string(),
+                            size_bytes: parts[1].parse().unwrap_or(0),
+                            // "-" when the module cannot be unloaded
+                            ref_count: parts[2].parse().unwrap_or(-1),
+                            used_by,
+                            state: parts[4].to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        
+        let module_count = module_data.len();
+        
+        Ok(ModulesInitData {
+            current_idx: AtomicUsize::new(0),
+            module_count,
+            module_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.module_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.module_count - current);
+        let batch = &init_data.module_data[current..current + batch_size];
+        
+        for (i, module) in batch.iter().enumerate() {
+            output.flat_vector(0).insert(i, CString::new(module.name.clone())?);
+            output.flat_vector(1).as_mut_slice::<u64>()[i] = module.size_bytes;
+            output.flat_vector(2).as_mut_slice::<i64>()[i] = module.ref_count;
+            output.flat_vector(4).insert(i, CString::new(module.state.clone())?);
+        }
+        
+        let used_by: Vec<&[String]> = batch.iter().map(|m| m.used_by.as_slice()).collect();
+        insert_varchar_lists(&mut output.list_vector(3), &used_by)?;
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Service Restarts Table Function - sazgar_service_restarts()
+// Returns systemd restart counters per service (Linux/systemd only)
+// ============================================================================
+
+#[repr(C)]
+struct ServiceRestartsBindData {
+    filter: Option<String>,
+}
+
+struct ServiceRestartInfo {
+    unit: String,
+    restart_count: u64,
+}
+
+#[repr(C)]
+struct ServiceRestartsInitData {
+    current_idx: AtomicUsize,
+    restart_count: usize,
+    restart_data: Vec<ServiceRestartInfo>,
+}
+
+struct ServiceRestartsVTab;
+
+impl VTab for ServiceRestartsVTab {
+    type InitData = ServiceRestartsInitData;
+    type BindData = ServiceRestartsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("restart_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        
+        let filter = bind.get_named_parameter("filter")
+            .map(|v| v.to_string().trim_matches('"').to_string())
+            .filter(|f| !f.is_empty());
+        
+        Ok(ServiceRestartsBindData { filter })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<ServiceRestartsBindData>();
+        #[allow(unused_variables)]
+        let filter = unsafe { (*bind_data).filter.clone() };
+        
+        #[allow(unused_mut)]
+        let mut restart_data: Vec<ServiceRestartInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            // List service units first, then query NRestarts for all of them in one call
+            let units: Vec<String> = list_service_units()
+                .into_iter()
+                .map(|listed| listed.unit)
+                .filter(|unit| match &filter {
+                    Some(f) => unit.to_lowercase().contains(&f.to_lowercase()),
+                    None => true,
+                })
+                .collect();
+            
+            if !units.is_empty() {
+                if let Ok(output) = std::process::Command::new("systemctl")
+                    .arg("show")
+                    .args(&units)
+                    .args(["--property=Id,NRestarts", "--no-pager"])
+                    .output()
+                {
+                    if output.status.success() {
+                        // Output is one "Key=Value" block per unit, separated by blank lines
+                        let stdout = String::from_utf8_lossy(&output.stdout);
+                        for block in stdout.split("\n\n") {
+                            let mut unit = None;
+                            let mut restarts = None;
+                            for line in block.lines() {
+                                if let Some(id) = line.strip_prefix("Id=") {
+                                    unit = Some(id.trim_end_matches(".service").to_string());
+                                } else if let Some(n) = line.strip_prefix("NRestarts=") {
+                                    restarts = n.trim().parse::<u64>().ok();
+                                }
+                            }
+                            if let Some(unit) = unit {
+                                restart_data.push(ServiceRestartInfo {
+                                    unit,
+                                    restart_count: restarts.unwrap_or(0),
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        
+        let restart_count = restart_data.len();
+        
+        Ok(ServiceRestartsInitData {
+            current_idx: AtomicUsize::new(0),
+            restart_count,
+            restart_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.restart_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.restart_count - current);
+        
+        for i in 0..batch_size {
+            let service = &init_data.restart_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(service.unit.clone())?);
+            output.flat_vector(1).as_mut_slice::<u64>()[i] = service.restart_count;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("filter".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+    }
+}
+
+// ============================================================================
+// NUMA Table Function - sazgar_numa()
+// Returns per-node memory, CPU mapping and allocation counters
+// ============================================================================
+
+#[repr(C)]
+struct NumaBindData {
+    unit: SizeUnit,
+}
+
+struct NumaNodeInfo {
+    node_id: i32,
+    total_memory: u64,
+    free_memory: u64,
+    used_memory: u64,
+    cpu_list: Vec<i32>,
+    numa_hit: u64,
+    numa_miss: u64,
+    numa_foreign: u64,
+}
+
+#[repr(C)]
+struct NumaInitData {
+    current_idx: AtomicUsize,
+    node_count: usize,
+    node_data: Vec<NumaNodeInfo>,
+    unit: SizeUnit,
+}
+
+/// Parse a kernel CPU list such as "0-3,8,10-11" into individual CPU ids
+#[cfg(target_os = "linux")]
+fn parse_cpu_list(list: &str) -> Vec<i32> {
+    let mut cpus = Vec::new();
+    for part in list.trim().split(',').filter(|p| !p.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                if let (Ok(start), Ok(end)) = (start.parse::<i32>(), end.parse::<i32>()) {
+                    cpus.extend(start..=end);
+                }
+            }
+            None => {
+                if let Ok(cpu) = part.parse::<i32>() {
+                    cpus.push(cpu);
+                }
+            }
+        }
+    }
+    cpus
+}
+
+/// Read NUMA nodes from /sys/devices/system/node
+#[cfg(target_os = "linux")]
+fn read_numa_nodes() -> Vec<NumaNodeInfo> {
+    let mut nodes = Vec::new();
+
+    let entries = match std::fs::read_dir("/sys/devices/system/node") {
+        Ok(entries) => entries,
+        Err(_) => return nodes,
+    };
+
+    for entry in entries.flatten() {
+        let dir_name = entry.file_name().to_string_lossy().to_string();
+        let node_id = match dir_name.strip_prefix("node").and_then(|id| id.parse::<i32>().ok()) {
+            Some(id) => id,
+            None => continue,
+        };
+        let dir = entry.path();
+        
+        let mut node = NumaNodeInfo {
+            node_id,
+            total_memory: 0,
+            free_memory: 0,
+            used_memory: 0,
+            cpu_list: std::fs::read_to_string(dir.join("cpulist"))
+                .map(|list| parse_cpu_list(&list))
+                .unwrap_or_default(),
+            numa_hit: 0,
+            numa_miss: 0,
+            numa_foreign: 0,
+        };
+        
+        // Lines look like "Node 0 MemTotal:       16316396 kB"
+        if let Ok(content) = std::fs::read_to_string(dir.join("meminfo")) {
+            for line in content.lines() {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                if parts.len() >= 4 {
+                    let bytes = parts[3].parse::<u64>().unwrap_or(0) * 1024;
+                    match parts[2] {
+                        "MemTotal:" => node.total_memory = bytes,
+                        "MemFree:" => node.free_memory = bytes,
+                        "MemUsed:" => node.used_memory = bytes,
+                        _ => {}
+                    }
+                }
+            }
+        }
+        
+        if let Ok(content) = std::fs::read_to_string(dir.join("numastat")) {
+            for line in content.lines() {
+                if let Some((key, value)) = line.split_once(' ') {
+                    let value = value.trim().parse::<u64>().unwrap_or(0);
+                    match key {
+                        "numa_hit" => node.numa_hit = value,
+                        "numa_miss" => node.numa_miss = value,
+                        "numa_foreign" => node.numa_foreign = value,
+                        _ => {}
+                    }
+                }
+            }
+        }
+        
+        nodes.push(node);
+    }
+
+    nodes.sort_by_key(|node| node.node_id);
+    nodes
+}
+
+struct NumaVTab;
+
+impl VTab for NumaVTab {
+    type InitData = NumaInitData;
+    type BindData = NumaBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        // Parse unit parameter (default: MB)
+        let unit = if bind.get_named_parameter("unit").is_some() {
+            let unit_str = bind.get_named_parameter("unit").unwrap().to_string();
+            SizeUnit::from_str(&unit_str).unwrap_or(SizeUnit::MB)
+        } else {
+            SizeUnit::MB
+        };
+        
+        bind.add_result_column("node_id", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("total_memory", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("free_memory", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("used_memory", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("cpu_list", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Integer)));
+        bind.add_result_column("numa_hit", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("numa_miss", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("numa_foreign", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        Ok(NumaBindData { unit })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<NumaBindData>();
+        let unit = unsafe { (*bind_data).unit };
+        
+        #[cfg(target_os = "linux")]
+        let mut node_data = read_numa_nodes();
+        
+        #[cfg(not(target_os = "linux"))]
+        let mut node_data: Vec<NumaNodeInfo> = Vec::new();
+        
+        // Non-NUMA systems are reported as a single node 0 spanning all memory and CPUs
+        if node_data.is_empty() {
+            let mut sys = System::new_with_specifics(
+                RefreshKind::new()
+                    .with_memory(MemoryRefreshKind::everything())
+                    .with_cpu(CpuRefreshKind::new())
+            );
+            sys.refresh_memory();
+            
+            node_data.push(NumaNodeInfo {
+                node_id: 0,
+                total_memory: sys.total_memory(),
+                free_memory: sys.free_memory(),
+                used_memory: sys.used_memory(),
+                cpu_list: (0..sys.cpus().len() as i32).collect(),
+                numa_hit: 0,
+                numa_miss: 0,
+                numa_foreign: 0,
+            });
+        }
+        
+        let node_count = node_data.len();
+        
+        Ok(NumaInitData {
+            current_idx: AtomicUsize::new(0),
+            node_count,
+            node_data,
+            unit,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.node_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.node_count - current);
+        let batch = &init_data.node_data[current..current + batch_size];
+        let unit = init_data.unit;
+        
+        for (i, node) in batch.iter().enumerate() {
+            output.flat_vector(0).as_mut_slice::<i32>()[i] = node.node_id;
+            output.flat_vector(1).as_mut_slice::<f64>()[i] = unit.convert(node.total_memory);
+            output.flat_vector(2).as_mut_slice::<f64>()[i] = unit.convert(node.free_memory);
+            output.flat_vector(3).as_mut_slice::<f64>()[i] = unit.convert(node.used_memory);
+            output.flat_vector(5).as_mut_slice::<u64>()[i] = node.numa_hit;
+            output.flat_vector(6).as_mut_slice::<u64>()[i] = node.numa_miss;
+            output.flat_vector(7).as_mut_slice::<u64>()[i] = node.numa_foreign;
+            output.flat_vector(8).insert(i, CString::new(unit.name())?);
+        }
+        
+        let cpu_lists: Vec<&[i32]> = batch.iter().map(|node| node.cpu_list.as_slice()).collect();
+        insert_integer_lists(&mut output.list_vector(4), &cpu_lists);
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+    }
+}
+
+// ============================================================================
+// Mounts Table Function - sazgar_mounts()
+// Returns the full mount table with mount options
+// ============================================================================
+
+#[repr(C)]
+struct MountsBindData;
+
+struct MountInfo {
+    source: String,
+    mount_point: String,
+    fs_type: String,
+    options: Vec<String>,
+    is_readonly: bool,
+    is_bind_mount: Option<bool>,
+}
+
+#[repr(C)]
+struct MountsInitData {
+    current_idx: AtomicUsize,
+    mount_count: usize,
+    mount_data: Vec<MountInfo>,
+}
+
+/// Decode the octal escapes (e.g. "\040" for space) used in /proc mount tables
+#[cfg(target_os = "linux")]
+fn unescape_mount_field(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 4 <= bytes.len() {
+            if let Ok(value) = u8::from_str_radix(&String::from_utf8_lossy(&bytes[i + 1..i + 4]), 8) {
+                out.push(value);
+                i += 4;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+/// Parse /proc/self/mountinfo into the mount table
+#[cfg(target_os = "linux")]
+fn parse_mountinfo(content: &str) -> Vec<MountInfo> {
+    let mut mounts = Vec::new();
+    // (major:minor, root within the filesystem, btrfs subvolume) for bind mount detection
+    let mut origins: Vec<(String, String, Option<String>)> = Vec::new();
+
+    // Format: id parent major:minor root mount_point mount_opts [optional...] - fs_type source super_opts
+    for line in content.lines() {
+        let (left, right) = match line.split_once(" - ") {
+            Some(halves) => halves,
+            None => continue,
+        };
+        let left: Vec<&str> = left.split(' ').collect();
+        let right: Vec<&str> = right.split(' ').collect();
+        if left.len() < 6 || right.len() < 3 {
+            continue;
+        }
+        
+        let mount_options: Vec<String> = left[5].split(',').map(|o| o.to_string()).collect();
+        let is_readonly = mount_options.iter().any(|o| o == "ro");
+        
+        // Per-mount options first, then filesystem-wide options not already present
+        let mut options = mount_options;
+        for option in right[2].split(',') {
+            if !options.iter().any(|o| o == option) {
+                options.push(option.to_string());
+            }
+        }
+        
+        let subvolume = options.iter()
+            .find_map(|o| o.strip_prefix("subvol="))
+            .map(unescape_mount_field);
+        origins.push((left[2].to_string(), unescape_mount_field(left[3]), subvolume));
+        
+        mounts.push(MountInfo {
+            source: unescape_mount_field(right[1]),
+            mount_point: unescape_mount_field(left[4]),
+            fs_type: right[0].to_string(),
+            options,
+            is_readonly,
+            is_bind_mount: Some(false),
+        });
+    }
+    
+    // A bind mount exposes a subtree of a filesystem that is also mounted elsewhere, so the
+    // same major:minor shows up with a deeper root. btrfs subvolume mounts share major:minor
+    // too, but their root is the subvolume they were mounted with.
+    for (i, (device, root, subvolume)) in origins.iter().enumerate() {
+        let shallowest_root = origins.iter()
+            .filter(|(other, _, _)| other == device)
+            .map(|(_, other_root, _)| other_root.len())
+            .min()
+            .unwrap_or(root.len());
+        if root.len() > shallowest_root && subvolume.as_deref() != Some(root.as_str()) {
+            mounts[i].is_bind_mount = Some(true);
+        }
+    }
+
+    mounts
+}
+
+/// Read the mount table from /proc/self/mountinfo
+#[cfg(target_os = "linux")]
+fn read_mounts() -> Vec<MountInfo> {
+    std::fs::read_to_string("/proc/self/mountinfo")
+        .map(|content| parse_mountinfo(&content))
+        .unwrap_or_default()
+}
+
+/// Read the mount table from `mount` output, e.g. "/dev/disk3s1 on / (apfs, local, read-only)"
+#[cfg(target_os = "macos")]
+fn read_mounts() -> Vec<MountInfo> {
+    let mut mounts = Vec::new();
+
+    if let Ok(output) = std::process::Command::new("mount").output() {
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            for line in stdout.lines() {
+                let (source, rest) = match line.split_once(" on ") {
+                    Some(parts) => parts,
+                    None => continue,
+                };
+                let (mount_point, flags) = match rest.rsplit_once(" (") {
+                    Some(parts) => parts,
+                    None => continue,
+                };
+                let mut flags = flags.trim_end_matches(')').split(", ").map(|f| f.to_string());
+                let fs_type = flags.next().unwrap_or_default();
+                let options: Vec<String> = flags.collect();
+                let is_readonly = options.iter().any(|o| o == "read-only");
+                
+                mounts.push(MountInfo {
+                    source: source.to_string(),
+                    mount_point: mount_point.to_string(),
+                    fs_type,
+                    options,
+                    is_readonly,
+                    is_bind_mount: None,
+                });
+            }
+        }
+    }
+
+    mounts
+}
+
+/// List drive roots from GetLogicalDriveStringsW, described as mounts
+#[cfg(windows)]
+fn read_mounts() -> Vec<MountInfo> {
+    use windows_sys::Win32::Storage::FileSystem::{GetLogicalDriveStringsW, GetVolumeInformationW};
+    
+    let mut mounts = Vec::new();
+    let mut buffer = [0u16; 512];
+    let len = unsafe { GetLogicalDriveStringsW(buffer.len() as u32, buffer.as_mut_ptr()) } as usize;
+    if len == 0 || len > buffer.len() {
+        return mounts;
+    }
+    
+    // Buffer holds NUL-separated roots such as "C:\\"
+    for root in buffer[..len].split(|&c| c == 0).filter(|r| !r.is_empty()) {
+        let wide: Vec<u16> = root.iter().copied().chain(std::iter::once(0)).collect();
+        let mut fs_name = [0u16; 64];
+        let ok = unsafe {
+            GetVolumeInformationW(
+                wide.as_ptr(),
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                fs_name.as_mut_ptr(),
+                fs_name.len() as u32,
+            )
+        };
+        let fs_type = if ok != 0 {
+            let end = fs_name.iter().position(|&c| c == 0).unwrap_or(fs_name.len());
+            String::from_utf16_lossy(&fs_name[..end])
+        } else {
+            String::new()
+        };
+        
+        let root = String::from_utf16_lossy(root);
+        let options: Vec<String> = windows_volume_options(&root)
+            .split(',')
+            .filter(|o| !o.is_empty())
+            .map(|o| o.to_string())
+            .collect();
+        let is_readonly = options.first().is_some_and(|o| o == "ro");
+        
+        mounts.push(MountInfo {
+            source: root.clone(),
+            mount_point: root,
+            fs_type,
+            options,
+            is_readonly,
+            is_bind_mount: None,
+        });
+    }
+    
+    mounts
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn read_mounts() -> Vec<MountInfo> {
+    Vec::new()
+}
+
+struct MountsVTab;
+
+impl VTab for MountsVTab {
+    type InitData = MountsInitData;
+    type BindData = MountsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("source", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("mount_point", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("fs_type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("options", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar)));
+        bind.add_result_column("is_readonly", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("is_virtual", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("is_bind_mount", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        Ok(MountsBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let mount_data = read_mounts();
+        let mount_count = mount_data.len();
+        
+        Ok(MountsInitData {
+            current_idx: AtomicUsize::new(0),
+            mount_count,
+            mount_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.mount_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.mount_count - current);
+        let batch = &init_data.mount_data[current..current + batch_size];
+        
+        for (i, mount) in batch.iter().enumerate() {
+            output.flat_vector(0).insert(i, CString::new(mount.source.clone())?);
+            output.flat_vector(1).insert(i, CString::new(mount.mount_point.clone())?);
+            output.flat_vector(2).insert(i, CString::new(mount.fs_type.clone())?);
+            output.flat_vector(4).as_mut_slice::<bool>()[i] = mount.is_readonly;
+            // Same classification sazgar_disks() uses to hide a mount
+            output.flat_vector(5).as_mut_slice::<bool>()[i] = is_virtual_filesystem(&mount.mount_point, &mount.fs_type);
+            match mount.is_bind_mount {
+                Some(is_bind_mount) => output.flat_vector(6).as_mut_slice::<bool>()[i] = is_bind_mount,
+                None => output.flat_vector(6).set_null(i),
+            }
+        }
+        
+        let options: Vec<&[String]> = batch.iter().map(|m| m.options.as_slice()).collect();
+        insert_varchar_lists(&mut output.list_vector(3), &options)?;
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Tmpfs Table Function - sazgar_tmpfs()
+// Returns RAM-backed filesystem usage (tmpfs/ramfs), hidden by sazgar_disks()
+// ============================================================================
+
+#[repr(C)]
+struct TmpfsBindData {
+    unit: SizeUnit,
+}
+
+struct TmpfsInfo {
+    mount_point: String,
+    fs_type: String,
+    size_bytes: u64,
+    used_bytes: u64,
+    available_bytes: u64,
+}
+
+#[repr(C)]
+struct TmpfsInitData {
+    current_idx: AtomicUsize,
+    tmpfs_count: usize,
+    tmpfs_data: Vec<TmpfsInfo>,
+    unit: SizeUnit,
+}
+
+struct TmpfsVTab;
+
+impl VTab for TmpfsVTab {
+    type InitData = TmpfsInitData;
+    type BindData = TmpfsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        // Parse unit parameter (default: MB)
+        let unit = if bind.get_named_parameter("unit").is_some() {
+            let unit_str = bind.get_named_parameter("unit").unwrap().to_string();
+            SizeUnit::from_str(&unit_str).unwrap_or(SizeUnit::MB)
+        } else {
+            SizeUnit::MB
+        };
+        
+        bind.add_result_column("mount_point", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("fs_type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("size", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("used", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("available", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("usage_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        Ok(TmpfsBindData { unit })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<TmpfsBindData>();
+        let unit = unsafe { (*bind_data).unit };
+        
+        #[allow(unused_mut)]
+        let mut tmpfs_data: Vec<TmpfsInfo> = Vec::new();
+        
+        #[cfg(unix)]
+        {
+            for mount in read_mounts() {
+                if mount.fs_type != "tmpfs" && mount.fs_type != "ramfs" {
+                    continue;
+                }
+                if let Some((size_bytes, used_bytes, available_bytes)) = statvfs_usage(&mount.mount_point) {
+                    tmpfs_data.push(TmpfsInfo {
+                        mount_point: mount.mount_point,
+                        fs_type: mount.fs_type,
+                        size_bytes,
+                        used_bytes,
+                        available_bytes,
+                    });
+                }
+            }
+        }
+        
+        let tmpfs_count = tmpfs_data.len();
+        
+        Ok(TmpfsInitData {
+            current_idx: AtomicUsize::new(0),
+            tmpfs_count,
+            tmpfs_data,
+            unit,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.tmpfs_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.tmpfs_count - current);
+        let unit = init_data.unit;
+        
+        for i in 0..batch_size {
+            let tmpfs = &init_data.tmpfs_data[current + i];
+            let usage_percent = if tmpfs.size_bytes > 0 {
+                (tmpfs.used_bytes as f32 / tmpfs.size_bytes as f32) * 100.0
+            } else {
+                0.0
+            };
+            
+            output.flat_vector(0).insert(i, CString::new(tmpfs.mount_point.clone())?);
+            output.flat_vector(1).insert(i, CString::new(tmpfs.fs_type.clone())?);
+            output.flat_vector(2).as_mut_slice::<f64>()[i] = unit.convert(tmpfs.size_bytes);
+            output.flat_vector(3).as_mut_slice::<f64>()[i] = unit.convert(tmpfs.used_bytes);
+            output.flat_vector(4).as_mut_slice::<f64>()[i] = unit.convert(tmpfs.available_bytes);
+            output.flat_vector(5).as_mut_slice::<f32>()[i] = usage_percent;
+            output.flat_vector(6).insert(i, CString::new(unit.name())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+    }
+}
+
+// ============================================================================
+// Open Files Table Function - sazgar_open_files(pid)
+// Returns the target of each open file descriptor of a process (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct OpenFilesBindData {
+    pid: u32,
+}
+
+struct OpenFileInfo {
+    fd: i32,
+    path: String,
+    kind: String,
+}
+
+#[repr(C)]
+struct OpenFilesInitData {
+    current_idx: AtomicUsize,
+    file_count: usize,
+    file_data: Vec<OpenFileInfo>,
+    pid: u32,
+}
+
+/// Classify a /proc/<pid>/fd link target, e.g. "socket:[1234]" -> "socket"
+#[cfg(target_os = "linux")]
+fn fd_target_kind(target: &str) -> &'static str {
+    if target.starts_with("socket:") {
+        "socket"
+    } else if target.starts_with("pipe:") {
+        "pipe"
+    } else if target.starts_with("anon_inode:") {
+        "anon"
+    } else {
+        "file"
+    }
+}
+
+struct OpenFilesVTab;
+
+impl VTab for OpenFilesVTab {
+    type InitData = OpenFilesInitData;
+    type BindData = OpenFilesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("pid", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("fd", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("path", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        let pid = bind.get_parameter(0).to_int64();
+        if pid < 0 || pid > u32::MAX as i64 {
+            return Err(format!("Invalid pid: {}", pid).into());
+        }
+        
+        Ok(OpenFilesBindData { pid: pid as u32 })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<OpenFilesBindData>();
+        let pid = unsafe { (*bind_data).pid };
+        
+        #[allow(unused_mut)]
+        let mut file_data: Vec<OpenFileInfo> = Vec::new();
+        
+        // A vanished process or denied permission simply yields no rows
+        #[cfg(target_os = "linux")]
+        {
+            if let Ok(entries) = std::fs::read_dir(format!("/proc/{}/fd", pid)) {
+                for entry in entries.flatten() {
+                    let fd = match entry.file_name().to_string_lossy().parse::<i32>() {
+                        Ok(fd) => fd,
+                        Err(_) => continue,
+                    };
+                    let target = match std::fs::read_link(entry.path()) {
+                        Ok(target) => target.to_string_lossy().to_string(),
+                        Err(_) => continue,
+                    };
+                    file_data.push(OpenFileInfo {
+                        fd,
+                        kind: fd_target_kind(&target).to_string(),
+                        path: target,
+                    });
+                }
+            }
+            
+            file_data.sort_by_key(|f| f.fd);
+        }
+        
+        let file_count = file_data.len();
+        
+        Ok(OpenFilesInitData {
+            current_idx: AtomicUsize::new(0),
+            file_count,
+            file_data,
+            pid,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.file_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.file_count - current);
+        
+        for i in 0..batch_size {
+            let file = &init_data.file_data[current + i];
+            
+            output.flat_vector(0).as_mut_slice::<i32>()[i] = init_data.pid as i32;
+            output.flat_vector(1).as_mut_slice::<i32>()[i] = file.fd;
+            output.flat_vector(2).insert(i, CString::new(file.path.clone())?);
+            output.flat_vector(3).insert(i, CString::new(file.kind.clone())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Integer)])
+    }
+}
+
+// ============================================================================
+// Environment Secrets Table Function - sazgar_env_secrets()
+// Returns environment variables that look like secrets, with masked values
+// ============================================================================
+
+const DEFAULT_SECRET_PATTERNS: [&str; 5] = ["PASSWORD", "TOKEN", "SECRET", "KEY", "CREDENTIAL"];
+
+#[repr(C)]
+struct EnvSecretsBindData {
+    patterns: Vec<String>,
+}
+
+struct EnvSecretInfo {
+    name: String,
+    masked_value: String,
+    matched_pattern: String,
+}
+
+#[repr(C)]
+struct EnvSecretsInitData {
+    current_idx: AtomicUsize,
+    secret_count: usize,
+    secret_data: Vec<EnvSecretInfo>,
+}
+
+/// Mask a secret value, keeping only a few leading and trailing characters of long values
+fn mask_secret(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= 8 {
+        return "*".repeat(chars.len());
+    }
+    let head: String = chars[..2].iter().collect();
+    let tail: String = chars[chars.len() - 2..].iter().collect();
+    format!("{}{}{}", head, "*".repeat(chars.len() - 4), tail)
+}
+
+struct EnvSecretsVTab;
+
+impl VTab for EnvSecretsVTab {
+    type InitData = EnvSecretsInitData;
+    type BindData = EnvSecretsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("masked_value", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("matched_pattern", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        // Extra comma-separated patterns extend the defaults
+        let mut patterns: Vec<String> = DEFAULT_SECRET_PATTERNS.iter().map(|p| p.to_string()).collect();
+        if let Some(extra) = bind.get_named_parameter("patterns") {
+            for pattern in extra.to_string().split(',') {
+                let pattern = pattern.trim().to_uppercase();
+                if !pattern.is_empty() && !patterns.contains(&pattern) {
+                    patterns.push(pattern);
+                }
+            }
+        }
+        
+        Ok(EnvSecretsBindData { patterns })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<EnvSecretsBindData>();
+        let patterns = unsafe { (*bind_data).patterns.clone() };
+        
+        let mut secret_data: Vec<EnvSecretInfo> = std::env::vars()
+            .filter_map(|(name, value)| {
+                let upper = name.to_uppercase();
+                patterns.iter()
+                    .find(|pattern| upper.contains(pattern.as_str()))
+                    .map(|pattern| EnvSecretInfo {
+                        masked_value: mask_secret(&value),
+                        matched_pattern: pattern.clone(),
+                        name,
+                    })
+            })
+            .collect();
+        
+        secret_data.sort_by(|a, b| a.name.cmp(&b.name));
+        let secret_count = secret_data.len();
+        
+        Ok(EnvSecretsInitData {
+            current_idx: AtomicUsize::new(0),
+            secret_count,
+            secret_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.secret_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.secret_count - current);
+        
+        for i in 0..batch_size {
+            let secret = &init_data.secret_data[current + i];
+            output.flat_vector(0).insert(i, CString::new(secret.name.clone())?);
+            output.flat_vector(1).insert(i, CString::new(secret.masked_value.clone())?);
+            output.flat_vector(2).insert(i, CString::new(secret.matched_pattern.clone())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("patterns".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+    }
+}
+
+// ============================================================================
+// Zram Table Function - sazgar_zram()
+// Returns compressed RAM block devices with their compression ratio (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct ZramBindData {
+    unit: SizeUnit,
+}
+
+struct ZramInfo {
+    device: String,
+    disksize_bytes: u64,
+    orig_data_bytes: u64,
+    compr_data_bytes: u64,
+    mem_used_total_bytes: u64,
+    algorithm: String,
+}
+
+#[repr(C)]
+struct ZramInitData {
+    current_idx: AtomicUsize,
+    zram_count: usize,
+    zram_data: Vec<ZramInfo>,
+    unit: SizeUnit,
+}
+
+struct ZramVTab;
+
+impl VTab for ZramVTab {
+    type InitData = ZramInitData;
+    type BindData = ZramBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        // Parse unit parameter (default: MB)
+        let unit = if bind.get_named_parameter("unit").is_some() {
+            let unit_str = bind.get_named_parameter("unit").unwrap().to_string();
+            SizeUnit::from_str(&unit_str).unwrap_or(SizeUnit::MB)
+        } else {
+            SizeUnit::MB
+        };
+        
+        bind.add_result_column("device", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("disksize", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("orig_data_size", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("compr_data_size", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("mem_used_total", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("compression_ratio", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("algorithm", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        Ok(ZramBindData { unit })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<ZramBindData>();
+        let unit = unsafe { (*bind_data).unit };
+        
+        #[allow(unused_mut)]
+        let mut zram_data: Vec<ZramInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            if let Ok(entries) = std::fs::read_dir("/sys/block") {
+                for entry in entries.flatten() {
+                    let device = entry.file_name().to_string_lossy().to_string();
+                    if !device.starts_with("zram") {
+                        continue;
+                    }
+                    let base = format!("/sys/block/{}", device);
+                    
+                    // mm_stat: orig_data_size compr_data_size mem_used_total mem_limit ...
+                    let mm_stat: Vec<u64> = std::fs::read_to_string(format!("{}/mm_stat", base))
+                        .map(|content| content.split_whitespace().filter_map(|v| v.parse().ok()).collect())
+                        .unwrap_or_default();
+                    let field = |idx: usize| mm_stat.get(idx).copied().unwrap_or(0);
+                    
+                    let algorithm_path = format!("{}/comp_algorithm", base);
+                    let algorithm = read_sys_selected(&algorithm_path)
+                        .or_else(|| std::fs::read_to_string(&algorithm_path).ok().map(|s| s.trim().to_string()))
+                        .unwrap_or_default();
+                    
+                    zram_data.push(ZramInfo {
+                        disksize_bytes: read_sys_u64(&format!("{}/disksize", base)).unwrap_or(0),
+                        orig_data_bytes: field(0),
+                        compr_data_bytes: field(1),
+                        mem_used_total_bytes: field(2),
+                        algorithm,
+                        device,
+                    });
+                }
+            }
+            zram_data.sort_by(|a, b| a.device.cmp(&b.device));
+        }
+        
+        let zram_count = zram_data.len();
+        
+        Ok(ZramInitData {
+            current_idx: AtomicUsize::new(0),
+            zram_count,
+            zram_data,
+            unit,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.zram_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.zram_count - current);
+        let unit = init_data.unit;
+        
+        for i in 0..batch_size {
+            let zram = &init_data.zram_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(zram.device.clone())?);
+            output.flat_vector(1).as_mut_slice::<f64>()[i] = unit.convert(zram.disksize_bytes);
+            output.flat_vector(2).as_mut_slice::<f64>()[i] = unit.convert(zram.orig_data_bytes);
+            output.flat_vector(3).as_mut_slice::<f64>()[i] = unit.convert(zram.compr_data_bytes);
+            output.flat_vector(4).as_mut_slice::<f64>()[i] = unit.convert(zram.mem_used_total_bytes);
+            // No ratio until something has been compressed
+            if zram.compr_data_bytes > 0 {
+                output.flat_vector(5).as_mut_slice::<f64>()[i] = zram.orig_data_bytes as f64 / zram.compr_data_bytes as f64;
+            } else {
+                output.flat_vector(5).set_null(i);
+            }
+            output.flat_vector(6).insert(i, CString::new(zram.algorithm.clone())?);
+            output.flat_vector(7).insert(i, CString::new(unit.name())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+    }
+}
+
+// ============================================================================
+// Scheduler Stats Table Function - sazgar_sched_stats()
+// Returns per-process CPU time and run queue delay from /proc/<pid>/schedstat (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct SchedStatsBindData {
+    pid_filter: Option<u32>,
+}
+
+struct SchedStatInfo {
+    pid: u32,
+    process_name: String,
+    cpu_time_ns: u64,
+    run_delay_ns: u64,
+    timeslices: u64,
+}
+
+#[repr(C)]
+struct SchedStatsInitData {
+    current_idx: AtomicUsize,
+    stat_count: usize,
+    stat_data: Vec<SchedStatInfo>,
+}
+
+/// Read one process's schedstat: "<cpu_time_ns> <run_delay_ns> <timeslices>"
+#[cfg(target_os = "linux")]
+fn read_schedstat(pid: u32) -> Option<SchedStatInfo> {
+    let content = std::fs::read_to_string(format!("/proc/{}/schedstat", pid)).ok()?;
+    let mut fields = content.split_whitespace().map(|v| v.parse::<u64>().ok());
+    let cpu_time_ns = fields.next()??;
+    let run_delay_ns = fields.next()??;
+    let timeslices = fields.next()??;
+    let process_name = std::fs::read_to_string(format!("/proc/{}/comm", pid))
+        .map(|name| name.trim_end().to_string())
+        .unwrap_or_default();
+    
+    Some(SchedStatInfo {
+        pid,
+        process_name,
+        cpu_time_ns,
+        run_delay_ns,
+        timeslices,
+    })
+}
+
+struct SchedStatsVTab;
+
+impl VTab for SchedStatsVTab {
+    type InitData = SchedStatsInitData;
+    type BindData = SchedStatsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("pid", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("process_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("cpu_time_ns", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("run_delay_ns", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("timeslices", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        
+        let pid_filter = match bind.get_named_parameter("pid").map(|v| v.to_int64()) {
+            Some(pid) if pid < 0 || pid > u32::MAX as i64 => {
+                return Err(format!("sazgar_sched_stats: pid must be between 0 and {}, got {}", u32::MAX, pid).into());
+            }
+            pid => pid.map(|pid| pid as u32),
+        };
+        
+        Ok(SchedStatsBindData { pid_filter })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<SchedStatsBindData>();
+        #[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+        let pid_filter = unsafe { (*bind_data).pid_filter };
+        
+        #[allow(unused_mut)]
+        let mut stat_data: Vec<SchedStatInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            match pid_filter {
+                Some(pid) => stat_data.extend(read_schedstat(pid)),
+                None => {
+                    if let Ok(entries) = std::fs::read_dir("/proc") {
+                        for entry in entries.flatten() {
+                            if let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) {
+                                stat_data.extend(read_schedstat(pid));
+                            }
+                        }
+                    }
+                    stat_data.sort_by_key(|stat| stat.pid);
+                }
+            }
+        }
+        
+        let stat_count = stat_data.len();
+        
+        Ok(SchedStatsInitData {
+            current_idx: AtomicUsize::new(0),
+            stat_count,
+            stat_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.stat_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.stat_count - current);
+        
+        for i in 0..batch_size {
+            let stat = &init_data.stat_data[current + i];
+            
+            output.flat_vector(0).as_mut_slice::<i32>()[i] = stat.pid as i32;
+            output.flat_vector(1).insert(i, CString::new(stat.process_name.clone())?);
+            output.flat_vector(2).as_mut_slice::<u64>()[i] = stat.cpu_time_ns;
+            output.flat_vector(3).as_mut_slice::<u64>()[i] = stat.run_delay_ns;
+            output.flat_vector(4).as_mut_slice::<u64>()[i] = stat.timeslices;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("pid".to_string(), LogicalTypeHandle::from(LogicalTypeId::Integer))])
+    }
+}
+
+// ============================================================================
+// Device Tree Table Function - sazgar_device_tree()
+// Returns block device stacking (partitions, dm-crypt, LVM, md) from sysfs (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct DeviceTreeBindData;
+
+struct DeviceLinkInfo {
+    device: String,
+    parent: String,
+    relationship: String,
+}
+
+#[repr(C)]
+struct DeviceTreeInitData {
+    current_idx: AtomicUsize,
+    link_count: usize,
+    link_data: Vec<DeviceLinkInfo>,
+}
+
+/// List the entries of a sysfs directory such as /sys/class/block/<dev>/holders
+#[cfg(target_os = "linux")]
+fn read_sys_dir_names(path: &str) -> Vec<String> {
+    std::fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| e.file_name().to_string_lossy().to_string()).collect())
+        .unwrap_or_default()
+}
+
+struct DeviceTreeVTab;
+
+impl VTab for DeviceTreeVTab {
+    type InitData = DeviceTreeInitData;
+    type BindData = DeviceTreeBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("device", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("parent", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("relationship", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(DeviceTreeBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[allow(unused_mut)]
+        let mut link_data: Vec<DeviceLinkInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            // holders and slaves describe the same edges from both ends, so dedupe
+            let mut links: std::collections::BTreeSet<(String, String, &str)> = std::collections::BTreeSet::new();
+            
+            for device in read_sys_dir_names("/sys/class/block") {
+                let base = format!("/sys/class/block/{}", device);
+                
+                // A partition's sysfs directory sits inside its disk's directory
+                if std::path::Path::new(&format!("{}/partition", base)).exists() {
+                    let disk = std::fs::canonicalize(&base).ok()
+                        .and_then(|path| path.parent().and_then(|p| p.file_name()).map(|n| n.to_string_lossy().to_string()));
+                    if let Some(disk) = disk {
+                        links.insert((device.clone(), disk, "partition"));
+                    }
+                }
+                
+                // slaves: devices this one is built on (e.g. dm-0 -> sda2)
+                for slave in read_sys_dir_names(&format!("{}/slaves", base)) {
+                    links.insert((device.clone(), slave, "holder"));
+                }
+                
+                // holders: devices built on this one
+                for holder in read_sys_dir_names(&format!("{}/holders", base)) {
+                    links.insert((holder, device.clone(), "holder"));
+                }
+            }
+            
+            link_data.extend(links.into_iter().map(|(device, parent, relationship)| DeviceLinkInfo {
+                device,
+                parent,
+                relationship: relationship.to_string(),
+            }));
+        }
+        
+        let link_count = link_data.len();
+        
+        Ok(DeviceTreeInitData {
+            current_idx: AtomicUsize::new(0),
+            link_count,
+            link_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.link_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.link_count - current);
+        
+        for i in 0..batch_size {
+            let link = &init_data.link_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(link.device.clone())?);
+            output.flat_vector(1).insert(i, CString::new(link.parent.clone())?);
+            output.flat_vector(2).insert(i, CString::new(link.relationship.clone())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Disk I/O Table Function - sazgar_diskio()
+// Returns per-device I/O rates sampled over an interval, like iostat (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct DiskIoBindData {
+    interval_ms: u64,
+    include_partitions: bool,
+}
+
+struct DiskIoInfo {
+    device: String,
+    reads_per_sec: f64,
+    writes_per_sec: f64,
+    read_bytes_per_sec: f64,
+    write_bytes_per_sec: f64,
+    avg_latency_ms: Option<f64>,
+    utilization_percent: f32,
+}
+
+#[repr(C)]
+struct DiskIoInitData {
+    current_idx: AtomicUsize,
+    device_count: usize,
+    device_data: Vec<DiskIoInfo>,
+}
+
+/// Cumulative counters for one device from /proc/diskstats
+#[cfg(target_os = "linux")]
+struct DiskStatsSample {
+    reads: u64,
+    sectors_read: u64,
+    ms_reading: u64,
+    writes: u64,
+    sectors_written: u64,
+    ms_writing: u64,
+    ms_doing_io: u64,
+}
+
+/// Read /proc/diskstats keyed by device name
+#[cfg(target_os = "linux")]
+fn read_diskstats() -> std::collections::HashMap<String, DiskStatsSample> {
+    let mut samples = std::collections::HashMap::new();
+    if let Ok(content) = std::fs::read_to_string("/proc/diskstats") {
+        for line in content.lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 14 {
+                continue;
+            }
+            let field = |idx: usize| parts[idx].parse::<u64>().unwrap_or(0);
+            samples.insert(parts[2].to_string(), DiskStatsSample {
+                reads: field(3),
+                sectors_read: field(5),
+                ms_reading: field(6),
+                writes: field(7),
+                sectors_written: field(9),
+                ms_writing: field(10),
+                ms_doing_io: field(12),
+            });
+        }
+    }
+    samples
+}
+
+struct DiskIoVTab;
+
+impl VTab for DiskIoVTab {
+    type InitData = DiskIoInitData;
+    type BindData = DiskIoBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("device", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("reads_per_sec", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("writes_per_sec", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("read_bytes_per_sec", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("write_bytes_per_sec", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("avg_latency_ms", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("utilization_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        
+        // Sampling window, clamped to 50..10000 ms (default: 500)
+        let interval_ms = bind.get_named_parameter("interval_ms")
+            .map(|v| v.to_int64())
+            .unwrap_or(500)
+            .clamp(50, 10_000) as u64;
+        
+        let include_partitions = bind.get_named_parameter("include_partitions")
+            .map(|v| v.to_string().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        
+        Ok(DiskIoBindData { interval_ms, include_partitions })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<DiskIoBindData>();
+        #[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+        let (interval_ms, include_partitions) = unsafe { ((*bind_data).interval_ms, (*bind_data).include_partitions) };
+        
+        #[allow(unused_mut)]
+        let mut device_data: Vec<DiskIoInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            let before = read_diskstats();
+            let start = std::time::Instant::now();
+            std::thread::sleep(std::time::Duration::from_millis(interval_ms));
+            let after = read_diskstats();
+            let elapsed_secs = start.elapsed().as_secs_f64();
+            
+            for (device, end) in after {
+                let is_partition = std::path::Path::new(&format!("/sys/class/block/{}/partition", device)).exists();
+                if is_partition && !include_partitions {
+                    continue;
+                }
+                // Devices that appeared mid-sample have no baseline
+                let begin = match before.get(&device) {
+                    Some(begin) => begin,
+                    None => continue,
+                };
+                
+                let reads = end.reads.saturating_sub(begin.reads);
+                let writes = end.writes.saturating_sub(begin.writes);
+                let io_ms = end.ms_reading.saturating_sub(begin.ms_reading)
+                    + end.ms_writing.saturating_sub(begin.ms_writing);
+                // diskstats always counts 512-byte sectors, whatever the device's block size
+                let read_bytes = end.sectors_read.saturating_sub(begin.sectors_read) * 512;
+                let write_bytes = end.sectors_written.saturating_sub(begin.sectors_written) * 512;
+                let busy_ms = end.ms_doing_io.saturating_sub(begin.ms_doing_io);
+                
+                device_data.push(DiskIoInfo {
+                    device,
+                    reads_per_sec: reads as f64 / elapsed_secs,
+                    writes_per_sec: writes as f64 / elapsed_secs,
+                    read_bytes_per_sec: read_bytes as f64 / elapsed_secs,
+                    write_bytes_per_sec: write_bytes as f64 / elapsed_secs,
+                    avg_latency_ms: (reads + writes > 0).then(|| io_ms as f64 / (reads + writes) as f64),
+                    utilization_percent: (busy_ms as f64 / (elapsed_secs * 1000.0) * 100.0).min(100.0) as f32,
+                });
+            }
+            device_data.sort_by(|a, b| a.device.cmp(&b.device));
+        }
+        
+        let device_count = device_data.len();
+        
+        Ok(DiskIoInitData {
+            current_idx: AtomicUsize::new(0),
+            device_count,
+            device_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.device_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.device_count - current);
+        
+        for i in 0..batch_size {
+            let io = &init_data.device_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(io.device.clone())?);
+            output.flat_vector(1).as_mut_slice::<f64>()[i] = io.reads_per_sec;
+            output.flat_vector(2).as_mut_slice::<f64>()[i] = io.writes_per_sec;
+            output.flat_vector(3).as_mut_slice::<f64>()[i] = io.read_bytes_per_sec;
+            output.flat_vector(4).as_mut_slice::<f64>()[i] = io.write_bytes_per_sec;
+            match io.avg_latency_ms {
+                Some(latency) => output.flat_vector(5).as_mut_slice::<f64>()[i] = latency,
+                None => output.flat_vector(5).set_null(i),
+            }
+            output.flat_vector(6).as_mut_slice::<f32>()[i] = io.utilization_percent;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("interval_ms".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+            ("include_partitions".to_string(), LogicalTypeHandle::from(LogicalTypeId::Boolean)),
+        ])
+    }
+}
+
+// ============================================================================
+// Port States Table Function - sazgar_port_states()
+// Returns TCP connection counts grouped by local port and state
+// ============================================================================
+
+#[repr(C)]
+struct PortStatesBindData;
+
+struct PortStateInfo {
+    local_port: u16,
+    state: String,
+    connection_count: u64,
+}
+
+#[repr(C)]
+struct PortStatesInitData {
+    current_idx: AtomicUsize,
+    state_count: usize,
+    state_data: Vec<PortStateInfo>,
+}
+
+struct PortStatesVTab;
+
+impl VTab for PortStatesVTab {
+    type InitData = PortStatesInitData;
+    type BindData = PortStatesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("local_port", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("connection_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        Ok(PortStatesBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
+        
+        let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+        
+        // UDP sockets have no connection state, so only TCP is counted
+        let mut counts: std::collections::BTreeMap<(u16, String), u64> = std::collections::BTreeMap::new();
+        if let Ok(sockets) = get_sockets_info(af_flags, ProtocolFlags::TCP) {
+            for socket in sockets {
+                if let ProtocolSocketInfo::Tcp(tcp) = &socket.protocol_socket_info {
+                    *counts.entry((tcp.local_port, format!("{:?}", tcp.state))).or_insert(0) += 1;
+                }
+            }
+        }
+        
+        let state_data: Vec<PortStateInfo> = counts
+            .into_iter()
+            .map(|((local_port, state), connection_count)| PortStateInfo { local_port, state, connection_count })
+            .collect();
+        let state_count = state_data.len();
+        
+        Ok(PortStatesInitData {
+            current_idx: AtomicUsize::new(0),
+            state_count,
+            state_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.state_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.state_count - current);
+        
+        for i in 0..batch_size {
+            let entry = &init_data.state_data[current + i];
+            
+            output.flat_vector(0).as_mut_slice::<i32>()[i] = entry.local_port as i32;
+            output.flat_vector(1).insert(i, CString::new(entry.state.clone())?);
+            output.flat_vector(2).as_mut_slice::<u64>()[i] = entry.connection_count;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// FD Summary Table Function - sazgar_fd_summary()
+// Returns system-wide file handle usage and the process holding the most fds
+// ============================================================================
+
+#[repr(C)]
+struct FdSummaryBindData;
+
+#[repr(C)]
+struct FdSummaryInitData {
+    done: AtomicBool,
+    system_open_files: Option<u64>,
+    system_file_max: Option<u64>,
+    top_process: Option<(u32, String, u64)>,
+}
+
+struct FdSummaryVTab;
+
+impl VTab for FdSummaryVTab {
+    type InitData = FdSummaryInitData;
+    type BindData = FdSummaryBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("system_open_files", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("system_file_max", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("system_usage_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("top_process_pid", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("top_process_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("top_process_fd_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        Ok(FdSummaryBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "linux")]
+        let (system_open_files, system_file_max, top_process) = {
+            let open_files = read_file_nr();
+            
+            let sys = System::new_with_specifics(
+                RefreshKind::new().with_processes(ProcessRefreshKind::new())
+            );
+            let top_process = sys.processes()
+                .iter()
+                .filter_map(|(pid, proc)| {
+                    let count = std::fs::read_dir(format!("/proc/{}/fd", pid.as_u32())).ok()?.count() as u64;
+                    Some((pid.as_u32(), proc.name().to_string_lossy().to_string(), count))
+                })
+                .max_by_key(|(_, _, count)| *count);
+            
+            (open_files, read_sys_u64("/proc/sys/fs/file-max"), top_process)
+        };
+        
+        #[cfg(not(target_os = "linux"))]
+        let (system_open_files, system_file_max, top_process) = (None, None, None);
+        
+        Ok(FdSummaryInitData {
+            done: AtomicBool::new(false),
+            system_open_files,
+            system_file_max,
+            top_process,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        
+        if init_data.done.swap(true, Ordering::Relaxed) {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        match init_data.system_open_files {
+            Some(open_files) => output.flat_vector(0).as_mut_slice::<u64>()[0] = open_files,
+            None => output.flat_vector(0).set_null(0),
+        }
+        match init_data.system_file_max {
+            Some(file_max) => output.flat_vector(1).as_mut_slice::<u64>()[0] = file_max,
+            None => output.flat_vector(1).set_null(0),
+        }
+        match (init_data.system_open_files, init_data.system_file_max) {
+            (Some(open_files), Some(file_max)) if file_max > 0 => {
+                output.flat_vector(2).as_mut_slice::<f32>()[0] = (open_files as f64 / file_max as f64 * 100.0) as f32;
+            }
+            _ => output.flat_vector(2).set_null(0),
+        }
+        match &init_data.top_process {
+            Some((pid, name, count)) => {
+                output.flat_vector(3).as_mut_slice::<i32>()[0] = *pid as i32;
+                output.flat_vector(4).insert(0, CString::new(name.clone())?);
+                output.flat_vector(5).as_mut_slice::<u64>()[0] = *count;
+            }
+            None => {
+                output.flat_vector(3).set_null(0);
+                output.flat_vector(4).set_null(0);
+                output.flat_vector(5).set_null(0);
+            }
+        }
+        
+        output.set_len(1);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// SMART Table Function - sazgar_smart()
+// Returns drive health from SMART data (via smartctl on Linux/macOS)
+// ============================================================================
+
+#[repr(C)]
+struct SmartBindData;
+
+struct SmartInfo {
+    device: String,
+    model: Option<String>,
+    serial: Option<String>,
+    power_on_hours: Option<u64>,
+    reallocated_sectors: Option<u64>,
+    percentage_used: Option<i32>,
+    temperature_celsius: Option<i32>,
+    health: Option<String>,
+    error: Option<String>,
+}
+
+#[repr(C)]
+struct SmartInitData {
+    current_idx: AtomicUsize,
+    drive_count: usize,
+    drive_data: Vec<SmartInfo>,
+}
+
+/// How long a single smartctl invocation may take; a drive stuck in error recovery can hang it
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+const SMARTCTL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Read SMART data for every drive smartctl can find; None when smartctl is not installed
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn read_smart_smartctl() -> Option<Vec<SmartInfo>> {
+    let (_, scan) = command_run_with_timeout("smartctl", &["--scan", "--json"], SMARTCTL_TIMEOUT)?;
+    let scan: serde_json::Value = serde_json::from_str(&scan).ok()?;
+    
+    let mut drives = Vec::new();
+    for entry in scan["devices"].as_array().into_iter().flatten() {
+        let name = match entry["name"].as_str() {
+            Some(name) => name,
+            None => continue,
+        };
+        let dev_type = entry["type"].as_str().unwrap_or("auto");
+        
+        // -n standby: don't spin up sleeping disks just to read their counters
+        let report = command_run_with_timeout(
+            "smartctl",
+            &["--json", "-n", "standby", "-i", "-H", "-A", "-d", dev_type, name],
+            SMARTCTL_TIMEOUT,
+        )
+        .and_then(|(_, stdout)| serde_json::from_str::<serde_json::Value>(&stdout).ok());
+        
+        drives.push(match report {
+            Some(report) => parse_smartctl_report(name, &report),
+            None => SmartInfo {
+                device: name.to_string(),
+                model: None,
+                serial: None,
+                power_on_hours: None,
+                reallocated_sectors: None,
+                percentage_used: None,
+                temperature_celsius: None,
+                health: None,
+                error: Some("smartctl did not return a report".to_string()),
+            },
+        });
+    }
+    
+    Some(drives)
+}
+
+/// Extract health fields from one `smartctl --json` device report
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn parse_smartctl_report(device: &str, report: &serde_json::Value) -> SmartInfo {
+    let ata_attribute = |ids: &[u64]| {
+        report["ata_smart_attributes"]["table"]
+            .as_array()
+            .and_then(|table| table.iter().find(|attr| attr["id"].as_u64().is_some_and(|id| ids.contains(&id))))
+            .cloned()
+    };
+    
+    // NVMe reports wear directly; SATA SSDs expose a normalized life-left attribute (100 = new)
+    let percentage_used = report["nvme_smart_health_information_log"]["percentage_used"]
+        .as_i64()
+        .map(|used| used as i32)
+        .or_else(|| {
+            ata_attribute(&[177, 231, 233])
+                .and_then(|attr| attr["value"].as_i64())
+                .map(|left| (100 - left).max(0) as i32)
+        });
+    
+    // smartctl messages explain why a report is empty, e.g. permission denied or standby
+    let error = report["smartctl"]["messages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|msg| msg["severity"].as_str() == Some("error"))
+        .filter_map(|msg| msg["string"].as_str())
+        .collect::<Vec<_>>()
+        .join("; ");
+    
+    SmartInfo {
+        device: device.to_string(),
+        model: report["model_name"].as_str().map(|s| s.trim().to_string()),
+        serial: report["serial_number"].as_str().map(|s| s.trim().to_string()),
+        power_on_hours: report["power_on_time"]["hours"].as_u64(),
+        reallocated_sectors: ata_attribute(&[5]).and_then(|attr| attr["raw"]["value"].as_u64()),
+        percentage_used,
+        temperature_celsius: report["temperature"]["current"].as_i64().map(|t| t as i32),
+        health: report["smart_status"]["passed"]
+            .as_bool()
+            .map(|passed| if passed { "PASSED" } else { "FAILED" }.to_string()),
+        error: if error.is_empty() { None } else { Some(error) },
+    }
+}
+
+struct SmartVTab;
+
+impl VTab for SmartVTab {
+    type InitData = SmartInitData;
+    type BindData = SmartBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("device", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("model", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("serial", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("power_on_hours", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("reallocated_sectors", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("percentage_used", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("temperature_celsius", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("health", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("error", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(SmartBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        // smartctl is the only backend for now; a native ioctl/IOKit reader can be tried first later
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        let drive_data = read_smart_smartctl().unwrap_or_default();
+        
+        #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+        let drive_data: Vec<SmartInfo> = Vec::new();
+        
+        let drive_count = drive_data.len();
+        
+        Ok(SmartInitData {
+            current_idx: AtomicUsize::new(0),
+            drive_count,
+            drive_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.drive_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.drive_count - current);
+        
+        for i in 0..batch_size {
+            let drive = &init_data.drive_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(drive.device.clone())?);
+            for (col, value) in [(1, &drive.model), (2, &drive.serial), (7, &drive.health), (8, &drive.error)] {
+                match value {
+                    Some(value) => output.flat_vector(col).insert(i, CString::new(value.clone())?),
+                    None => output.flat_vector(col).set_null(i),
+                }
+            }
+            for (col, value) in [(3, drive.power_on_hours), (4, drive.reallocated_sectors)] {
+                match value {
+                    Some(value) => output.flat_vector(col).as_mut_slice::<u64>()[i] = value,
+                    None => output.flat_vector(col).set_null(i),
+                }
+            }
+            match drive.percentage_used {
+                Some(used) => output.flat_vector(5).as_mut_slice::<f32>()[i] = used as f32,
+                None => output.flat_vector(5).set_null(i),
+            }
+            match drive.temperature_celsius {
+                Some(temp) => output.flat_vector(6).as_mut_slice::<i32>()[i] = temp,
+                None => output.flat_vector(6).set_null(i),
+            }
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Block Devices Table Function - sazgar_block_devices()
+// Returns lsblk-style block device topology (Linux/macOS)
+// ============================================================================
+
+#[repr(C)]
+struct BlockDevicesBindData;
+
+struct BlockDeviceInfo {
+    name: String,
+    parent: Option<String>,
+    device_type: String,
+    size_bytes: u64,
+    fs_type: Option<String>,
+    mount_point: Option<String>,
+    uuid: Option<String>,
+}
+
+#[repr(C)]
+struct BlockDevicesInitData {
+    current_idx: AtomicUsize,
+    device_count: usize,
+    device_data: Vec<BlockDeviceInfo>,
+}
+
+/// Classify a block device the way lsblk's TYPE column does
+#[cfg(target_os = "linux")]
+fn block_device_type(name: &str) -> String {
+    let base = format!("/sys/class/block/{}", name);
+    if std::path::Path::new(&format!("{}/partition", base)).exists() {
+        return "part".to_string();
+    }
+    if let Ok(dm_uuid) = std::fs::read_to_string(format!("{}/dm/uuid", base)) {
+        // device-mapper targets identify themselves by uuid prefix
+        let kind = match dm_uuid.split('-').next().unwrap_or("") {
+            "LVM" => "lvm",
+            "CRYPT" => "crypt",
+            "mpath" => "mpath",
+            _ => "dm",
+        };
+        return kind.to_string();
+    }
+    if std::path::Path::new(&format!("{}/md", base)).exists() {
+        return "raid".to_string();
+    }
+    if name.starts_with("loop") {
+        return "loop".to_string();
+    }
+    if name.starts_with("sr") {
+        return "rom".to_string();
+    }
+    "disk".to_string()
+}
+
+/// Read filesystem type and UUID from the udev database, e.g. /run/udev/data/b8:1
+#[cfg(target_os = "linux")]
+fn read_udev_fs_info(name: &str) -> (Option<String>, Option<String>) {
+    let dev = match std::fs::read_to_string(format!("/sys/class/block/{}/dev", name)) {
+        Ok(dev) => dev,
+        Err(_) => return (None, None),
+    };
+    let mut fs_type = None;
+    let mut uuid = None;
+    if let Ok(content) = std::fs::read_to_string(format!("/run/udev/data/b{}", dev.trim())) {
+        for line in content.lines() {
+            if let Some(value) = line.strip_prefix("E:ID_FS_TYPE=") {
+                fs_type = Some(value.to_string()).filter(|v| !v.is_empty());
+            } else if let Some(value) = line.strip_prefix("E:ID_FS_UUID=") {
+                uuid = Some(value.to_string()).filter(|v| !v.is_empty());
+            }
+        }
+    }
+    (fs_type, uuid)
+}
+
+#[cfg(target_os = "linux")]
+fn read_block_devices() -> Vec<BlockDeviceInfo> {
+    use std::collections::HashMap;
+    
+    // Kernel device name -> UUID from the /dev/disk/by-uuid symlinks
+    let mut uuids: HashMap<String, String> = HashMap::new();
+    if let Ok(entries) = std::fs::read_dir("/dev/disk/by-uuid") {
+        for entry in entries.flatten() {
+            if let Ok(target) = std::fs::canonicalize(entry.path()) {
+                if let Some(name) = target.file_name() {
+                    uuids.insert(name.to_string_lossy().to_string(), entry.file_name().to_string_lossy().to_string());
+                }
+            }
+        }
+    }
+    
+    // Kernel device name -> (mount point, fs type); the first mount of a device wins
+    let mut mounts: HashMap<String, (String, String)> = HashMap::new();
+    for mount in read_mounts() {
+        if !mount.source.starts_with("/dev/") {
+            continue;
+        }
+        if let Some(name) = std::fs::canonicalize(&mount.source).ok().and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string())) {
+            mounts.entry(name).or_insert((mount.mount_point, mount.fs_type));
+        }
+    }
+    
+    let mut devices = Vec::new();
+    for name in read_sys_dir_names("/sys/class/block") {
+        let base = format!("/sys/class/block/{}", name);
+        let device_type = block_device_type(&name);
+        
+        let mut parents: Vec<String> = if device_type == "part" {
+            // A partition's sysfs directory sits inside its disk's directory
+            std::fs::canonicalize(&base).ok()
+                .and_then(|path| path.parent().and_then(|p| p.file_name()).map(|n| n.to_string_lossy().to_string()))
+                .into_iter()
+                .collect()
+        } else {
+            read_sys_dir_names(&format!("{}/slaves", base))
+        };
+        parents.sort();
+        
+        let (udev_fs_type, udev_uuid) = read_udev_fs_info(&name);
+        let mount = mounts.get(&name);
+        let fs_type = udev_fs_type.or_else(|| mount.map(|(_, fs_type)| fs_type.clone()));
+        let mount_point = mount.map(|(mount_point, _)| mount_point.clone());
+        let uuid = udev_uuid.or_else(|| uuids.get(&name).cloned());
+        // sysfs sizes are always in 512-byte sectors
+        let size_bytes = read_sys_u64(&format!("{}/size", base)).unwrap_or(0) * 512;
+        
+        // One row per parent so that md/LVM devices spanning several disks link to each
+        let parents: Vec<Option<String>> = if parents.is_empty() {
+            vec![None]
+        } else {
+            parents.into_iter().map(Some).collect()
+        };
+        for parent in parents {
+            devices.push(BlockDeviceInfo {
+                name: name.clone(),
+                parent,
+                device_type: device_type.clone(),
+                size_bytes,
+                fs_type: fs_type.clone(),
+                mount_point: mount_point.clone(),
+                uuid: uuid.clone(),
+            });
+        }
+    }
+    
+    devices.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.parent.cmp(&b.parent)));
+    devices
+}
+
+/// Parse `diskutil info -all`, whose records are separated by lines of asterisks
+#[cfg(target_os = "macos")]
+fn read_block_devices() -> Vec<BlockDeviceInfo> {
+    let output = match command_output_with_timeout("diskutil", &["info", "-all"], std::time::Duration::from_secs(10)) {
+        Some(output) => output,
+        None => return Vec::new(),
+    };
+    
+    let mut devices = Vec::new();
+    for record in output.split("**********") {
+        let fields: std::collections::HashMap<&str, &str> = record
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .collect();
+        let name = match fields.get("Device Identifier") {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        let is_whole = fields.get("Whole") == Some(&"Yes");
+        let non_empty = |key: &str| fields.get(key).map(|v| v.to_string()).filter(|v| !v.is_empty());
+        
+        // APFS containers are synthesized disks backed by a partition
+        let parent = if is_whole {
+            non_empty("APFS Physical Store")
+        } else {
+            non_empty("Part of Whole")
+        };
+        // "Disk Size: 500.3 GB (500277792768 Bytes) (exactly ...)"
+        let size_bytes = fields.get("Disk Size")
+            .and_then(|v| v.split_once('(').and_then(|(_, rest)| rest.split_whitespace().next()))
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(0);
+        
+        devices.push(BlockDeviceInfo {
+            name,
+            parent,
+            device_type: if is_whole { "disk" } else { "part" }.to_string(),
+            size_bytes,
+            fs_type: non_empty("Type (Bundle)"),
+            mount_point: non_empty("Mount Point").filter(|v| !v.starts_with("Not applicable")),
+            uuid: non_empty("Volume UUID").filter(|v| !v.starts_with("Not applicable")),
+        });
+    }
+    
+    devices
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn read_block_devices() -> Vec<BlockDeviceInfo> {
+    Vec::new()
+}
+
+struct BlockDevicesVTab;
+
+impl VTab for BlockDevicesVTab {
+    type InitData = BlockDevicesInitData;
+    type BindData = BlockDevicesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("parent", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("size_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("fs_type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("mount_point", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("uuid", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(BlockDevicesBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let device_data = read_block_devices();
+        let device_count = device_data.len();
+        
+        Ok(BlockDevicesInitData {
+            current_idx: AtomicUsize::new(0),
+            device_count,
+            device_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.device_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.device_count - current);
+        
+        for i in 0..batch_size {
+            let device = &init_data.device_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(device.name.clone())?);
+            output.flat_vector(2).insert(i, CString::new(device.device_type.clone())?);
+            output.flat_vector(3).as_mut_slice::<u64>()[i] = device.size_bytes;
+            for (col, value) in [(1, &device.parent), (4, &device.fs_type), (5, &device.mount_point), (6, &device.uuid)] {
+                match value {
+                    Some(value) => output.flat_vector(col).insert(i, CString::new(value.clone())?),
+                    None => output.flat_vector(col).set_null(i),
+                }
+            }
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Disk Usage Table Function - sazgar_du(path)
+// Returns directory sizes like `du`, walking the tree incrementally across scan calls
+// ============================================================================
+
+#[repr(C)]
+struct DuBindData {
+    path: String,
+    max_depth: usize,
+    one_filesystem: bool,
+}
+
+struct DuRow {
+    path: String,
+    depth: usize,
+    size_bytes: u64,
+    file_count: u64,
+    dir_count: u64,
+}
+
+/// A directory whose subtree is still being summed
+struct DuFrame {
+    path: std::path::PathBuf,
+    depth: usize,
+    entries: Option<std::fs::ReadDir>,
+    size_bytes: u64,
+    file_count: u64,
+    dir_count: u64,
+}
+
+/// Depth-first walk state; directories are emitted once their whole subtree has been summed
+struct DuWalker {
+    stack: Vec<DuFrame>,
+    ready: std::collections::VecDeque<DuRow>,
+    root_device: Option<u64>,
+    max_depth: usize,
+    one_filesystem: bool,
+    // Hard-linked files are counted once, like du does
+    seen_inodes: std::collections::HashSet<(u64, u64)>,
+}
+
+#[repr(C)]
+struct DuInitData {
+    walker: Mutex<DuWalker>,
+}
+
+/// Device id of a file, used to stay on one filesystem
+#[cfg(unix)]
+fn du_device(meta: &std::fs::Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(meta.dev())
+}
+
+#[cfg(not(unix))]
+fn du_device(_meta: &std::fs::Metadata) -> Option<u64> {
+    None
+}
+
+/// Space a file occupies on disk; sparse files count only their allocated blocks
+#[cfg(unix)]
+fn du_allocated_size(meta: &std::fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    meta.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn du_allocated_size(meta: &std::fs::Metadata) -> u64 {
+    meta.len()
+}
+
+/// Inode key for files with more than one hard link
+#[cfg(unix)]
+fn du_hard_link_key(meta: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    (meta.nlink() > 1).then(|| (meta.dev(), meta.ino()))
+}
+
+#[cfg(not(unix))]
+fn du_hard_link_key(_meta: &std::fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+impl DuWalker {
+    fn new(root: &str, max_depth: usize, one_filesystem: bool) -> Self {
+        let mut walker = DuWalker {
+            stack: Vec::new(),
+            ready: std::collections::VecDeque::new(),
+            root_device: None,
+            max_depth,
+            one_filesystem,
+            seen_inodes: std::collections::HashSet::new(),
+        };
+        
+        if let Ok(meta) = std::fs::symlink_metadata(root) {
+            walker.root_device = du_device(&meta);
+            if meta.is_dir() {
+                walker.push_dir(std::path::PathBuf::from(root), 0, &meta);
+            } else {
+                walker.ready.push_back(DuRow {
+                    path: root.to_string(),
+                    depth: 0,
+                    size_bytes: du_allocated_size(&meta),
+                    file_count: 1,
+                    dir_count: 0,
+                });
+            }
+        }
+        
+        walker
+    }
+    
+    fn push_dir(&mut self, path: std::path::PathBuf, depth: usize, meta: &std::fs::Metadata) {
+        // Unreadable directories still count with their own size, just not their contents
+        let entries = std::fs::read_dir(&path).ok();
+        self.stack.push(DuFrame {
+            path,
+            depth,
+            entries,
+            size_bytes: du_allocated_size(meta),
+            file_count: 0,
+            dir_count: 0,
+        });
+    }
+    
+    /// Process one directory entry, or finish the current directory; false once the walk is done
+    fn step(&mut self) -> bool {
+        let frame = match self.stack.last_mut() {
+            Some(frame) => frame,
+            None => return false,
+        };
+        
+        let next = frame.entries.as_mut().and_then(|entries| entries.next());
+        match next {
+            Some(Ok(entry)) => {
+                let depth = frame.depth + 1;
+                // DirEntry::metadata does not follow symlinks, so they are counted but never traversed
+                let meta = match entry.metadata() {
+                    Ok(meta) => meta,
+                    Err(_) => return true,
+                };
+                if meta.is_dir() {
+                    if self.one_filesystem && du_device(&meta) != self.root_device {
+                        return true;
+                    }
+                    self.push_dir(entry.path(), depth, &meta);
+                } else {
+                    if let Some(key) = du_hard_link_key(&meta) {
+                        if !self.seen_inodes.insert(key) {
+                            return true;
+                        }
+                    }
+                    frame.size_bytes += du_allocated_size(&meta);
+                    frame.file_count += 1;
+                }
+            }
+            // Entries that vanish or error mid-listing are skipped
+            Some(Err(_)) => {}
+            None => {
+                let done = self.stack.pop().expect("stack is non-empty");
+                if let Some(parent) = self.stack.last_mut() {
+                    parent.size_bytes += done.size_bytes;
+                    parent.file_count += done.file_count;
+                    parent.dir_count += done.dir_count + 1;
+                }
+                if done.depth <= self.max_depth {
+                    self.ready.push_back(DuRow {
+                        path: done.path.to_string_lossy().to_string(),
+                        depth: done.depth,
+                        size_bytes: done.size_bytes,
+                        file_count: done.file_count,
+                        dir_count: done.dir_count,
+                    });
+                }
+            }
+        }
+        true
+    }
+}
+
+struct DuVTab;
+
+impl VTab for DuVTab {
+    type InitData = DuInitData;
+    type BindData = DuBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("path", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("depth", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("size_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("file_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("dir_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        
+        let path = bind.get_parameter(0).to_string().trim_matches('"').to_string();
+        if std::fs::symlink_metadata(&path).is_err() {
+            return Err(format!("Cannot access path: {}", path).into());
+        }
+        
+        let max_depth = bind.get_named_parameter("max_depth")
+            .map(|v| v.to_int64())
+            .unwrap_or(2);
+        if max_depth < 0 {
+            return Err(format!("Invalid max_depth: {}", max_depth).into());
+        }
+        
+        let one_filesystem = bind.get_named_parameter("one_filesystem")
+            .map(|v| v.to_string().eq_ignore_ascii_case("true"))
+            .unwrap_or(true);
+        
+        Ok(DuBindData { path, max_depth: max_depth as usize, one_filesystem })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<DuBindData>();
+        let (path, max_depth, one_filesystem) = unsafe {
+            ((*bind_data).path.clone(), (*bind_data).max_depth, (*bind_data).one_filesystem)
+        };
+        
+        // The walk itself happens in func, so a long scan can be cancelled between chunks
+        Ok(DuInitData {
+            walker: Mutex::new(DuWalker::new(&path, max_depth, one_filesystem)),
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let mut walker = init_data.walker.lock().map_err(|_| "sazgar_du walker poisoned")?;
+        
+        // Return as soon as some rows are ready and a slice of work is done, rather than
+        // walking the whole tree in one call
+        let mut steps = 0;
+        while walker.ready.len() < 2048 && (walker.ready.is_empty() || steps < 10_000) {
+            if !walker.step() {
+                break;
+            }
+            steps += 1;
+        }
+        
+        let batch_size = std::cmp::min(2048, walker.ready.len());
+        for i in 0..batch_size {
+            let row = walker.ready.pop_front().expect("batch is within ready rows");
+            output.flat_vector(0).insert(i, CString::new(row.path)?);
+            output.flat_vector(1).as_mut_slice::<i32>()[i] = row.depth as i32;
+            output.flat_vector(2).as_mut_slice::<u64>()[i] = row.size_bytes;
+            output.flat_vector(3).as_mut_slice::<u64>()[i] = row.file_count;
+            output.flat_vector(4).as_mut_slice::<u64>()[i] = row.dir_count;
+        }
+        
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)])
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("max_depth".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+            ("one_filesystem".to_string(), LogicalTypeHandle::from(LogicalTypeId::Boolean)),
+        ])
+    }
+}
+
+// ============================================================================
+// DNS Table Function - sazgar_dns()
+// Returns resolver configuration (nameservers, search domains, options)
+// ============================================================================
+
+#[repr(C)]
+struct DnsBindData;
+
+struct DnsEntry {
+    entry_type: String,
+    value: String,
+}
+
+#[repr(C)]
+struct DnsInitData {
+    current_idx: AtomicUsize,
+    entry_count: usize,
+    entry_data: Vec<DnsEntry>,
+}
+
+/// Parse /etc/resolv.conf; each search domain and option gets its own row
+#[cfg(unix)]
+fn read_dns_config() -> Vec<DnsEntry> {
+    let Ok(content) = std::fs::read_to_string("/etc/resolv.conf") else {
+        return Vec::new();
+    };
+    let resolv = parse_resolv_conf(&content);
+    
+    let entry = |entry_type: &str, value: String| DnsEntry { entry_type: entry_type.to_string(), value };
+    let mut entries: Vec<DnsEntry> = resolv.nameservers.into_iter().map(|ns| entry("nameserver", ns)).collect();
+    entries.extend(resolv.search.into_iter().map(|domain| entry(resolv.search_keyword, domain)));
+    entries.extend(resolv.options.into_iter().map(|option| entry("option", option)));
+    entries
+}
+
+/// Read DNS servers and the primary domain from GetNetworkParams
+#[cfg(windows)]
+fn read_dns_config() -> Vec<DnsEntry> {
+    use windows_sys::Win32::NetworkManagement::IpHelper::{GetNetworkParams, FIXED_INFO_W2KSP1, IP_ADDR_STRING};
+    
+    let mut entries = Vec::new();
+    let c_string = |chars: &[i8]| {
+        let bytes: Vec<u8> = chars.iter().take_while(|&&c| c != 0).map(|&c| c as u8).collect();
+        String::from_utf8_lossy(&bytes).to_string()
+    };
+    
+    // The server list is a linked list stored after the struct, so size the buffer first
+    let mut size: u32 = 0;
+    unsafe { GetNetworkParams(std::ptr::null_mut(), &mut size) };
+    if size == 0 {
+        return entries;
+    }
+    let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+    let info = buffer.as_mut_ptr() as *mut FIXED_INFO_W2KSP1;
+    if unsafe { GetNetworkParams(info, &mut size) } != 0 {
+        return entries;
+    }
+    
+    let info = unsafe { &*info };
+    let mut server: *const IP_ADDR_STRING = &info.DnsServerList;
+    while !server.is_null() {
+        let address = c_string(unsafe { &(*server).IpAddress.String });
+        if !address.is_empty() {
+            entries.push(DnsEntry { entry_type: "nameserver".to_string(), value: address });
+        }
+        server = unsafe { (*server).Next };
+    }
+    
+    let domain = c_string(&info.DomainName);
+    if !domain.is_empty() {
+        entries.push(DnsEntry { entry_type: "domain".to_string(), value: domain });
+    }
+    
+    entries
+}
+
+#[cfg(not(any(unix, windows)))]
+fn read_dns_config() -> Vec<DnsEntry> {
+    Vec::new()
+}
+
+struct DnsVTab;
+
+impl VTab for DnsVTab {
+    type InitData = DnsInitData;
+    type BindData = DnsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("value", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(DnsBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let entry_data = read_dns_config();
+        let entry_count = entry_data.len();
+        
+        Ok(DnsInitData {
+            current_idx: AtomicUsize::new(0),
+            entry_count,
+            entry_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.entry_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.entry_count - current);
+        
+        for i in 0..batch_size {
+            let entry = &init_data.entry_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(entry.entry_type.clone())?);
+            output.flat_vector(1).insert(i, CString::new(entry.value.clone())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Files Table Function - sazgar_files(path)
+// Returns file metadata for a directory listing, optionally glob-filtered and recursive
+// ============================================================================
+
+#[repr(C)]
+struct FilesBindData {
+    path: String,
+    pattern: Option<globset::GlobMatcher>,
+    recursive: bool,
+}
+
+struct FileEntryInfo {
+    path: String,
+    size_bytes: u64,
+    modified_at: Option<i64>,
+    created_at: Option<i64>,
+    accessed_at: Option<i64>,
+    owner: Option<String>,
+    permissions: Option<String>,
+    is_symlink: bool,
+    symlink_target: Option<String>,
+}
+
+#[repr(C)]
+struct FilesInitData {
+    current_idx: AtomicUsize,
+    file_count: usize,
+    file_data: Vec<FileEntryInfo>,
+}
+
+/// Microseconds since the Unix epoch, for TIMESTAMP columns
+fn system_time_micros(time: std::io::Result<std::time::SystemTime>) -> Option<i64> {
+    let time = time.ok()?;
+    Some(match time.duration_since(std::time::UNIX_EPOCH) {
+        Ok(after) => after.as_micros() as i64,
+        Err(before) => -(before.duration().as_micros() as i64),
+    })
+}
+
+/// Render a Unix mode like `ls -l` does, e.g. "rwxr-sr-t"
+#[cfg(unix)]
+fn format_permissions(mode: u32) -> String {
+    let bit = |mask: u32, c: char| if mode & mask != 0 { c } else { '-' };
+    // Execute slot doubles as setuid/setgid/sticky: lowercase if also executable
+    let special = |exec: u32, flag: u32, c: char| match (mode & exec != 0, mode & flag != 0) {
+        (true, true) => c,
+        (false, true) => c.to_ascii_uppercase(),
+        (true, false) => 'x',
+        (false, false) => '-',
+    };
+    [
+        bit(0o400, 'r'), bit(0o200, 'w'), special(0o100, 0o4000, 's'),
+        bit(0o040, 'r'), bit(0o020, 'w'), special(0o010, 0o2000, 's'),
+        bit(0o004, 'r'), bit(0o002, 'w'), special(0o001, 0o1000, 't'),
+    ]
+    .iter()
+    .collect()
+}
+
+struct FilesVTab;
+
+impl VTab for FilesVTab {
+    type InitData = FilesInitData;
+    type BindData = FilesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("path", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("size_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("modified_at", LogicalTypeHandle::from(LogicalTypeId::Timestamp));
+        bind.add_result_column("created_at", LogicalTypeHandle::from(LogicalTypeId::Timestamp));
+        bind.add_result_column("accessed_at", LogicalTypeHandle::from(LogicalTypeId::Timestamp));
+        bind.add_result_column("owner", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("permissions", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("is_symlink", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("symlink_target", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        let path = bind.get_parameter(0).to_string().trim_matches('"').to_string();
+        if !std::path::Path::new(&path).is_dir() {
+            return Err(format!("Not a directory: {}", path).into());
+        }
+        
+        let pattern = bind.get_named_parameter("pattern")
+            .map(|v| v.to_string().trim_matches('"').to_string())
+            .filter(|p| !p.is_empty())
+            .map(|p| compile_glob("sazgar_files", &p))
+            .transpose()?;
+        
+        let recursive = bind.get_named_parameter("recursive")
+            .map(|v| v.to_string().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        
+        Ok(FilesBindData { path, pattern, recursive })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<FilesBindData>();
+        let (root, pattern, recursive) = unsafe {
+            ((*bind_data).path.clone(), (*bind_data).pattern.clone(), (*bind_data).recursive)
+        };
+        
+        #[cfg(unix)]
+        let owners: std::collections::HashMap<String, String> = sysinfo::Users::new_with_refreshed_list()
+            .iter()
+            .map(|user| (user.id().to_string(), user.name().to_string()))
+            .collect();
+        
+        let mut file_data: Vec<FileEntryInfo> = Vec::new();
+        let mut pending = vec![std::path::PathBuf::from(root)];
+        
+        while let Some(dir) = pending.pop() {
+            // Directories we may not read are skipped rather than failing the query
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+            for entry in entries.flatten() {
+                // DirEntry::metadata does not follow symlinks
+                let meta = match entry.metadata() {
+                    Ok(meta) => meta,
+                    Err(_) => continue,
+                };
+                if meta.is_dir() {
+                    if recursive {
+                        pending.push(entry.path());
+                    }
+                    continue;
+                }
+                
+                let name = entry.file_name().to_string_lossy().to_string();
+                if let Some(ref pattern) = pattern {
+                    if !pattern.is_match(&name) {
+                        continue;
+                    }
+                }
+                
+                let is_symlink = meta.file_type().is_symlink();
+                
+                #[cfg(unix)]
+                let (owner, permissions) = {
+                    use std::os::unix::fs::MetadataExt;
+                    let uid = meta.uid().to_string();
+                    (
+                        Some(owners.get(&uid).cloned().unwrap_or(uid)),
+                        Some(format_permissions(meta.mode())),
+                    )
+                };
+                
+                #[cfg(not(unix))]
+                let (owner, permissions): (Option<String>, Option<String>) = (None, None);
+                
+                file_data.push(FileEntryInfo {
+                    path: entry.path().to_string_lossy().to_string(),
+                    size_bytes: meta.len(),
+                    modified_at: system_time_micros(meta.modified()),
+                    created_at: system_time_micros(meta.created()),
+                    accessed_at: system_time_micros(meta.accessed()),
+                    owner,
+                    permissions,
+                    is_symlink,
+                    symlink_target: if is_symlink {
+                        std::fs::read_link(entry.path()).ok().map(|t| t.to_string_lossy().to_string())
+                    } else {
+                        None
+                    },
+                });
+            }
+        }
+        
+        let file_count = file_data.len();
+        
+        Ok(FilesInitData {
+            current_idx: AtomicUsize::new(0),
+            file_count,
+            file_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.file_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.file_count - current);
+        
+        for i in 0..batch_size {
+            let file = &init_data.file_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(file.path.clone())?);
+            output.flat_vector(1).as_mut_slice::<u64>()[i] = file.size_bytes;
+            for (col, value) in [(2, file.modified_at), (3, file.created_at), (4, file.accessed_at)] {
+                match value {
+                    Some(micros) => output.flat_vector(col).as_mut_slice::<i64>()[i] = micros,
+                    None => output.flat_vector(col).set_null(i),
+                }
+            }
+            for (col, value) in [(5, &file.owner), (6, &file.permissions), (8, &file.symlink_target)] {
+                match value {
+                    Some(value) => output.flat_vector(col).insert(i, CString::new(value.clone())?),
+                    None => output.flat_vector(col).set_null(i),
+                }
+            }
+            output.flat_vector(7).as_mut_slice::<bool>()[i] = file.is_symlink;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)])
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("pattern".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+            ("recursive".to_string(), LogicalTypeHandle::from(LogicalTypeId::Boolean)),
+        ])
+    }
+}
+
+// ============================================================================
+// Hosts Table Function - sazgar_hosts()
+// Returns the static host table (/etc/hosts), one row per hostname
+// ============================================================================
+
+#[repr(C)]
+struct HostsBindData;
+
+struct HostEntry {
+    ip_address: String,
+    hostname: String,
+}
+
+#[repr(C)]
+struct HostsInitData {
+    current_idx: AtomicUsize,
+    host_count: usize,
+    host_data: Vec<HostEntry>,
+}
+
+/// Location of the hosts file for this platform
+fn hosts_file_path() -> std::path::PathBuf {
+    #[cfg(windows)]
+    {
+        let root = std::env::var("SystemRoot").unwrap_or_else(|_| "C:\\Windows".to_string());
+        std::path::Path::new(&root).join("System32").join("drivers").join("etc").join("hosts")
+    }
+    
+    #[cfg(not(windows))]
+    {
+        std::path::PathBuf::from("/etc/hosts")
+    }
+}
+
+struct HostsVTab;
+
+impl VTab for HostsVTab {
+    type InitData = HostsInitData;
+    type BindData = HostsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("ip_address", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("hostname", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(HostsBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let mut host_data: Vec<HostEntry> = Vec::new();
+        
+        if let Ok(content) = std::fs::read_to_string(hosts_file_path()) {
+            for line in content.lines() {
+                // Comments may also follow an entry on the same line
+                let line = line.split('#').next().unwrap_or("");
+                let mut fields = line.split_whitespace();
+                let ip_address = match fields.next() {
+                    Some(ip) => ip,
+                    None => continue,
+                };
+                // The canonical name and each alias become separate rows
+                for hostname in fields {
+                    host_data.push(HostEntry {
+                        ip_address: ip_address.to_string(),
+                        hostname: hostname.to_string(),
+                    });
+                }
+            }
+        }
+        
+        let host_count = host_data.len();
+        
+        Ok(HostsInitData {
+            current_idx: AtomicUsize::new(0),
+            host_count,
+            host_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.host_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.host_count - current);
+        
+        for i in 0..batch_size {
+            let host = &init_data.host_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(host.ip_address.clone())?);
+            output.flat_vector(1).insert(i, CString::new(host.hostname.clone())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// File Stat Scalar Function - sazgar_file_stat(path)
+// Returns metadata for a single path as a STRUCT; missing paths are not an error
+// ============================================================================
+
+struct FileStatScalar;
+
+impl VScalar for FileStatScalar {
+    type State = ();
+
+    unsafe fn invoke(
+        _: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let len = input.len();
+        let paths = input.flat_vector(0);
+        let path_values = paths.as_slice_with_len::<duckdb_string_t>(len);
+        
+        let mut result = output.struct_vector();
+        let mut size_bytes = result.child(0, len);
+        let mut modified_at = result.child(1, len);
+        let mut owner = result.child(2, len);
+        let mut permissions = result.child(3, len);
+        let mut exists = result.child(4, len);
+        
+        // Resolved on first use so that calls on missing paths stay cheap
+        #[cfg(unix)]
+        let mut owners: Option<std::collections::HashMap<u32, String>> = None;
+        
+        for (i, value) in path_values.iter().enumerate() {
+            if paths.row_is_null(i as u64) {
+                result.set_null(i);
+                continue;
+            }
+            let path = DuckString::new(&mut { *value }).as_str().to_string();
+            
+            let meta = match std::fs::metadata(&path) {
+                Ok(meta) => meta,
+                Err(_) => {
+                    exists.as_mut_slice::<bool>()[i] = false;
+                    size_bytes.set_null(i);
+                    modified_at.set_null(i);
+                    owner.set_null(i);
+                    permissions.set_null(i);
+                    continue;
+                }
+            };
+            
+            exists.as_mut_slice::<bool>()[i] = true;
+            size_bytes.as_mut_slice::<i64>()[i] = meta.len() as i64;
+            match system_time_micros(meta.modified()) {
+                Some(micros) => modified_at.as_mut_slice::<i64>()[i] = micros,
+                None => modified_at.set_null(i),
+            }
+            
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::MetadataExt;
+                let owners = owners.get_or_insert_with(|| {
+                    sysinfo::Users::new_with_refreshed_list()
+                        .iter()
+                        .map(|user| (**user.id(), user.name().to_string()))
+                        .collect()
+                });
+                let name = owners.get(&meta.uid()).cloned().unwrap_or_else(|| meta.uid().to_string());
+                owner.insert(i, name.as_str());
+                permissions.insert(i, format_permissions(meta.mode()).as_str());
+            }
+            
+            #[cfg(not(unix))]
+            {
+                owner.set_null(i);
+                permissions.set_null(i);
+            }
+        }
+        
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        vec![ScalarFunctionSignature::exact(
+            vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)],
+            LogicalTypeHandle::struct_type(&[
+                ("size_bytes", LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+                ("modified_at", LogicalTypeHandle::from(LogicalTypeId::Timestamp)),
+                ("owner", LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+                ("permissions", LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+                ("exists", LogicalTypeHandle::from(LogicalTypeId::Boolean)),
+            ]),
+        )]
+    }
+}
+
+// ============================================================================
+// ARP Table Function - sazgar_arp()
+// Returns the ARP/neighbor cache (IP address to MAC mappings)
+// ============================================================================
+
+#[repr(C)]
+struct ArpBindData;
+
+struct ArpEntry {
+    ip_address: String,
+    mac_address: Option<String>,
+    interface: String,
+    state: String,
+    is_permanent: bool,
+}
+
+#[repr(C)]
+struct ArpInitData {
+    current_idx: AtomicUsize,
+    entry_count: usize,
+    entry_data: Vec<ArpEntry>,
+}
+
+/// Decode one RTM_NEWNEIGH payload: an ndmsg followed by rtattrs. Returns the
+/// interface index alongside the entry, whose interface name is left empty.
+/// NOARP and NONE entries (multicast, loopback) are skipped, as `ip neigh` does.
+#[cfg(target_os = "linux")]
+fn parse_neighbor_message(payload: &[u8]) -> Option<(u32, ArpEntry)> {
+    // struct ndmsg { u8 family; u8 pad[3]; i32 ifindex; u16 state; u8 flags; u8 type; }
+    if payload.len() < 12 {
+        return None;
+    }
+    let ifindex = u32::from_ne_bytes(payload[4..8].try_into().ok()?);
+    let nud_state = u16::from_ne_bytes(payload[8..10].try_into().ok()?);
+    let state = match nud_state {
+        libc::NUD_INCOMPLETE => "incomplete",
+        libc::NUD_REACHABLE => "reachable",
+        libc::NUD_STALE => "stale",
+        libc::NUD_DELAY => "delay",
+        libc::NUD_PROBE => "probe",
+        libc::NUD_FAILED => "failed",
+        libc::NUD_PERMANENT => "permanent",
+        _ => return None,
+    };
+    
+    let (mut address, mut mac_address) = (None, None);
+    for (kind, value) in netlink_attributes(&payload[12..]) {
+        match (kind, value.len()) {
+            (libc::NDA_DST, 4) => address = Some(std::net::IpAddr::from(<[u8; 4]>::try_from(value).ok()?)),
+            (libc::NDA_DST, 16) => address = Some(std::net::IpAddr::from(<[u8; 16]>::try_from(value).ok()?)),
+            (libc::NDA_LLADDR, _) => {
+                let mac = value.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(":");
+                mac_address = normalize_mac_address(&mac);
+            }
+            _ => {}
+        }
+    }
+    
+    Some((ifindex, ArpEntry {
+        ip_address: address?.to_string(),
+        mac_address,
+        interface: String::new(),
+        state: state.to_string(),
+        is_permanent: nud_state == libc::NUD_PERMANENT,
+    }))
+}
+
+/// Send an rtnetlink dump request (an nlmsghdr followed by `header`) and pass
+/// the payload of every `reply_type` message to `handle`. Returns None unless
+/// the kernel finishes the dump.
+#[cfg(target_os = "linux")]
+fn netlink_dump(request_type: u16, header: &[u8], reply_type: u16, mut handle: impl FnMut(&[u8])) -> Option<()> {
+    let fd = unsafe { libc::socket(libc::AF_NETLINK, libc::SOCK_RAW | libc::SOCK_CLOEXEC, libc::NETLINK_ROUTE) };
+    if fd < 0 {
+        return None;
+    }
+    
+    let mut request = vec![0u8; 16];
+    request[0..4].copy_from_slice(&((16 + header.len()) as u32).to_ne_bytes());
+    request[4..6].copy_from_slice(&request_type.to_ne_bytes());
+    request[6..8].copy_from_slice(&((libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16).to_ne_bytes());
+    request[8..12].copy_from_slice(&1u32.to_ne_bytes());
+    request.extend_from_slice(header);
+    
+    let mut done = None;
+    if unsafe { libc::send(fd, request.as_ptr() as *const libc::c_void, request.len(), 0) } == request.len() as isize {
+        let mut buffer = vec![0u8; 32 * 1024];
+        'receive: loop {
+            let len = unsafe { libc::recv(fd, buffer.as_mut_ptr() as *mut libc::c_void, buffer.len(), 0) };
+            if len <= 0 {
+                break;
+            }
+            let mut messages = &buffer[..len as usize];
+            while messages.len() >= 16 {
+                let msg_len = u32::from_ne_bytes([messages[0], messages[1], messages[2], messages[3]]) as usize;
+                let msg_type = u16::from_ne_bytes([messages[4], messages[5]]);
+                if msg_len < 16 || msg_len > messages.len() {
+                    break 'receive;
+                }
+                match i32::from(msg_type) {
+                    libc::NLMSG_DONE => {
+                        done = Some(());
+                        break 'receive;
+                    }
+                    libc::NLMSG_ERROR => break 'receive,
+                    _ if msg_type == reply_type => handle(&messages[16..msg_len]),
+                    _ => {}
+                }
+                messages = &messages[((msg_len + 3) & !3).min(messages.len())..];
+            }
+        }
+    }
+    
+    unsafe { libc::close(fd) };
+    done
+}
+
+/// Split an rtnetlink attribute list (`struct rtattr { u16 len; u16 type; }`
+/// followed by the value, padded to 4 bytes) into (type, value) pairs
+#[cfg(target_os = "linux")]
+fn netlink_attributes(mut attributes: &[u8]) -> Vec<(u16, &[u8])> {
+    let mut parsed = Vec::new();
+    while attributes.len() >= 4 {
+        let len = u16::from_ne_bytes([attributes[0], attributes[1]]) as usize;
+        let kind = u16::from_ne_bytes([attributes[2], attributes[3]]);
+        if len < 4 || len > attributes.len() {
+            break;
+        }
+        parsed.push((kind, &attributes[4..len]));
+        attributes = &attributes[((len + 3) & !3).min(attributes.len())..];
+    }
+    parsed
+}
+
+/// Name of the interface with this index, as `ip` shows it
+#[cfg(target_os = "linux")]
+fn interface_index_name(ifindex: u32) -> Option<String> {
+    let mut name = [0 as libc::c_char; libc::IF_NAMESIZE];
+    if unsafe { libc::if_indextoname(ifindex, name.as_mut_ptr()) }.is_null() {
+        return None;
+    }
+    Some(unsafe { std::ffi::CStr::from_ptr(name.as_ptr()) }.to_string_lossy().to_string())
+}
+
+/// Dump the kernel neighbour table (ARP and IPv6 NDP) over rtnetlink, which
+/// unlike /proc/net/arp reports states such as stale and delay
+#[cfg(target_os = "linux")]
+fn read_netlink_neighbors() -> Option<Vec<ArpEntry>> {
+    // A zeroed ndmsg, whose AF_UNSPEC family asks for every family
+    let mut entries = Vec::new();
+    netlink_dump(libc::RTM_GETNEIGH, &[0u8; 12], libc::RTM_NEWNEIGH, |payload| {
+        if let Some((ifindex, mut entry)) = parse_neighbor_message(payload) {
+            entry.interface = interface_index_name(ifindex).unwrap_or_default();
+            entries.push(entry);
+        }
+    })?;
+    Some(entries)
+}
+
+/// Read the neighbour table over rtnetlink, falling back to /proc/net/arp
+/// (IPv4 only, flags ATF_COM 0x2 resolved and ATF_PERM 0x4 static)
+#[cfg(target_os = "linux")]
+fn read_arp_table() -> Vec<ArpEntry> {
+    if let Some(entries) = read_netlink_neighbors() {
+        return entries;
+    }
+    
+    let mut entries = Vec::new();
+    
+    if let Ok(content) = std::fs::read_to_string("/proc/net/arp") {
+        // Header: IP address, HW type, Flags, HW address, Mask, Device
+        for line in content.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 6 {
+                continue;
+            }
+            let flags = u32::from_str_radix(fields[2].trim_start_matches("0x"), 16).unwrap_or(0);
+            let state = if flags & 0x4 != 0 {
+                "permanent"
+            } else if flags & 0x2 != 0 {
+                "reachable"
+            } else {
+                "incomplete"
+            };
+            entries.push(ArpEntry {
+                ip_address: fields[0].to_string(),
+                mac_address: normalize_mac_address(fields[3]),
+                interface: fields[5].to_string(),
+                state: state.to_string(),
+                is_permanent: flags & 0x4 != 0,
+            });
+        }
+    }
+    
+    entries
+}
+
+/// Parse `arp -an`, e.g. "? (192.168.1.1) at aa:bb:cc:dd:ee:ff on en0 ifscope [ethernet]"
+#[cfg(target_os = "macos")]
+fn read_arp_table() -> Vec<ArpEntry> {
+    let mut entries = Vec::new();
+    
+    if let Ok(output) = std::process::Command::new("arp").arg("-an").output() {
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            for line in stdout.lines() {
+                let words: Vec<&str> = line.split_whitespace().collect();
+                if words.len() < 6 || words[2] != "at" || words[4] != "on" {
+                    continue;
+                }
+                let mac = words[3];
+                let is_permanent = line.contains("permanent");
+                // arp -an only distinguishes resolved from unresolved entries
+                let state = if mac == "(incomplete)" {
+                    "incomplete"
+                } else if is_permanent {
+                    "permanent"
+                } else {
+                    "reachable"
+                };
+                entries.push(ArpEntry {
+                    ip_address: words[1].trim_matches(|c| c == '(' || c == ')').to_string(),
+                    // arp prints octets unpadded, e.g. "0:1a:2b:3c:4d:5e"
+                    mac_address: normalize_mac_address(mac),
+                    interface: words[5].to_string(),
+                    state: state.to_string(),
+                    is_permanent,
+                });
+            }
+        }
+    }
+    
+    entries
+}
+
+/// Read the IPv4 and IPv6 neighbour tables from GetIpNetTable2
+#[cfg(windows)]
+fn read_arp_table() -> Vec<ArpEntry> {
+    use windows_sys::Win32::NetworkManagement::IpHelper::{FreeMibTable, GetIpNetTable2, MIB_IPNET_TABLE2};
+    use windows_sys::Win32::Networking::WinSock::AF_UNSPEC;
+    
+    let mut entries = Vec::new();
+    let mut table: *mut MIB_IPNET_TABLE2 = std::ptr::null_mut();
+    if unsafe { GetIpNetTable2(AF_UNSPEC, &mut table) } != 0 || table.is_null() {
+        return entries;
+    }
+    
+    let rows = unsafe { std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as usize) };
+    for row in rows {
+        let Some(address) = sockaddr_inet_to_ip(&row.Address) else {
+            continue;
+        };
+        // NL_NEIGHBOR_STATE values
+        let state = match row.State {
+            1 => "incomplete",
+            2 => "probe",
+            3 => "delay",
+            4 => "stale",
+            5 => "reachable",
+            6 => "permanent",
+            _ => "failed",
+        };
+        let mac_len = (row.PhysicalAddressLength as usize).min(row.PhysicalAddress.len());
+        let mac = row.PhysicalAddress[..mac_len].iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(":");
+        entries.push(ArpEntry {
+            ip_address: address.to_string(),
+            mac_address: normalize_mac_address(&mac),
+            interface: interface_alias(&row.InterfaceLuid, || row.InterfaceIndex.to_string()),
+            state: state.to_string(),
+            is_permanent: row.State == 6,
+        });
+    }
+    
+    unsafe { FreeMibTable(table as *const _) };
+    entries
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn read_arp_table() -> Vec<ArpEntry> {
+    Vec::new()
+}
+
+struct ArpVTab;
+
+impl VTab for ArpVTab {
+    type InitData = ArpInitData;
+    type BindData = ArpBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("ip_address", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("mac_address", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("interface", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("state", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("is_permanent", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        Ok(ArpBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let entry_data = read_arp_table();
+        let entry_count = entry_data.len();
+        
+        Ok(ArpInitData {
+            current_idx: AtomicUsize::new(0),
+            entry_count,
+            entry_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.entry_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.entry_count - current);
+        
+        for i in 0..batch_size {
+            let entry = &init_data.entry_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(entry.ip_address.clone())?);
+            match &entry.mac_address {
+                Some(mac) => output.flat_vector(1).insert(i, CString::new(mac.clone())?),
+                None => output.flat_vector(1).set_null(i),
+            }
+            output.flat_vector(2).insert(i, CString::new(entry.interface.clone())?);
+            output.flat_vector(3).insert(i, CString::new(entry.state.clone())?);
+            output.flat_vector(4).as_mut_slice::<bool>()[i] = entry.is_permanent;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Routes Table Function - sazgar_routes()
+// Returns the kernel IPv4 and IPv6 routing tables
+// ============================================================================
+
+#[repr(C)]
+struct RoutesBindData;
+
+struct RouteInfo {
+    destination: String,
+    prefix_len: u8,
+    gateway: String,
+    mask: String,
+    interface: String,
+    metric: u32,
+    flags: String,
+    /// Who installed the route, where the OS exposes it
+    protocol: Option<&'static str>,
+    scope: &'static str,
+    address_family: &'static str,
+}
+
+#[repr(C)]
+struct RoutesInitData {
+    current_idx: AtomicUsize,
+    route_count: usize,
+    route_data: Vec<RouteInfo>,
+}
+
+/// Netmask for a prefix length, e.g. 24 -> "255.255.255.0"
+fn prefix_mask(is_ipv6: bool, prefix_len: u8) -> String {
+    if is_ipv6 {
+        let mask = u128::MAX.checked_shl(128 - u32::from(prefix_len.min(128))).unwrap_or(0);
+        std::net::Ipv6Addr::from(mask).to_string()
+    } else {
+        let mask = u32::MAX.checked_shl(32 - u32::from(prefix_len.min(32))).unwrap_or(0);
+        std::net::Ipv4Addr::from(mask).to_string()
+    }
+}
+
+/// Scope as `ip route` reports it: routes through a gateway reach the wider
+/// network, direct routes only the attached link, local routes only this host
+fn route_scope(has_gateway: bool, is_local: bool) -> &'static str {
+    if is_local {
+        "host"
+    } else if has_gateway {
+        "global"
+    } else {
+        "link"
+    }
+}
+
+/// Render route flags the way `route -n` does, e.g. "UG"
+#[cfg(target_os = "linux")]
+fn format_route_flags(flags: u32) -> String {
+    [(0x1, 'U'), (0x2, 'G'), (0x4, 'H'), (0x8, 'R'), (0x10, 'D'), (0x20, 'M'), (0x200, '!')]
+        .iter()
+        .filter(|(bit, _)| flags & bit != 0)
+        .map(|(_, c)| *c)
+        .collect()
+}
+
+/// Parse /proc/net/route, whose addresses are little-endian hex
+#[cfg(target_os = "linux")]
+fn read_ipv4_routes() -> Vec<RouteInfo> {
+    let mut routes = Vec::new();
+    let ipv4 = |hex: &str| {
+        u32::from_str_radix(hex, 16)
+            .map(|raw| std::net::Ipv4Addr::from(u32::from_be(raw)).to_string())
+            .unwrap_or_default()
+    };
+    
+    if let Ok(content) = std::fs::read_to_string("/proc/net/route") {
+        // Iface Destination Gateway Flags RefCnt Use Metric Mask MTU Window IRTT
+        for line in content.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 8 {
+                continue;
+            }
+            let flags = u32::from_str_radix(fields[3], 16).unwrap_or(0);
+            routes.push(RouteInfo {
+                destination: ipv4(fields[1]),
+                prefix_len: u32::from_str_radix(fields[7], 16).unwrap_or(0).count_ones() as u8,
+                gateway: ipv4(fields[2]),
+                mask: ipv4(fields[7]),
+                interface: fields[0].to_string(),
+                metric: fields[6].parse().unwrap_or(0),
+                flags: format_route_flags(flags),
+                // /proc only exposes the protocol of routes learned from ICMP redirects (RTF_DYNAMIC)
+                protocol: (flags & 0x10 != 0).then_some("redirect"),
+                scope: route_scope(flags & 0x2 != 0, false),
+                address_family: "IPv4",
+            });
+        }
+    }
+    
+    routes
+}
+
+/// Parse /proc/net/ipv6_route, whose addresses are big-endian hex and prefixes are lengths
+#[cfg(target_os = "linux")]
+fn read_ipv6_routes() -> Vec<RouteInfo> {
+    let mut routes = Vec::new();
+    let ipv6 = |hex: &str| {
+        u128::from_str_radix(hex, 16)
+            .map(|raw| std::net::Ipv6Addr::from(raw).to_string())
+            .unwrap_or_default()
+    };
+    
+    if let Ok(content) = std::fs::read_to_string("/proc/net/ipv6_route") {
+        // dest dest_plen src src_plen next_hop metric refcnt use flags iface
+        for line in content.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                continue;
+            }
+            let prefix_len = u8::from_str_radix(fields[1], 16).unwrap_or(0).min(128);
+            let flags = u32::from_str_radix(fields[8], 16).unwrap_or(0);
+            // RTF_ADDRCONF marks routes from router advertisements, RTF_DYNAMIC from redirects
+            let protocol = if flags & 0x40000 != 0 {
+                Some("ra")
+            } else if flags & 0x10 != 0 {
+                Some("redirect")
+            } else {
+                None
+            };
+            routes.push(RouteInfo {
+                destination: ipv6(fields[0]),
+                prefix_len,
+                gateway: ipv6(fields[4]),
+                mask: prefix_mask(true, prefix_len),
+                interface: fields[9].to_string(),
+                metric: u32::from_str_radix(fields[5], 16).unwrap_or(0),
+                flags: format_route_flags(flags),
+                protocol,
+                // RTF_LOCAL routes come from the local table (this host's own addresses)
+                scope: route_scope(flags & 0x2 != 0, flags & 0x8000_0000 != 0),
+                address_family: "IPv6",
+            });
+        }
+    }
+    
+    routes
+}
+
+/// Name an rtnetlink route protocol (RTPROT_*) the way `ip route` does
+#[cfg(target_os = "linux")]
+fn route_protocol_name(protocol: u8) -> Option<&'static str> {
+    Some(match protocol {
+        1 => "redirect",
+        2 => "kernel",
+        3 => "boot",
+        4 => "static",
+        8 => "gated",
+        9 => "ra",
+        10 => "mrt",
+        11 => "zebra",
+        12 => "bird",
+        13 => "dnrouted",
+        14 => "xorp",
+        15 => "ntk",
+        16 => "dhcp",
+        17 => "mrouted",
+        18 => "keepalived",
+        42 => "babel",
+        99 => "openr",
+        186 => "bgp",
+        187 => "isis",
+        188 => "ospf",
+        189 => "rip",
+        192 => "eigrp",
+        _ => return None,
+    })
+}
+
+/// Route identity shared by /proc and rtnetlink: destination, prefix length,
+/// output interface (empty if none) and metric
+#[cfg(target_os = "linux")]
+type RouteKey = (String, u8, String, u32);
+
+/// The parts of an RTM_NEWROUTE message needed to match it to a /proc route
+#[cfg(target_os = "linux")]
+#[derive(Debug, PartialEq)]
+struct NetlinkRoute {
+    destination: std::net::IpAddr,
+    prefix_len: u8,
+    output_ifindex: Option<u32>,
+    metric: u32,
+    table: u32,
+    protocol: &'static str,
+}
+
+/// Decode one RTM_NEWROUTE payload: an rtmsg followed by rtattrs. Routes with
+/// an unknown protocol number are skipped.
+#[cfg(target_os = "linux")]
+fn parse_route_message(payload: &[u8]) -> Option<NetlinkRoute> {
+    // struct rtmsg { u8 family, dst_len, src_len, tos, table, protocol, scope, type; u32 flags; }
+    if payload.len() < 12 {
+        return None;
+    }
+    let (family, prefix_len, mut table, protocol) = (payload[0], payload[1], u32::from(payload[4]), payload[5]);
+    // Without RTA_DST the route is a default route
+    let mut destination = match i32::from(family) {
+        libc::AF_INET => std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED),
+        libc::AF_INET6 => std::net::IpAddr::V6(std::net::Ipv6Addr::UNSPECIFIED),
+        _ => return None,
+    };
+    let (mut output_ifindex, mut metric) = (None, 0);
+    for (kind, value) in netlink_attributes(&payload[12..]) {
+        match (kind, value.len()) {
+            (libc::RTA_DST, 4) => destination = std::net::IpAddr::from(<[u8; 4]>::try_from(value).ok()?),
+            (libc::RTA_DST, 16) => destination = std::net::IpAddr::from(<[u8; 16]>::try_from(value).ok()?),
+            (libc::RTA_OIF, 4) => output_ifindex = Some(u32::from_ne_bytes(value.try_into().ok()?)),
+            (libc::RTA_PRIORITY, 4) => metric = u32::from_ne_bytes(value.try_into().ok()?),
+            // Table ids above 255 only fit in the attribute
+            (libc::RTA_TABLE, 4) => table = u32::from_ne_bytes(value.try_into().ok()?),
+            _ => {}
+        }
+    }
+    Some(NetlinkRoute {
+        destination,
+        prefix_len,
+        output_ifindex,
+        metric,
+        table,
+        protocol: route_protocol_name(protocol)?,
+    })
+}
+
+/// Look up who installed each route over rtnetlink, which /proc does not say.
+/// Routes netlink cannot account for keep the protocol /proc implies.
+#[cfg(target_os = "linux")]
+fn fill_route_protocols(routes: &mut [RouteInfo]) {
+    let mut protocols: std::collections::HashMap<RouteKey, &'static str> = std::collections::HashMap::new();
+    // An empty rtmsg (AF_UNSPEC) dumps every table of every family
+    let dumped = netlink_dump(libc::RTM_GETROUTE, &[0u8; 12], libc::RTM_NEWROUTE, |payload| {
+        if let Some(route) = parse_route_message(payload) {
+            let interface = route.output_ifindex.and_then(interface_index_name).unwrap_or_default();
+            let key = (route.destination.to_string(), route.prefix_len, interface, route.metric);
+            // /proc/net/route only shows the main table, so it wins any clash
+            if route.table == u32::from(libc::RT_TABLE_MAIN) {
+                protocols.insert(key, route.protocol);
+            } else {
+                protocols.entry(key).or_insert(route.protocol);
+            }
+        }
+    });
+    if dumped.is_none() {
+        return;
+    }
+    
+    for route in routes {
+        let key = (route.destination.clone(), route.prefix_len, route.interface.clone(), route.metric);
+        // Multipath and unreachable routes carry no single output interface
+        let protocol = protocols.get(&key).or_else(|| protocols.get(&(key.0, key.1, String::new(), key.3)));
+        if let Some(protocol) = protocol {
+            route.protocol = Some(protocol);
+        }
+    }
+}
+
+/// Parse a `netstat -rn` destination, which abbreviates IPv4 networks to
+/// their significant octets ("10" is 10.0.0.0/8) and may carry a %zone
+#[cfg(any(target_os = "macos", test))]
+fn parse_netstat_destination(destination: &str, is_ipv6: bool) -> Option<(std::net::IpAddr, u8)> {
+    if destination == "default" {
+        let any = if is_ipv6 {
+            std::net::IpAddr::V6(std::net::Ipv6Addr::UNSPECIFIED)
+        } else {
+            std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED)
+        };
+        return Some((any, 0));
+    }
+    let (address, prefix_len) = match destination.split_once('/') {
+        Some((address, prefix_len)) => (address, Some(prefix_len.parse::<u8>().ok()?)),
+        None => (destination, None),
+    };
+    let address = address.split('%').next().unwrap_or(address);
+    
+    if is_ipv6 {
+        let address: std::net::Ipv6Addr = address.parse().ok()?;
+        return Some((std::net::IpAddr::V6(address), prefix_len.unwrap_or(128)));
+    }
+    let octets: Vec<u8> = address.split('.').map(|octet| octet.parse().ok()).collect::<Option<_>>()?;
+    if octets.is_empty() || octets.len() > 4 {
+        return None;
+    }
+    let mut padded = [0u8; 4];
+    padded[..octets.len()].copy_from_slice(&octets);
+    let prefix_len = prefix_len.unwrap_or(octets.len() as u8 * 8);
+    Some((std::net::IpAddr::V4(std::net::Ipv4Addr::from(padded)), prefix_len))
+}
+
+/// Parse the Internet and Internet6 sections of `netstat -rn`
+#[cfg(any(target_os = "macos", test))]
+fn parse_netstat_routes(output: &str) -> Vec<RouteInfo> {
+    let mut routes = Vec::new();
+    let mut is_ipv6 = None;
+    
+    // Destination Gateway Flags Netif [Expire]
+    for line in output.lines() {
+        match line.trim() {
+            "Internet:" => is_ipv6 = Some(false),
+            "Internet6:" => is_ipv6 = Some(true),
+            _ => {}
+        }
+        let Some(is_ipv6) = is_ipv6 else {
+            continue;
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.len() < 4 || words[0] == "Destination" {
+            continue;
+        }
+        let Some((destination, prefix_len)) = parse_netstat_destination(words[0], is_ipv6) else {
+            continue;
+        };
+        // Directly connected routes name a link ("link#4") or MAC address instead of a gateway
+        let gateway = words[1]
+            .split('%')
+            .next()
+            .and_then(|gateway| gateway.parse::<std::net::IpAddr>().ok())
+            .unwrap_or(if is_ipv6 {
+                std::net::IpAddr::V6(std::net::Ipv6Addr::UNSPECIFIED)
+            } else {
+                std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED)
+            });
+        let flags = words[2];
+        let protocol = if flags.contains('S') {
+            Some("static")
+        } else if flags.contains('D') {
+            Some("redirect")
+        } else {
+            None
+        };
+        
+        routes.push(RouteInfo {
+            destination: destination.to_string(),
+            prefix_len,
+            gateway: gateway.to_string(),
+            mask: prefix_mask(is_ipv6, prefix_len),
+            interface: words[3].to_string(),
+            metric: 0,
+            flags: flags.to_string(),
+            protocol,
+            scope: route_scope(flags.contains('G'), words[3].starts_with("lo")),
+            address_family: if is_ipv6 { "IPv6" } else { "IPv4" },
+        });
+    }
+    
+    routes
+}
+
+/// Read the IPv4 and IPv6 routing tables from `netstat -rn`, which needs no privileges
+#[cfg(target_os = "macos")]
+fn read_netstat_routes() -> Vec<RouteInfo> {
+    command_output_with_timeout("netstat", &["-rn"], std::time::Duration::from_secs(5))
+        .map(|output| parse_netstat_routes(&output))
+        .unwrap_or_default()
+}
+
+/// Convert a SOCKADDR_INET from the IP Helper MIB tables to an address
+#[cfg(windows)]
+fn sockaddr_inet_to_ip(address: &windows_sys::Win32::Networking::WinSock::SOCKADDR_INET) -> Option<std::net::IpAddr> {
+    use windows_sys::Win32::Networking::WinSock::{AF_INET, AF_INET6};
+    
+    unsafe {
+        match address.si_family {
+            AF_INET => Some(std::net::IpAddr::V4(std::net::Ipv4Addr::from(u32::from_be(address.Ipv4.sin_addr.S_un.S_addr)))),
+            AF_INET6 => Some(std::net::IpAddr::V6(std::net::Ipv6Addr::from(address.Ipv6.sin6_addr.u.Byte))),
+            _ => None,
+        }
+    }
+}
+
+/// Interface alias (the name sysinfo uses), or `fallback` if it can't be resolved
+#[cfg(windows)]
+fn interface_alias(luid: &windows_sys::Win32::NetworkManagement::Ndis::NET_LUID_LH, fallback: impl FnOnce() -> String) -> String {
+    use windows_sys::Win32::NetworkManagement::IpHelper::ConvertInterfaceLuidToAlias;
+    use windows_sys::Win32::NetworkManagement::Ndis::IF_MAX_STRING_SIZE;
+    
+    let mut alias = [0u16; IF_MAX_STRING_SIZE as usize + 1];
+    if unsafe { ConvertInterfaceLuidToAlias(luid, alias.as_mut_ptr(), alias.len()) } != 0 {
+        return fallback();
+    }
+    let len = alias.iter().position(|&c| c == 0).unwrap_or(alias.len());
+    String::from_utf16_lossy(&alias[..len])
+}
+
+/// Read the IPv4 and IPv6 routing tables from GetIpForwardTable2
+#[cfg(windows)]
+fn read_ip_forward_table() -> Vec<RouteInfo> {
+    use windows_sys::Win32::NetworkManagement::IpHelper::{FreeMibTable, GetIpForwardTable2, MIB_IPFORWARD_TABLE2};
+    use windows_sys::Win32::Networking::WinSock::AF_UNSPEC;
+    
+    let mut routes = Vec::new();
+    let mut table: *mut MIB_IPFORWARD_TABLE2 = std::ptr::null_mut();
+    if unsafe { GetIpForwardTable2(AF_UNSPEC, &mut table) } != 0 || table.is_null() {
+        return routes;
+    }
+    
+    let rows = unsafe { std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as usize) };
+    for row in rows {
+        let Some(destination) = sockaddr_inet_to_ip(&row.DestinationPrefix.Prefix) else {
+            continue;
+        };
+        let is_ipv6 = destination.is_ipv6();
+        let gateway = sockaddr_inet_to_ip(&row.NextHop).unwrap_or(if is_ipv6 {
+            std::net::IpAddr::V6(std::net::Ipv6Addr::UNSPECIFIED)
+        } else {
+            std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED)
+        });
+        let has_gateway = !gateway.is_unspecified();
+        let prefix_len = row.DestinationPrefix.PrefixLength;
+        let is_host = prefix_len == if is_ipv6 { 128 } else { 32 };
+        
+        // MIB_IPPROTO_* values
+        let protocol = match row.Protocol {
+            2 => "local",
+            3 | 10002 | 10006 | 10007 => "static",
+            4 => "redirect",
+            8 => "rip",
+            13 => "ospf",
+            14 => "bgp",
+            _ => "other",
+        };
+        
+        routes.push(RouteInfo {
+            destination: destination.to_string(),
+            prefix_len,
+            gateway: gateway.to_string(),
+            mask: prefix_mask(is_ipv6, prefix_len),
+            interface: interface_alias(&row.InterfaceLuid, || row.InterfaceIndex.to_string()),
+            metric: row.Metric,
+            flags: format!("U{}{}", if has_gateway { "G" } else { "" }, if is_host { "H" } else { "" }),
+            protocol: Some(protocol),
+            scope: route_scope(has_gateway, row.Loopback),
+            address_family: if is_ipv6 { "IPv6" } else { "IPv4" },
+        });
+    }
+    
+    unsafe { FreeMibTable(table as *const _) };
+    routes
+}
+
+struct RoutesVTab;
+
+impl VTab for RoutesVTab {
+    type InitData = RoutesInitData;
+    type BindData = RoutesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("destination", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("gateway", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("mask", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("interface", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("metric", LogicalTypeHandle::from(LogicalTypeId::UInteger));
+        bind.add_result_column("flags", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("address_family", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("prefix_len", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("protocol", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("scope", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(RoutesBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[allow(unused_mut)]
+        let mut route_data: Vec<RouteInfo> = Vec::new();
+        
+        #[cfg(target_os = "linux")]
+        {
+            route_data.extend(read_ipv4_routes());
+            route_data.extend(read_ipv6_routes());
+            fill_route_protocols(&mut route_data);
+        }
+        #[cfg(target_os = "macos")]
+        route_data.extend(read_netstat_routes());
+        #[cfg(windows)]
+        route_data.extend(read_ip_forward_table());
+        
+        let route_count = route_data.len();
+        
+        Ok(RoutesInitData {
+            current_idx: AtomicUsize::new(0),
+            route_count,
+            route_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.route_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.route_count - current);
+        
+        for i in 0..batch_size {
+            let route = &init_data.route_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(route.destination.clone())?);
+            output.flat_vector(1).insert(i, CString::new(route.gateway.clone())?);
+            output.flat_vector(2).insert(i, CString::new(route.mask.clone())?);
+            output.flat_vector(3).insert(i, CString::new(route.interface.clone())?);
+            output.flat_vector(4).as_mut_slice::<u32>()[i] = route.metric;
+            output.flat_vector(5).insert(i, CString::new(route.flags.clone())?);
+            output.flat_vector(6).insert(i, CString::new(route.address_family)?);
+            output.flat_vector(7).as_mut_slice::<i32>()[i] = i32::from(route.prefix_len);
+            match route.protocol {
+                Some(protocol) => output.flat_vector(8).insert(i, CString::new(protocol)?),
+                None => output.flat_vector(8).set_null(i),
+            }
+            output.flat_vector(9).insert(i, CString::new(route.scope)?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Trip Points Table Function - sazgar_trip_points()
+// Returns the thermal zone trip points (throttle/shutdown thresholds, Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct TripPointsBindData;
+
+struct TripPointInfo {
+    zone: String,
+    trip_index: u32,
+    trip_type: String,
+    temperature_celsius: Option<f64>,
+}
+
+#[repr(C)]
+struct TripPointsInitData {
+    current_idx: AtomicUsize,
+    trip_count: usize,
+    trip_data: Vec<TripPointInfo>,
+}
+
+/// The thermal_zone<n> entries of /sys/class/thermal, in numeric order
+#[cfg(target_os = "linux")]
+fn list_thermal_zones() -> Vec<String> {
+    let mut zones: Vec<String> = read_sys_dir_names("/sys/class/thermal")
+        .into_iter()
+        .filter(|name| name.starts_with("thermal_zone"))
+        .collect();
+    zones.sort_by_key(|name| name["thermal_zone".len()..].parse::<u32>().unwrap_or(u32::MAX));
+    zones
+}
+
+/// Read a thermal sysfs temperature in millidegrees Celsius as degrees
+#[cfg(target_os = "linux")]
+fn read_millidegrees(path: &str) -> Option<f64> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| s.trim().parse::<i64>().ok())
+        .map(|millis| millis as f64 / 1000.0)
+}
+
+/// Read trip_point_<n>_type / trip_point_<n>_temp for every thermal zone
+#[cfg(target_os = "linux")]
+fn read_trip_points() -> Vec<TripPointInfo> {
+    let mut trips = Vec::new();
+    
+    for zone in list_thermal_zones() {
+        let base = format!("/sys/class/thermal/{}", zone);
+        let mut indices: Vec<u32> = read_sys_dir_names(&base)
+            .iter()
+            .filter_map(|name| name.strip_prefix("trip_point_")?.strip_suffix("_type")?.parse().ok())
+            .collect();
+        indices.sort_unstable();
+        
+        for trip_index in indices {
+            let trip_type = std::fs::read_to_string(format!("{}/trip_point_{}_type", base, trip_index))
+                .map(|s| s.trim().to_string())
+                .unwrap_or_default();
+            // May be negative or unreadable for disabled trips
+            let temperature_celsius = read_millidegrees(&format!("{}/trip_point_{}_temp", base, trip_index));
+            
+            trips.push(TripPointInfo {
+                zone: zone.clone(),
+                trip_index,
+                trip_type,
+                temperature_celsius,
+            });
+        }
+    }
+    
+    trips
+}
+
+struct TripPointsVTab;
+
+impl VTab for TripPointsVTab {
+    type InitData = TripPointsInitData;
+    type BindData = TripPointsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("zone", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("trip_index", LogicalTypeHandle::from(LogicalTypeId::UInteger));
+        bind.add_result_column("type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("temperature_celsius", LogicalTypeHandle::from(LogicalTypeId::Double));
+        Ok(TripPointsBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "linux")]
+        let trip_data = read_trip_points();
+        #[cfg(not(target_os = "linux"))]
+        let trip_data: Vec<TripPointInfo> = Vec::new();
+        
+        let trip_count = trip_data.len();
+        
+        Ok(TripPointsInitData {
+            current_idx: AtomicUsize::new(0),
+            trip_count,
+            trip_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.trip_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.trip_count - current);
+        
+        for i in 0..batch_size {
+            let trip = &init_data.trip_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(trip.zone.clone())?);
+            output.flat_vector(1).as_mut_slice::<u32>()[i] = trip.trip_index;
+            output.flat_vector(2).insert(i, CString::new(trip.trip_type.clone())?);
+            match trip.temperature_celsius {
+                Some(temp) => output.flat_vector(3).as_mut_slice::<f64>()[i] = temp,
+                None => output.flat_vector(3).set_null(i),
+            }
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Thermal Zones Table Function - sazgar_thermal_zones()
+// Returns the current temperature of each thermal zone (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct ThermalZonesBindData {
+    unit: TemperatureUnit,
+}
+
+struct ThermalZoneInfo {
+    zone: String,
+    zone_type: String,
+    temperature_celsius: Option<f64>,
+}
+
+#[repr(C)]
+struct ThermalZonesInitData {
+    current_idx: AtomicUsize,
+    zone_count: usize,
+    zone_data: Vec<ThermalZoneInfo>,
+    unit: TemperatureUnit,
+}
+
+/// Read type and temp for every /sys/class/thermal/thermal_zone<n>
+#[cfg(target_os = "linux")]
+fn read_thermal_zones() -> Vec<ThermalZoneInfo> {
+    list_thermal_zones().into_iter().map(|zone| {
+        let base = format!("/sys/class/thermal/{}", zone);
+        let zone_type = std::fs::read_to_string(format!("{}/type", base))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default();
+        // Reading fails for zones whose sensor is asleep or disabled
+        let temperature_celsius = read_millidegrees(&format!("{}/temp", base));
+        
+        ThermalZoneInfo { zone, zone_type, temperature_celsius }
+    }).collect()
+}
+
+struct ThermalZonesVTab;
+
+impl VTab for ThermalZonesVTab {
+    type InitData = ThermalZonesInitData;
+    type BindData = ThermalZonesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        let unit = if bind.get_named_parameter("unit").is_some() {
+            let unit_str = bind.get_named_parameter("unit").unwrap().to_string();
+            TemperatureUnit::from_str(&unit_str).unwrap_or(TemperatureUnit::Celsius)
+        } else {
+            TemperatureUnit::Celsius
+        };
+        
+        bind.add_result_column("zone", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("temperature_celsius", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("temperature", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(ThermalZonesBindData { unit })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<ThermalZonesBindData>();
+        let unit = unsafe { (*bind_data).unit };
+        
+        #[cfg(target_os = "linux")]
+        let zone_data = read_thermal_zones();
+        #[cfg(not(target_os = "linux"))]
+        let zone_data: Vec<ThermalZoneInfo> = Vec::new();
+        
+        let zone_count = zone_data.len();
+        
+        Ok(ThermalZonesInitData {
+            current_idx: AtomicUsize::new(0),
+            zone_count,
+            zone_data,
+            unit,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.zone_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.zone_count - current);
+        let unit = init_data.unit;
+        
+        for i in 0..batch_size {
+            let zone = &init_data.zone_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(zone.zone.clone())?);
+            output.flat_vector(1).insert(i, CString::new(zone.zone_type.clone())?);
+            match zone.temperature_celsius {
+                Some(celsius) => {
+                    output.flat_vector(2).as_mut_slice::<f64>()[i] = celsius;
+                    output.flat_vector(3).as_mut_slice::<f64>()[i] = unit.convert(celsius);
+                }
+                None => {
+                    output.flat_vector(2).set_null(i);
+                    output.flat_vector(3).set_null(i);
+                }
+            }
+            output.flat_vector(4).insert(i, CString::new(unit.name())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+        ])
+    }
+}
+
+// ============================================================================
+// Listening Summary Table Function - sazgar_listening_summary()
+// Returns listening TCP and bound UDP sockets grouped by owning process
+// ============================================================================
+
+#[repr(C)]
+struct ListeningSummaryBindData;
+
+struct ListeningSummaryInfo {
+    process_name: String,
+    pid: Option<u32>,
+    tcp_listen_count: u64,
+    udp_bind_count: u64,
+    ports: Vec<i32>,
+}
+
+#[repr(C)]
+struct ListeningSummaryInitData {
+    current_idx: AtomicUsize,
+    summary_count: usize,
+    summary_data: Vec<ListeningSummaryInfo>,
+}
+
+struct ListeningSummaryVTab;
+
+impl VTab for ListeningSummaryVTab {
+    type InitData = ListeningSummaryInitData;
+    type BindData = ListeningSummaryBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("process_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("pid", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("tcp_listen_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("udp_bind_count", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("ports", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Integer)));
+        Ok(ListeningSummaryBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        // Same sockets as sazgar_ports(listening_only := true), keyed like its pid column
+        let mut groups: std::collections::BTreeMap<(String, Option<u32>), ListeningSummaryInfo> =
+            std::collections::BTreeMap::new();
+        for port in read_ports(None, true, i64::MIN..=i64::MAX, None) {
+            let entry = groups.entry((port.process_name.clone(), port.pid)).or_insert_with(|| ListeningSummaryInfo {
+                process_name: port.process_name,
+                pid: port.pid,
+                tcp_listen_count: 0,
+                udp_bind_count: 0,
+                ports: Vec::new(),
+            });
+            if port.protocol == "TCP" {
+                entry.tcp_listen_count += 1;
+            } else {
+                entry.udp_bind_count += 1;
+            }
+            entry.ports.push(port.local_port as i32);
+        }
+        
+        let summary_data: Vec<ListeningSummaryInfo> = groups
+            .into_values()
+            .map(|mut summary| {
+                summary.ports.sort_unstable();
+                summary.ports.dedup();
+                summary
+            })
+            .collect();
+        let summary_count = summary_data.len();
+        
+        Ok(ListeningSummaryInitData {
+            current_idx: AtomicUsize::new(0),
+            summary_count,
+            summary_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.summary_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.summary_count - current);
+        
+        for i in 0..batch_size {
+            let summary = &init_data.summary_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(summary.process_name.clone())?);
+            match summary.pid {
+                Some(pid) => output.flat_vector(1).as_mut_slice::<i32>()[i] = pid as i32,
+                None => output.flat_vector(1).set_null(i),
+            }
+            output.flat_vector(2).as_mut_slice::<u64>()[i] = summary.tcp_listen_count;
+            output.flat_vector(3).as_mut_slice::<u64>()[i] = summary.udp_bind_count;
+        }
+        
+        let ports: Vec<&[i32]> = init_data.summary_data[current..current + batch_size]
+            .iter()
+            .map(|summary| summary.ports.as_slice())
+            .collect();
+        insert_integer_lists(&mut output.list_vector(4), &ports);
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Network Rate Table Function - sazgar_network_rate(interval_ms)
+// Returns per-interface throughput measured over a sampling interval
+// ============================================================================
+
+#[repr(C)]
+struct NetworkRateBindData {
+    interval_ms: u64,
+}
+
+struct NetworkRateInfo {
+    interface_name: String,
+    rx_bps: f64,
+    tx_bps: f64,
+}
+
+#[repr(C)]
+struct NetworkRateInitData {
+    current_idx: AtomicUsize,
+    rate_count: usize,
+    rate_data: Vec<NetworkRateInfo>,
+}
+
+struct NetworkRateVTab;
+
+impl VTab for NetworkRateVTab {
+    type InitData = NetworkRateInitData;
+    type BindData = NetworkRateBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("interface_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("rx_bps", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("tx_bps", LogicalTypeHandle::from(LogicalTypeId::Double));
+        
+        // Sampling window, clamped to 50..10000 ms
+        let interval_ms = bind.get_parameter(0).to_int64().clamp(50, 10_000) as u64;
+        
+        Ok(NetworkRateBindData { interval_ms })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<NetworkRateBindData>();
+        let interval_ms = unsafe { (*bind_data).interval_ms };
+        
+        let mut networks = Networks::new_with_refreshed_list();
+        let mut rate_data: Vec<NetworkRateInfo> = sample_network_rates(&mut networks, interval_ms)
+            .into_iter()
+            .map(|(interface_name, rates)| NetworkRateInfo {
+                interface_name,
+                rx_bps: rates.rx_bytes_per_sec * 8.0,
+                tx_bps: rates.tx_bytes_per_sec * 8.0,
+            })
+            .collect();
+        rate_data.sort_by(|a, b| a.interface_name.cmp(&b.interface_name));
+        
+        let rate_count = rate_data.len();
+        
+        Ok(NetworkRateInitData {
+            current_idx: AtomicUsize::new(0),
+            rate_count,
+            rate_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.rate_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.rate_count - current);
+        
+        for i in 0..batch_size {
+            let rate = &init_data.rate_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(rate.interface_name.clone())?);
+            output.flat_vector(1).as_mut_slice::<f64>()[i] = rate.rx_bps;
+            output.flat_vector(2).as_mut_slice::<f64>()[i] = rate.tx_bps;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Integer)])
+    }
+}
+
+// ============================================================================
+// Battery Minutes Remaining Scalar Function - sazgar_battery_minutes_remaining()
+// Returns minutes until empty (positive) or until full (negative)
+// ============================================================================
+
+/// Estimated minutes until the system battery is empty while discharging
+/// (positive) or full while charging (negative). None when there is no
+/// battery, it is neither charging nor discharging, or no estimate exists.
+#[cfg(target_os = "linux")]
+fn battery_minutes_remaining() -> Option<f64> {
+    // (remaining, capacity, rate) summed over all system batteries, in
+    // either energy (µWh, µW) or charge (µAh, µA) units
+    let mut now = 0u64;
+    let mut full = 0u64;
+    let mut rate = 0u64;
+    let mut charging = false;
+    let mut discharging = false;
+    
+    for name in read_sys_dir_names("/sys/class/power_supply") {
+        let base = format!("/sys/class/power_supply/{}", name);
+        let read = |file: &str| std::fs::read_to_string(format!("{}/{}", base, file)).map(|s| s.trim().to_string()).unwrap_or_default();
+        // Peripheral batteries (mice, headsets) report scope "Device"
+        if read("type") != "Battery" || read("scope") == "Device" || read("present") == "0" {
+            continue;
+        }
+        
+        let (battery_now, battery_full, battery_rate) = match read_sys_u64(&format!("{}/energy_now", base)) {
+            Some(energy_now) => (
+                energy_now,
+                read_sys_u64(&format!("{}/energy_full", base))?,
+                read_sys_u64(&format!("{}/power_now", base))?,
+            ),
+            None => (
+                read_sys_u64(&format!("{}/charge_now", base))?,
+                read_sys_u64(&format!("{}/charge_full", base))?,
+                read_sys_u64(&format!("{}/current_now", base))?,
+            ),
+        };
+        now += battery_now;
+        full += battery_full;
+        rate += battery_rate;
+        
+        match read("status").as_str() {
+            "Charging" => charging = true,
+            "Discharging" => discharging = true,
+            _ => {}
+        }
+    }
+    
+    if rate == 0 {
+        return None;
+    }
+    if discharging {
+        Some(now as f64 / rate as f64 * 60.0)
+    } else if charging {
+        Some(-(full.saturating_sub(now) as f64 / rate as f64 * 60.0))
+    } else {
+        None
+    }
+}
+
+/// Parse `pmset -g batt`, e.g. "... 85%; discharging; 3:45 remaining present: true"
+#[cfg(target_os = "macos")]
+fn battery_minutes_remaining() -> Option<f64> {
+    let output = command_output_with_timeout("pmset", &["-g", "batt"], std::time::Duration::from_secs(5))?;
+    let line = output.lines().find(|line| line.contains("InternalBattery"))?;
+    let fields: Vec<&str> = line.split(';').map(|field| field.trim()).collect();
+    let state = fields.get(1)?;
+    // "(no estimate)" is reported for a while after the power source changes
+    let (hours, minutes) = fields.get(2)?.split_whitespace().next()?.split_once(':')?;
+    let minutes = hours.parse::<f64>().ok()? * 60.0 + minutes.parse::<f64>().ok()?;
+    
+    match *state {
+        "discharging" => Some(minutes),
+        "charging" => Some(-minutes),
+        _ => None,
+    }
+}
+
+/// GetSystemPowerStatus only estimates the time to empty, never the time to full
+#[cfg(windows)]
+fn battery_minutes_remaining() -> Option<f64> {
+    use windows_sys::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+    
+    let mut status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+    if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+        return None;
+    }
+    // BatteryFlag 128 = no system battery, 255 = unknown; 8 = charging
+    if status.BatteryFlag == 128 || status.BatteryFlag == 255 || status.BatteryFlag & 8 != 0 {
+        return None;
+    }
+    if status.ACLineStatus != 0 || status.BatteryLifeTime == u32::MAX {
+        return None;
+    }
+    Some(status.BatteryLifeTime as f64 / 60.0)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn battery_minutes_remaining() -> Option<f64> {
+    None
+}
+
+struct BatteryMinutesScalar;
+
+impl VScalar for BatteryMinutesScalar {
+    type State = ();
+
+    unsafe fn invoke(
+        _: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let len = input.len();
+        let minutes = battery_minutes_remaining();
+        
+        let mut result = output.flat_vector();
+        for i in 0..len {
+            match minutes {
+                Some(minutes) => result.as_mut_slice::<f64>()[i] = minutes,
+                None => result.set_null(i),
+            }
+        }
+        
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        vec![ScalarFunctionSignature::exact(
+            vec![],
+            LogicalTypeHandle::from(LogicalTypeId::Double),
+        )]
+    }
+
+    // Re-read on every call instead of folding into a constant at plan time
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// ============================================================================
+// CPU Times Table Function - sazgar_cpu_times()
+// Returns cumulative CPU time per state for each core (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct CpuTimesBindData;
+
+struct CpuTimesInfo {
+    core_id: i32,
+    // user, nice, system, idle, iowait, irq, softirq, steal
+    times: [u64; 8],
+}
+
+#[repr(C)]
+struct CpuTimesInitData {
+    current_idx: AtomicUsize,
+    cpu_count: usize,
+    cpu_data: Vec<CpuTimesInfo>,
+}
+
+/// Parse the "cpu" and "cpuN" lines of /proc/stat; the aggregate line gets core_id -1
+#[cfg(target_os = "linux")]
+fn read_cpu_times() -> Vec<CpuTimesInfo> {
+    let mut cpus = Vec::new();
+    
+    if let Ok(content) = std::fs::read_to_string("/proc/stat") {
+        for line in content.lines() {
+            let mut fields = line.split_whitespace();
+            let core_id = match fields.next().and_then(|label| label.strip_prefix("cpu")) {
+                Some("") => -1,
+                Some(n) => match n.parse::<i32>() {
+                    Ok(id) => id,
+                    Err(_) => continue,
+                },
+                None => continue,
+            };
+            
+            // Older kernels omit trailing columns such as steal
+            let mut times = [0u64; 8];
+            for (slot, value) in times.iter_mut().zip(fields) {
+                *slot = value.parse().unwrap_or(0);
+            }
+            cpus.push(CpuTimesInfo { core_id, times });
+        }
+    }
+    
+    cpus
+}
+
+struct CpuTimesVTab;
+
+impl VTab for CpuTimesVTab {
+    type InitData = CpuTimesInitData;
+    type BindData = CpuTimesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("core_id", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        for column in ["user", "nice", "system", "idle", "iowait", "irq", "softirq", "steal"] {
+            bind.add_result_column(column, LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        }
+        Ok(CpuTimesBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "linux")]
+        let cpu_data = read_cpu_times();
+        #[cfg(not(target_os = "linux"))]
+        let cpu_data: Vec<CpuTimesInfo> = Vec::new();
+        
+        let cpu_count = cpu_data.len();
+        
+        Ok(CpuTimesInitData {
+            current_idx: AtomicUsize::new(0),
+            cpu_count,
+            cpu_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.cpu_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.cpu_count - current);
+        
+        for i in 0..batch_size {
+            let cpu = &init_data.cpu_data[current + i];
+            
+            output.flat_vector(0).as_mut_slice::<i32>()[i] = cpu.core_id;
+            for (col, value) in cpu.times.iter().enumerate() {
+                output.flat_vector(col + 1).as_mut_slice::<u64>()[i] = *value;
+            }
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Interrupts Table Function - sazgar_interrupts()
+// Returns per-CPU interrupt counts from /proc/interrupts (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct InterruptsBindData;
+
+struct InterruptInfo {
+    irq: String,
+    per_cpu: Vec<u64>,
+    description: String,
+}
+
+#[repr(C)]
+struct InterruptsInitData {
+    current_idx: AtomicUsize,
+    irq_count: usize,
+    irq_data: Vec<InterruptInfo>,
+}
+
+/// Parse /proc/interrupts: a header of CPU names, then "<irq>: <count per CPU> <description>"
+#[cfg(target_os = "linux")]
+fn read_interrupts() -> Vec<InterruptInfo> {
+    let mut interrupts = Vec::new();
+    
+    if let Ok(content) = std::fs::read_to_string("/proc/interrupts") {
+        let mut lines = content.lines();
+        let cpu_count = lines.next().map(|header| header.split_whitespace().count()).unwrap_or(0);
+        
+        for line in lines {
+            let (irq, rest) = match line.split_once(':') {
+                Some(parts) => parts,
+                None => continue,
+            };
+            
+            // Summary rows such as ERR and MIS carry a single count
+            let mut fields = rest.split_whitespace().peekable();
+            let mut per_cpu = Vec::with_capacity(cpu_count);
+            while per_cpu.len() < cpu_count {
+                match fields.peek().and_then(|field| field.parse::<u64>().ok()) {
+                    Some(count) => {
+                        per_cpu.push(count);
+                        fields.next();
+                    }
+                    None => break,
+                }
+            }
+            
+            interrupts.push(InterruptInfo {
+                irq: irq.trim().to_string(),
+                per_cpu,
+                description: fields.collect::<Vec<_>>().join(" "),
+            });
+        }
+    }
+    
+    interrupts
+}
+
+struct InterruptsVTab;
+
+impl VTab for InterruptsVTab {
+    type InitData = InterruptsInitData;
+    type BindData = InterruptsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("irq", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("per_cpu_counts", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::UBigint)));
+        bind.add_result_column("description", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(InterruptsBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "linux")]
+        let irq_data = read_interrupts();
+        #[cfg(not(target_os = "linux"))]
+        let irq_data: Vec<InterruptInfo> = Vec::new();
+        
+        let irq_count = irq_data.len();
+        
+        Ok(InterruptsInitData {
+            current_idx: AtomicUsize::new(0),
+            irq_count,
+            irq_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.irq_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.irq_count - current);
+        
+        for i in 0..batch_size {
+            let interrupt = &init_data.irq_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(interrupt.irq.clone())?);
+            output.flat_vector(2).insert(i, CString::new(interrupt.description.clone())?);
+        }
+        
+        let per_cpu: Vec<&[u64]> = init_data.irq_data[current..current + batch_size]
+            .iter()
+            .map(|interrupt| interrupt.per_cpu.as_slice())
+            .collect();
+        insert_integer_lists(&mut output.list_vector(1), &per_cpu);
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Top I/O Wait Table Function - sazgar_top_io_wait(limit)
+// Returns the processes that have spent the most time waiting on block I/O
+// ============================================================================
+
+#[repr(C)]
+struct TopIoWaitBindData {
+    limit: usize,
+}
+
+struct IoWaitInfo {
+    pid: u32,
+    process_name: String,
+    blkio_wait_ms: u64,
+}
+
+#[repr(C)]
+struct TopIoWaitInitData {
+    current_idx: AtomicUsize,
+    process_count: usize,
+    process_data: Vec<IoWaitInfo>,
+}
+
+/// Read delayacct_blkio_ticks (field 42 of /proc/<pid>/stat) for every process
+/// that has waited on block I/O at all
+#[cfg(target_os = "linux")]
+fn read_blkio_waits() -> Vec<IoWaitInfo> {
+    let ticks_per_sec = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    if ticks_per_sec <= 0 {
+        return Vec::new();
+    }
+    
+    read_sys_dir_names("/proc")
+        .into_iter()
+        .filter_map(|entry| {
+            let pid = entry.parse::<u32>().ok()?;
+            let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+            // comm may contain spaces and parentheses, so split around the last ')'
+            let open = stat.find('(')?;
+            let close = stat.rfind(')')?;
+            let process_name = stat[open + 1..close].to_string();
+            // Fields after comm start at field 3 (state)
+            let ticks = stat[close + 1..].split_whitespace().nth(42 - 3)?.parse::<u64>().ok()?;
+            if ticks == 0 {
+                return None;
+            }
+            Some(IoWaitInfo {
+                pid,
+                process_name,
+                blkio_wait_ms: ticks * 1000 / ticks_per_sec as u64,
+            })
+        })
+        .collect()
+}
+
+struct TopIoWaitVTab;
+
+impl VTab for TopIoWaitVTab {
+    type InitData = TopIoWaitInitData;
+    type BindData = TopIoWaitBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("pid", LogicalTypeHandle::from(LogicalTypeId::UInteger));
+        bind.add_result_column("process_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("blkio_wait_ms", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        
+        let limit = bind.get_parameter(0).to_int64();
+        if limit < 0 {
+            return Err(format!("sazgar_top_io_wait: limit must not be negative, got {}", limit).into());
+        }
+        
+        Ok(TopIoWaitBindData { limit: limit as usize })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<TopIoWaitBindData>();
+        #[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+        let limit = unsafe { (*bind_data).limit };
+        
+        #[cfg(target_os = "linux")]
+        let process_data = {
+            let mut waits = read_blkio_waits();
+            waits.sort_by(|a, b| b.blkio_wait_ms.cmp(&a.blkio_wait_ms).then(a.pid.cmp(&b.pid)));
+            waits.truncate(limit);
+            waits
+        };
+        #[cfg(not(target_os = "linux"))]
+        let process_data: Vec<IoWaitInfo> = Vec::new();
+        
+        let process_count = process_data.len();
+        
+        Ok(TopIoWaitInitData {
+            current_idx: AtomicUsize::new(0),
+            process_count,
+            process_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.process_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.process_count - current);
+        
+        for i in 0..batch_size {
+            let proc = &init_data.process_data[current + i];
+            
+            output.flat_vector(0).as_mut_slice::<u32>()[i] = proc.pid;
+            output.flat_vector(1).insert(i, CString::new(proc.process_name.clone())?);
+            output.flat_vector(2).as_mut_slice::<u64>()[i] = proc.blkio_wait_ms;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Integer)])
+    }
+}
+
+// ============================================================================
+// Limits Table Function - sazgar_limits()
+// Returns the resource limits (rlimits) of the DuckDB process itself
+// ============================================================================
+
+#[repr(C)]
+struct LimitsBindData;
+
+struct LimitInfo {
+    resource: String,
+    soft_limit: Option<u64>,
+    hard_limit: Option<u64>,
+    unit: Option<String>,
+}
+
+#[repr(C)]
+struct LimitsInitData {
+    current_idx: AtomicUsize,
+    limit_count: usize,
+    limit_data: Vec<LimitInfo>,
+}
+
+#[cfg(target_os = "linux")]
+fn read_limits() -> Vec<LimitInfo> {
+    std::fs::read_to_string("/proc/self/limits")
+        .map(|content| parse_proc_limits(&content))
+        .unwrap_or_default()
+}
+
+/// Parse /proc/<pid>/limits, a fixed-width table whose column offsets come from its header
+#[cfg(target_os = "linux")]
+fn parse_proc_limits(content: &str) -> Vec<LimitInfo> {
+    let mut limits = Vec::new();
+    let mut lines = content.lines();
+    let header = lines.next().unwrap_or_default();
+    let (soft_at, hard_at, unit_at) = match (header.find("Soft Limit"), header.find("Hard Limit"), header.find("Units")) {
+        (Some(soft), Some(hard), Some(unit)) => (soft, hard, unit),
+        _ => return limits,
+    };
+    
+    // "unlimited" becomes NULL
+    let value = |field: &str| field.trim().parse::<u64>().ok();
+    for line in lines {
+        let column = |start: usize, end: usize| line.get(start..end.min(line.len())).unwrap_or_default();
+        let unit = column(unit_at, line.len()).trim();
+        limits.push(LimitInfo {
+            resource: column(0, soft_at).trim().to_string(),
+            soft_limit: value(column(soft_at, hard_at)),
+            hard_limit: value(column(hard_at, unit_at)),
+            unit: if unit.is_empty() { None } else { Some(unit.to_string()) },
+        });
+    }
+    
+    limits
+}
+
+/// Query getrlimit for the limits POSIX and the BSDs have in common, named as on Linux
+#[cfg(all(unix, not(target_os = "linux")))]
+fn read_limits() -> Vec<LimitInfo> {
+    let resources = [
+        ("Max cpu time", libc::RLIMIT_CPU, "seconds"),
+        ("Max file size", libc::RLIMIT_FSIZE, "bytes"),
+        ("Max data size", libc::RLIMIT_DATA, "bytes"),
+        ("Max stack size", libc::RLIMIT_STACK, "bytes"),
+        ("Max core file size", libc::RLIMIT_CORE, "bytes"),
+        ("Max resident set", libc::RLIMIT_RSS, "bytes"),
+        ("Max processes", libc::RLIMIT_NPROC, "processes"),
+        ("Max open files", libc::RLIMIT_NOFILE, "files"),
+        ("Max locked memory", libc::RLIMIT_MEMLOCK, "bytes"),
+        ("Max address space", libc::RLIMIT_AS, "bytes"),
+    ];
+    let value = |limit: libc::rlim_t| (limit != libc::RLIM_INFINITY).then_some(limit as u64);
+    
+    resources
+        .into_iter()
+        .filter_map(|(resource, id, unit)| {
+            let mut limit = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+            if unsafe { libc::getrlimit(id, &mut limit) } != 0 {
+                return None;
+            }
+            Some(LimitInfo {
+                resource: resource.to_string(),
+                soft_limit: value(limit.rlim_cur),
+                hard_limit: value(limit.rlim_max),
+                unit: Some(unit.to_string()),
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(unix))]
+fn read_limits() -> Vec<LimitInfo> {
+    Vec::new()
+}
+
+struct LimitsVTab;
+
+impl VTab for LimitsVTab {
+    type InitData = LimitsInitData;
+    type BindData = LimitsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("resource", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("soft_limit", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("hard_limit", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(LimitsBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let limit_data = read_limits();
+        let limit_count = limit_data.len();
+        
+        Ok(LimitsInitData {
+            current_idx: AtomicUsize::new(0),
+            limit_count,
+            limit_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.limit_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.limit_count - current);
+        
+        for i in 0..batch_size {
+            let limit = &init_data.limit_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(limit.resource.clone())?);
+            match limit.soft_limit {
+                Some(value) => output.flat_vector(1).as_mut_slice::<u64>()[i] = value,
+                None => output.flat_vector(1).set_null(i),
+            }
+            match limit.hard_limit {
+                Some(value) => output.flat_vector(2).as_mut_slice::<u64>()[i] = value,
+                None => output.flat_vector(2).set_null(i),
+            }
+            match &limit.unit {
+                Some(unit) => output.flat_vector(3).insert(i, CString::new(unit.clone())?),
+                None => output.flat_vector(3).set_null(i),
+            }
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Host Report Scalar Function - sazgar_host_report()
+// Returns one JSON document summarizing the host, e.g. for heartbeats
+// ============================================================================
+
+/// Collect OS, CPU, memory, load and the top 5 processes by CPU into a JSON
+/// document. Every key is always present (null when unknown) so consumers
+/// can rely on a fixed schema.
+fn host_report() -> serde_json::Value {
+    let mut sys = System::new_with_specifics(
+        RefreshKind::new()
+            .with_cpu(CpuRefreshKind::everything())
+            .with_memory(MemoryRefreshKind::everything())
+            .with_processes(ProcessRefreshKind::everything())
+    );
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    sys.refresh_all();
+    
+    let mut processes: Vec<&sysinfo::Process> = sys.processes().values().collect();
+    processes.sort_by(|a, b| {
+        b.cpu_usage().total_cmp(&a.cpu_usage()).then(b.memory().cmp(&a.memory()))
+    });
+    let top_processes: Vec<serde_json::Value> = processes.iter().take(5).map(|proc| {
+        serde_json::json!({
+            "pid": proc.pid().as_u32(),
+            "name": proc.name().to_string_lossy(),
+            "cpu_percent": proc.cpu_usage(),
+            "memory_bytes": proc.memory(),
+        })
+    }).collect();
+    
+    let load = System::load_average();
+    let collected_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or(0);
+    
+    serde_json::json!({
+        "hostname": System::host_name(),
+        "collected_at": collected_at,
+        "os": {
+            "name": System::name(),
+            "version": System::os_version(),
+            "kernel_version": System::kernel_version(),
+            "architecture": System::cpu_arch(),
+            "distribution_id": System::distribution_id(),
+            "uptime_seconds": System::uptime(),
+            "boot_time": System::boot_time(),
+        },
+        "cpu": {
+            "brand": sys.cpus().first().map(|cpu| cpu.brand().to_string()),
+            "logical_cores": sys.cpus().len(),
+            "physical_cores": sys.physical_core_count(),
+            "usage_percent": sys.global_cpu_usage(),
+        },
+        "memory": {
+            "total_bytes": sys.total_memory(),
+            "used_bytes": sys.used_memory(),
+            "available_bytes": sys.available_memory(),
+            "swap_total_bytes": sys.total_swap(),
+            "swap_used_bytes": sys.used_swap(),
+        },
+        "load": {
+            "load_1min": load.one,
+            "load_5min": load.five,
+            "load_15min": load.fifteen,
+        },
+        "process_count": sys.processes().len(),
+        "top_processes": top_processes,
+    })
+}
+
+struct HostReportScalar;
+
+impl VScalar for HostReportScalar {
+    type State = ();
+
+    unsafe fn invoke(
+        _: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let len = input.len();
+        // One snapshot per chunk; collecting takes a CPU sampling interval
+        let report = host_report().to_string();
+        
+        let result = output.flat_vector();
+        for i in 0..len {
+            result.insert(i, report.as_str());
+        }
+        
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        vec![ScalarFunctionSignature::exact(
+            vec![],
+            LogicalTypeHandle::from(LogicalTypeId::Varchar),
+        )]
+    }
+
+    // Re-collect on every call instead of folding into a constant at plan time
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// ============================================================================
+// Vmstat Table Function - sazgar_vmstat()
+// Returns the raw kernel VM counters from /proc/vmstat (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct VmstatBindData;
+
+struct VmstatInfo {
+    key: String,
+    value: u64,
+}
+
+#[repr(C)]
+struct VmstatInitData {
+    current_idx: AtomicUsize,
+    vmstat_count: usize,
+    vmstat_data: Vec<VmstatInfo>,
+}
+
+/// Parse /proc/vmstat's "<key> <value>" lines, keeping kernel order and any
+/// counters we don't know about
+#[cfg(target_os = "linux")]
+fn parse_proc_vmstat(content: &str) -> Vec<VmstatInfo> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(' ')?;
+            Some(VmstatInfo {
+                key: key.to_string(),
+                value: value.trim().parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+struct VmstatVTab;
+
+impl VTab for VmstatVTab {
+    type InitData = VmstatInitData;
+    type BindData = VmstatBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("key", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("value", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        Ok(VmstatBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "linux")]
+        let vmstat_data = std::fs::read_to_string("/proc/vmstat")
+            .map(|content| parse_proc_vmstat(&content))
+            .unwrap_or_default();
+        #[cfg(not(target_os = "linux"))]
+        let vmstat_data: Vec<VmstatInfo> = Vec::new();
+        
+        let vmstat_count = vmstat_data.len();
+        
+        Ok(VmstatInitData {
+            current_idx: AtomicUsize::new(0),
+            vmstat_count,
+            vmstat_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.vmstat_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.vmstat_count - current);
+        
+        for i in 0..batch_size {
+            let entry = &init_data.vmstat_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(entry.key.clone())?);
+            output.flat_vector(1).as_mut_slice::<u64>()[i] = entry.value;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Meminfo Table Function - sazgar_meminfo()
+// Returns every /proc/meminfo line with unit support (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct MeminfoBindData {
+    unit: SizeUnit,
+}
+
+struct MeminfoInfo {
+    key: String,
+    value: u64,
+    /// False for the plain counts such as HugePages_Total
+    is_bytes: bool,
+}
+
+#[repr(C)]
+struct MeminfoInitData {
+    current_idx: AtomicUsize,
+    meminfo_count: usize,
+    meminfo_data: Vec<MeminfoInfo>,
+    unit: SizeUnit,
+}
+
+/// Parse /proc/meminfo's "<Key>: <value> [kB]" lines. The kernel's "kB" is
+/// KiB, so those values are multiplied by 1024.
+#[cfg(target_os = "linux")]
+fn parse_proc_meminfo(content: &str) -> Vec<MeminfoInfo> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, rest) = line.split_once(':')?;
+            let mut words = rest.split_whitespace();
+            let value: u64 = words.next()?.parse().ok()?;
+            let is_bytes = words.next() == Some("kB");
+            Some(MeminfoInfo {
+                key: key.trim().to_string(),
+                value: if is_bytes { value.saturating_mul(1024) } else { value },
+                is_bytes,
+            })
+        })
+        .collect()
+}
+
+struct MeminfoVTab;
+
+impl VTab for MeminfoVTab {
+    type InitData = MeminfoInitData;
+    type BindData = MeminfoBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        // Parse unit parameter (default: MB)
+        let unit = if bind.get_named_parameter("unit").is_some() {
+            let unit_str = bind.get_named_parameter("unit").unwrap().to_string();
+            SizeUnit::from_str(&unit_str).unwrap_or(SizeUnit::MB)
+        } else {
+            SizeUnit::MB
+        };
+        
+        bind.add_result_column("key", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("value_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("value", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(MeminfoBindData { unit })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<MeminfoBindData>();
+        let unit = unsafe { (*bind_data).unit };
+        
+        #[cfg(target_os = "linux")]
+        let meminfo_data = std::fs::read_to_string("/proc/meminfo")
+            .map(|content| parse_proc_meminfo(&content))
+            .unwrap_or_default();
+        #[cfg(not(target_os = "linux"))]
+        let meminfo_data: Vec<MeminfoInfo> = Vec::new();
+        
+        let meminfo_count = meminfo_data.len();
+        
+        Ok(MeminfoInitData {
+            current_idx: AtomicUsize::new(0),
+            meminfo_count,
+            meminfo_data,
+            unit,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.meminfo_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.meminfo_count - current);
+        let unit = init_data.unit;
+        
+        for i in 0..batch_size {
+            let entry = &init_data.meminfo_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(entry.key.clone())?);
+            // Counts are passed through as-is with no unit
+            if entry.is_bytes {
+                output.flat_vector(1).as_mut_slice::<u64>()[i] = entry.value;
+                output.flat_vector(2).as_mut_slice::<f64>()[i] = unit.convert(entry.value);
+                output.flat_vector(3).insert(i, CString::new(unit.name())?);
+            } else {
+                output.flat_vector(1).set_null(i);
+                output.flat_vector(2).as_mut_slice::<f64>()[i] = entry.value as f64;
+                output.flat_vector(3).set_null(i);
+            }
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+    
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar)),
+        ])
+    }
+}
+
+// ============================================================================
+// Net Addresses Table Function - sazgar_net_addresses()
+// Returns one row per IP address assigned to a network interface
+// ============================================================================
+
+#[repr(C)]
+struct NetAddressesBindData;
+
+struct NetAddressInfo {
+    interface_name: String,
+    address: std::net::IpAddr,
+    prefix_len: Option<u8>,
+    /// IPv6 zone index (sin6_scope_id) of link-local addresses
+    zone_index: Option<u32>,
+    is_secondary: bool,
+}
+
+#[repr(C)]
+struct NetAddressesInitData {
+    current_idx: AtomicUsize,
+    address_count: usize,
+    address_data: Vec<NetAddressInfo>,
+}
+
+/// Classify an address the way `ip addr` labels its scope
+fn address_scope(address: &std::net::IpAddr) -> &'static str {
+    match address {
+        std::net::IpAddr::V4(v4) if v4.is_loopback() => "host",
+        std::net::IpAddr::V4(v4) if v4.is_link_local() => "link",
+        std::net::IpAddr::V6(v6) if v6.is_loopback() => "host",
+        std::net::IpAddr::V6(v6) if v6.segments()[0] & 0xffc0 == 0xfe80 => "link",
+        _ => "global",
+    }
+}
+
+/// Mark IPv4 addresses that fall inside the subnet of an earlier address on
+/// the same interface, which is what the kernel calls a secondary address.
+/// IPv6 has no secondaries. Expects the addresses in OS order.
+fn mark_secondary_addresses(addresses: &mut [NetAddressInfo]) {
+    for i in 0..addresses.len() {
+        let std::net::IpAddr::V4(address) = addresses[i].address else {
+            continue;
+        };
+        addresses[i].is_secondary = addresses[..i].iter().any(|primary| {
+            let (std::net::IpAddr::V4(primary_address), Some(prefix_len)) = (primary.address, primary.prefix_len) else {
+                return false;
+            };
+            let mask = u32::MAX.checked_shl(32 - u32::from(prefix_len.min(32))).unwrap_or(0);
+            primary.interface_name == addresses[i].interface_name
+                && !primary.is_secondary
+                && u32::from(primary_address) & mask == u32::from(address) & mask
+        });
+    }
+}
+
+/// Read unicast addresses from getifaddrs, with prefix lengths from the netmask
+#[cfg(unix)]
+fn read_net_addresses() -> Vec<NetAddressInfo> {
+    let mut addresses = Vec::new();
+    
+    let mut addrs: *mut libc::ifaddrs = std::ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut addrs) } != 0 {
+        return addresses;
+    }
+    
+    let mut cursor = addrs;
+    while !cursor.is_null() {
+        let ifa = unsafe { &*cursor };
+        cursor = ifa.ifa_next;
+        
+        if ifa.ifa_addr.is_null() {
+            continue;
+        }
+        let netmask = (!ifa.ifa_netmask.is_null()).then_some(ifa.ifa_netmask);
+        let (address, prefix_len, zone_index) = match unsafe { (*ifa.ifa_addr).sa_family } as i32 {
+            libc::AF_INET => {
+                let sin = unsafe { &*(ifa.ifa_addr as *const libc::sockaddr_in) };
+                let prefix_len = netmask.map(|mask| {
+                    let mask = unsafe { &*(mask as *const libc::sockaddr_in) };
+                    mask.sin_addr.s_addr.count_ones() as u8
+                });
+                (std::net::IpAddr::V4(std::net::Ipv4Addr::from(u32::from_be(sin.sin_addr.s_addr))), prefix_len, None)
+            }
+            libc::AF_INET6 => {
+                let sin6 = unsafe { &*(ifa.ifa_addr as *const libc::sockaddr_in6) };
+                #[allow(unused_mut)]
+                let mut octets = sin6.sin6_addr.s6_addr;
+                #[allow(unused_mut)]
+                let mut scope_id = sin6.sin6_scope_id;
+                // The BSD kernel embeds the zone index in bytes 2-3 of link-local addresses
+                #[cfg(target_os = "macos")]
+                if octets[0] == 0xfe && octets[1] & 0xc0 == 0x80 {
+                    let embedded = u16::from_be_bytes([octets[2], octets[3]]);
+                    if scope_id == 0 {
+                        scope_id = u32::from(embedded);
+                    }
+                    octets[2] = 0;
+                    octets[3] = 0;
+                }
+                let prefix_len = netmask.map(|mask| {
+                    let mask = unsafe { &*(mask as *const libc::sockaddr_in6) };
+                    mask.sin6_addr.s6_addr.iter().map(|b| b.count_ones()).sum::<u32>() as u8
+                });
+                (std::net::IpAddr::V6(std::net::Ipv6Addr::from(octets)), prefix_len, Some(scope_id).filter(|id| *id != 0))
+            }
+            _ => continue,
+        };
+        
+        addresses.push(NetAddressInfo {
+            interface_name: unsafe { std::ffi::CStr::from_ptr(ifa.ifa_name) }.to_string_lossy().to_string(),
+            address,
+            prefix_len,
+            zone_index,
+            is_secondary: false,
+        });
+    }
+    
+    unsafe { libc::freeifaddrs(addrs) };
+    addresses
+}
+
+/// Read unicast addresses from GetAdaptersAddresses, keyed by friendly name
+/// as sysinfo names interfaces
+#[cfg(windows)]
+fn read_net_addresses() -> Vec<NetAddressInfo> {
+    use windows_sys::Win32::NetworkManagement::IpHelper::{
+        GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST, IP_ADAPTER_UNICAST_ADDRESS_LH,
+    };
+    use windows_sys::Win32::Networking::WinSock::{AF_INET, AF_INET6, SOCKADDR_IN, SOCKADDR_IN6};
+    
+    let mut addresses = Vec::new();
+    let adapters = AdapterList::read(GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_DNS_SERVER);
+    
+    for info in adapters.iter() {
+        if info.FriendlyName.is_null() {
+            continue;
+        }
+        let name = wide_ptr_to_string(info.FriendlyName);
+        
+        let mut unicast: *const IP_ADAPTER_UNICAST_ADDRESS_LH = info.FirstUnicastAddress;
+        while !unicast.is_null() {
+            let entry = unsafe { &*unicast };
+            unicast = entry.Next;
+            let sockaddr = entry.Address.lpSockaddr;
+            if sockaddr.is_null() {
+                continue;
+            }
+            let (address, zone_index) = match unsafe { (*sockaddr).sa_family } {
+                AF_INET => {
+                    let sin = unsafe { &*(sockaddr as *const SOCKADDR_IN) };
+                    let s_addr = unsafe { sin.sin_addr.S_un.S_addr };
+                    (std::net::IpAddr::V4(std::net::Ipv4Addr::from(u32::from_be(s_addr))), None)
+                }
+                AF_INET6 => {
+                    let sin6 = unsafe { &*(sockaddr as *const SOCKADDR_IN6) };
+                    let octets = unsafe { sin6.sin6_addr.u.Byte };
+                    let scope_id = unsafe { sin6.Anonymous.sin6_scope_id };
+                    (std::net::IpAddr::V6(std::net::Ipv6Addr::from(octets)), Some(scope_id).filter(|id| *id != 0))
+                }
+                _ => continue,
+            };
+            addresses.push(NetAddressInfo {
+                interface_name: name.clone(),
+                address,
+                prefix_len: Some(entry.OnLinkPrefixLength),
+                zone_index,
+                is_secondary: false,
+            });
+        }
+    }
+    
+    addresses
+}
+
+#[cfg(not(any(unix, windows)))]
+fn read_net_addresses() -> Vec<NetAddressInfo> {
+    Vec::new()
+}
+
+struct NetAddressesVTab;
+
+impl VTab for NetAddressesVTab {
+    type InitData = NetAddressesInitData;
+    type BindData = NetAddressesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("interface_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("family", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("address", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("prefix_len", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("zone_index", LogicalTypeHandle::from(LogicalTypeId::UInteger));
+        bind.add_result_column("scope", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("is_secondary", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        Ok(NetAddressesBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let mut address_data = read_net_addresses();
+        mark_secondary_addresses(&mut address_data);
+        // Stable, so each interface's addresses keep their OS (primary-first) order
+        address_data.sort_by(|a, b| {
+            a.interface_name.cmp(&b.interface_name).then(a.address.is_ipv6().cmp(&b.address.is_ipv6()))
+        });
+        
+        let address_count = address_data.len();
+        
+        Ok(NetAddressesInitData {
+            current_idx: AtomicUsize::new(0),
+            address_count,
+            address_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.address_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.address_count - current);
+        
+        for i in 0..batch_size {
+            let entry = &init_data.address_data[current + i];
+            let family = if entry.address.is_ipv6() { "ipv6" } else { "ipv4" };
+            
+            output.flat_vector(0).insert(i, CString::new(entry.interface_name.clone())?);
+            output.flat_vector(1).insert(i, CString::new(family)?);
+            output.flat_vector(2).insert(i, CString::new(entry.address.to_string())?);
+            match entry.prefix_len {
+                Some(prefix_len) => output.flat_vector(3).as_mut_slice::<i32>()[i] = i32::from(prefix_len),
+                None => output.flat_vector(3).set_null(i),
+            }
+            match entry.zone_index {
+                Some(zone_index) => output.flat_vector(4).as_mut_slice::<u32>()[i] = zone_index,
+                None => output.flat_vector(4).set_null(i),
+            }
+            output.flat_vector(5).insert(i, CString::new(address_scope(&entry.address))?);
+            output.flat_vector(6).as_mut_slice::<bool>()[i] = entry.is_secondary;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// Swap Devices Table Function - sazgar_swap_devices()
+// Returns each active swap partition or file from /proc/swaps (Linux only)
+// ============================================================================
+
+#[repr(C)]
+struct SwapDevicesBindData {
+    unit: SizeUnit,
+}
+
+struct SwapDeviceInfo {
+    filename: String,
+    swap_type: String,
+    size_bytes: u64,
+    used_bytes: u64,
+    priority: i32,
+}
+
+#[repr(C)]
+struct SwapDevicesInitData {
+    current_idx: AtomicUsize,
+    device_count: usize,
+    device_data: Vec<SwapDeviceInfo>,
+    unit: SizeUnit,
+}
+
+/// Parse /proc/swaps. Sizes are in KiB and the filename uses the same octal
+/// escapes as the mount tables.
+#[cfg(target_os = "linux")]
+fn parse_proc_swaps(content: &str) -> Vec<SwapDeviceInfo> {
+    // Format: Filename Type Size Used Priority
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 5 {
+                return None;
+            }
+            Some(SwapDeviceInfo {
+                filename: unescape_mount_field(fields[0]),
+                swap_type: fields[1].to_string(),
+                size_bytes: fields[2].parse::<u64>().ok()?.saturating_mul(1024),
+                used_bytes: fields[3].parse::<u64>().ok()?.saturating_mul(1024),
+                priority: fields[4].parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+struct SwapDevicesVTab;
+
+impl VTab for SwapDevicesVTab {
+    type InitData = SwapDevicesInitData;
+    type BindData = SwapDevicesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        // Parse unit parameter (default: GB, as in sazgar_swap)
+        let unit = if bind.get_named_parameter("unit").is_some() {
+            let unit_str = bind.get_named_parameter("unit").unwrap().to_string();
+            SizeUnit::from_str(&unit_str).unwrap_or(SizeUnit::GB)
+        } else {
+            SizeUnit::GB
+        };
+        
+        bind.add_result_column("filename", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("size_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("used_bytes", LogicalTypeHandle::from(LogicalTypeId::UBigint));
+        bind.add_result_column("size", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("used", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("priority", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("unit", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        Ok(SwapDevicesBindData { unit })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<SwapDevicesBindData>();
+        let unit = unsafe { (*bind_data).unit };
+        
+        #[cfg(target_os = "linux")]
+        let device_data = std::fs::read_to_string("/proc/swaps")
+            .map(|content| parse_proc_swaps(&content))
+            .unwrap_or_default();
+        #[cfg(not(target_os = "linux"))]
+        let device_data: Vec<SwapDeviceInfo> = Vec::new();
+        
+        let device_count = device_data.len();
+        
+        Ok(SwapDevicesInitData {
+            current_idx: AtomicUsize::new(0),
+            device_count,
+            device_data,
+            unit,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.device_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.device_count - current);
+        let unit = init_data.unit;
+        
+        for i in 0..batch_size {
+            let device = &init_data.device_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(device.filename.clone())?);
+            output.flat_vector(1).insert(i, CString::new(device.swap_type.clone())?);
+            output.flat_vector(2).as_mut_slice::<u64>()[i] = device.size_bytes;
+            output.flat_vector(3).as_mut_slice::<u64>()[i] = device.used_bytes;
+            output.flat_vector(4).as_mut_slice::<f64>()[i] = unit.convert(device.size_bytes);
+            output.flat_vector(5).as_mut_slice::<f64>()[i] = unit.convert(device.used_bytes);
+            output.flat_vector(6).as_mut_slice::<i32>()[i] = device.priority;
+            output.flat_vector(7).insert(i, CString::new(unit.name())?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+    
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![("unit".to_string(), LogicalTypeHandle::from(LogicalTypeId::Varchar))])
+    }
+}
+
+// ============================================================================
+// DNS Config Table Function - sazgar_dns_config()
+// Returns the resolver configuration in use, one row per nameserver
+// ============================================================================
+
+#[repr(C)]
+struct DnsConfigBindData;
+
+struct DnsServerInfo {
+    nameserver: String,
+    port: u16,
+    source: &'static str,
+    search_domains: Vec<String>,
+    ndots: Option<i32>,
+    /// Link the server is configured on, None for global servers
+    interface: Option<String>,
+}
+
+#[repr(C)]
+struct DnsConfigInitData {
+    current_idx: AtomicUsize,
+    server_count: usize,
+    server_data: Vec<DnsServerInfo>,
+}
+
+/// Resolver settings from resolv.conf, shared by sazgar_dns() and sazgar_dns_config()
+#[cfg(unix)]
+#[derive(Default)]
+struct ResolvConf {
+    nameservers: Vec<String>,
+    /// `search` or `domain`, whichever line supplied `search`
+    search_keyword: &'static str,
+    search: Vec<String>,
+    options: Vec<String>,
+}
+
+#[cfg(unix)]
+impl ResolvConf {
+    /// The `ndots:N` option; like any option, the last one wins
+    fn ndots(&self) -> Option<i32> {
+        self.options.iter().rev().find_map(|option| option.strip_prefix("ndots:")).and_then(|value| value.parse().ok())
+    }
+}
+
+/// Parse resolv.conf. As in glibc, the last search or domain line wins.
+#[cfg(unix)]
+fn parse_resolv_conf(content: &str) -> ResolvConf {
+    let mut resolv = ResolvConf::default();
+    
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        let mut words = line.split_whitespace().map(|s| s.to_string());
+        match words.next().as_deref() {
+            Some("nameserver") => resolv.nameservers.extend(words.next()),
+            Some("search") => (resolv.search_keyword, resolv.search) = ("search", words.collect()),
+            Some("domain") => (resolv.search_keyword, resolv.search) = ("domain", words.collect()),
+            Some("options") => resolv.options.extend(words),
+            _ => {}
+        }
+    }
+    
+    resolv
+}
+
+/// Split a resolved server token such as "1.1.1.1", "1.1.1.1:853#one.one.one.one"
+/// or "[2606:4700::1111]:853" into address and port
+#[cfg(target_os = "linux")]
+fn parse_resolved_server(token: &str) -> Option<(String, u16)> {
+    // Anything after '#' is the DNS-over-TLS server name
+    let token = token.split('#').next()?;
+    if let Some(rest) = token.strip_prefix('[') {
+        let (address, port) = rest.split_once(']')?;
+        let port = port.strip_prefix(':').and_then(|port| port.parse().ok()).unwrap_or(53);
+        return Some((address.to_string(), port));
+    }
+    match token.split_once(':') {
+        Some((address, port)) if !port.contains(':') => Some((address.to_string(), port.parse().ok()?)),
+        _ => Some((token.to_string(), 53)),
+    }
+}
+
+/// Parse `resolvectl dns` and `resolvectl domain`, whose lines look like
+/// "Global: 1.1.1.1" and "Link 2 (eth0): 192.168.1.1 fe80::1%eth0"
+#[cfg(target_os = "linux")]
+fn parse_resolvectl(dns_output: &str, domain_output: &str, ndots: Option<i32>) -> Vec<DnsServerInfo> {
+    // "Global" -> None, "Link 2 (eth0)" -> Some("eth0")
+    let split_line = |line: &str| -> Option<(Option<String>, Vec<String>)> {
+        let (scope, values) = line.split_once(':')?;
+        let interface = match scope.trim() {
+            "Global" => None,
+            link => Some(link.split_once('(')?.1.trim_end_matches(')').to_string()),
+        };
+        Some((interface, values.split_whitespace().map(|s| s.to_string()).collect()))
+    };
+    
+    // Routing-only domains ("~example.com", "~.") are not used for search
+    let domains: Vec<(Option<String>, Vec<String>)> = domain_output
+        .lines()
+        .filter_map(split_line)
+        .map(|(interface, domains)| (interface, domains.into_iter().filter(|d| !d.starts_with('~')).collect()))
+        .collect();
+    
+    let mut servers = Vec::new();
+    for (interface, tokens) in dns_output.lines().filter_map(split_line) {
+        let search_domains = domains.iter()
+            .find(|(domain_interface, _)| *domain_interface == interface)
+            .map(|(_, domains)| domains.clone())
+            .unwrap_or_default();
+        for (nameserver, port) in tokens.iter().filter_map(|token| parse_resolved_server(token)) {
+            servers.push(DnsServerInfo {
+                nameserver,
+                port,
+                source: "systemd-resolved",
+                search_domains: search_domains.clone(),
+                ndots,
+                interface: interface.clone(),
+            });
+        }
+    }
+    
+    servers
+}
+
+/// Read /etc/resolv.conf. When it only points at the systemd-resolved stub,
+/// report resolved's real upstream servers instead, per link if possible.
+#[cfg(target_os = "linux")]
+fn read_dns_servers() -> Vec<DnsServerInfo> {
+    let resolv = std::fs::read_to_string("/etc/resolv.conf")
+        .map(|content| parse_resolv_conf(&content))
+        .unwrap_or_default();
+    // resolv.conf's default is 1
+    let ndots = Some(resolv.ndots().unwrap_or(1));
+    let (nameservers, search) = (resolv.nameservers, resolv.search);
+    
+    let is_stub = !nameservers.is_empty() && nameservers.iter().all(|ns| ns == "127.0.0.53" || ns == "127.0.0.54");
+    if is_stub {
+        let timeout = std::time::Duration::from_secs(5);
+        if let (Some(dns), Some(domain)) = (
+            command_output_with_timeout("resolvectl", &["dns"], timeout),
+            command_output_with_timeout("resolvectl", &["domain"], timeout),
+        ) {
+            let servers = parse_resolvectl(&dns, &domain, ndots);
+            if !servers.is_empty() {
+                return servers;
+            }
+        }
+        
+        // resolved also writes its upstream servers, without link detail, here
+        if let Ok(content) = std::fs::read_to_string("/run/systemd/resolve/resolv.conf") {
+            let upstream = parse_resolv_conf(&content);
+            if !upstream.nameservers.is_empty() {
+                return upstream.nameservers.into_iter().map(|nameserver| DnsServerInfo {
+                    nameserver,
+                    port: 53,
+                    source: "systemd-resolved",
+                    search_domains: upstream.search.clone(),
+                    ndots,
+                    interface: None,
+                }).collect();
+            }
+        }
+    }
+    
+    nameservers.into_iter().map(|nameserver| DnsServerInfo {
+        nameserver,
+        port: 53,
+        source: "resolv.conf",
+        search_domains: search.clone(),
+        ndots,
+        interface: None,
+    }).collect()
+}
+
+/// Parse the main (unscoped) section of `scutil --dns`, one resolver block per
+/// "resolver #N" header with "nameserver[0] : 1.1.1.1" style entries
+#[cfg(any(target_os = "macos", test))]
+fn parse_scutil_dns(output: &str) -> Vec<DnsServerInfo> {
+    struct Resolver {
+        nameservers: Vec<String>,
+        search_domains: Vec<String>,
+        port: u16,
+        ndots: Option<i32>,
+        interface: Option<String>,
+    }
+    
+    let mut resolvers: Vec<Resolver> = Vec::new();
+    for line in output.lines() {
+        // The scoped section repeats the same servers per interface
+        if line.starts_with("DNS configuration (") {
+            break;
+        }
+        if line.starts_with("resolver #") {
+            resolvers.push(Resolver { nameservers: Vec::new(), search_domains: Vec::new(), port: 53, ndots: None, interface: None });
+            continue;
+        }
+        let (Some(resolver), Some((key, value))) = (resolvers.last_mut(), line.split_once(" : ")) else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if key.starts_with("nameserver[") {
+            resolver.nameservers.push(value.to_string());
+        } else if key.starts_with("search domain[") {
+            resolver.search_domains.push(value.to_string());
+        } else if key == "port" {
+            resolver.port = value.parse().unwrap_or(53);
+        } else if key == "if_index" {
+            // "6 (en0)"
+            resolver.interface = value.split_once('(').map(|(_, name)| name.trim_end_matches(')').to_string());
+        } else if key == "options" {
+            resolver.ndots = value.split_whitespace().find_map(|option| option.strip_prefix("ndots:")?.parse().ok());
+        }
+    }
+    
+    resolvers.into_iter().flat_map(|resolver| {
+        resolver.nameservers.into_iter().map(move |nameserver| DnsServerInfo {
+            nameserver,
+            port: resolver.port,
+            source: "scutil",
+            search_domains: resolver.search_domains.clone(),
+            ndots: resolver.ndots,
+            interface: resolver.interface.clone(),
+        })
+    }).collect()
+}
+
+#[cfg(target_os = "macos")]
+fn read_dns_servers() -> Vec<DnsServerInfo> {
+    command_output_with_timeout("scutil", &["--dns"], std::time::Duration::from_secs(5))
+        .map(|output| parse_scutil_dns(&output))
+        .unwrap_or_default()
+}
+
+/// Read each connected adapter's DNS servers and connection-specific
+/// suffixes from GetAdaptersAddresses
+#[cfg(windows)]
+fn read_dns_servers() -> Vec<DnsServerInfo> {
+    use windows_sys::Win32::NetworkManagement::IpHelper::{
+        IP_ADAPTER_DNS_SERVER_ADDRESS_XP, IP_ADAPTER_DNS_SUFFIX, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_MULTICAST,
+        GAA_FLAG_SKIP_UNICAST,
+    };
+    use windows_sys::Win32::Networking::WinSock::{AF_INET, AF_INET6, SOCKADDR_IN, SOCKADDR_IN6};
+    
+    let mut servers = Vec::new();
+    let adapters = AdapterList::read(GAA_FLAG_SKIP_UNICAST | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST);
+    
+    for info in adapters.iter() {
+        // Only connected adapters are consulted for name resolution
+        if info.OperStatus != 1 {
+            continue;
+        }
+        
+        let mut search_domains: Vec<String> = Some(wide_ptr_to_string(info.DnsSuffix)).filter(|s| !s.is_empty()).into_iter().collect();
+        let mut suffix: *const IP_ADAPTER_DNS_SUFFIX = info.FirstDnsSuffix;
+        while !suffix.is_null() {
+            let entry = unsafe { &*suffix };
+            suffix = entry.Next;
+            let domain = wide_ptr_to_string(entry.String.as_ptr());
+            if !domain.is_empty() && !search_domains.contains(&domain) {
+                search_domains.push(domain);
+            }
+        }
+        
+        let interface = wide_ptr_to_string(info.FriendlyName);
+        let mut server: *const IP_ADAPTER_DNS_SERVER_ADDRESS_XP = info.FirstDnsServerAddress;
+        while !server.is_null() {
+            let entry = unsafe { &*server };
+            server = entry.Next;
+            let sockaddr = entry.Address.lpSockaddr;
+            if sockaddr.is_null() {
+                continue;
+            }
+            let address = match unsafe { (*sockaddr).sa_family } {
+                AF_INET => {
+                    let sin = unsafe { &*(sockaddr as *const SOCKADDR_IN) };
+                    std::net::IpAddr::V4(std::net::Ipv4Addr::from(u32::from_be(unsafe { sin.sin_addr.S_un.S_addr })))
+                }
+                AF_INET6 => {
+                    let sin6 = unsafe { &*(sockaddr as *const SOCKADDR_IN6) };
+                    std::net::IpAddr::V6(std::net::Ipv6Addr::from(unsafe { sin6.sin6_addr.u.Byte }))
+                }
+                _ => continue,
+            };
+            // fec0:0:0:ffff::1-3 are placeholders Windows lists when no IPv6 DNS is configured
+            if let std::net::IpAddr::V6(v6) = address {
+                if v6.segments()[..4] == [0xfec0, 0, 0, 0xffff] {
+                    continue;
+                }
+            }
+            servers.push(DnsServerInfo {
+                nameserver: address.to_string(),
+                port: 53,
+                source: "registry",
+                search_domains: search_domains.clone(),
+                ndots: None,
+                interface: Some(interface.clone()),
+            });
+        }
+    }
+    
+    servers
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn read_dns_servers() -> Vec<DnsServerInfo> {
+    Vec::new()
+}
+
+struct DnsConfigVTab;
+
+impl VTab for DnsConfigVTab {
+    type InitData = DnsConfigInitData;
+    type BindData = DnsConfigBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("nameserver", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("port", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("source", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("search_domains", LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar)));
+        bind.add_result_column("ndots", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("interface", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(DnsConfigBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let server_data = read_dns_servers();
+        let server_count = server_data.len();
+        
+        Ok(DnsConfigInitData {
+            current_idx: AtomicUsize::new(0),
+            server_count,
+            server_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.server_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.server_count - current);
+        
+        for i in 0..batch_size {
+            let server = &init_data.server_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(server.nameserver.clone())?);
+            output.flat_vector(1).as_mut_slice::<i32>()[i] = i32::from(server.port);
+            output.flat_vector(2).insert(i, CString::new(server.source)?);
+            match server.ndots {
+                Some(ndots) => output.flat_vector(4).as_mut_slice::<i32>()[i] = ndots,
+                None => output.flat_vector(4).set_null(i),
+            }
+            match &server.interface {
+                Some(interface) => output.flat_vector(5).insert(i, CString::new(interface.clone())?),
+                None => output.flat_vector(5).set_null(i),
+            }
+        }
+        
+        let search_domains: Vec<&[String]> = init_data.server_data[current..current + batch_size]
+            .iter()
+            .map(|server| server.search_domains.as_slice())
+            .collect();
+        insert_varchar_lists(&mut output.list_vector(3), &search_domains)?;
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        None
+    }
+}
+
+// ============================================================================
+// DNS Lookup Table Function - sazgar_dns_lookup(hostname)
+// Resolves a hostname through the system resolver, one row per address
+// ============================================================================
+
+#[repr(C)]
+struct DnsLookupBindData {
+    hostname: String,
+    timeout_ms: u64,
+}
+
+struct DnsLookupResult {
+    addresses: Vec<std::net::IpAddr>,
+    canonical_name: Option<String>,
+}
+
+#[repr(C)]
+struct DnsLookupInitData {
+    current_idx: AtomicUsize,
+    hostname: String,
+    result: DnsLookupResult,
+    resolve_ms: f64,
+}
+
+/// Resolve with getaddrinfo, asking for the canonical name (the end of any CNAME chain)
+#[cfg(unix)]
+fn resolve_hostname(hostname: &str) -> Option<DnsLookupResult> {
+    let host = CString::new(hostname).ok()?;
+    let mut hints: libc::addrinfo = unsafe { std::mem::zeroed() };
+    hints.ai_family = libc::AF_UNSPEC;
+    // One socket type, otherwise every address comes back once per protocol
+    hints.ai_socktype = libc::SOCK_STREAM;
+    hints.ai_flags = libc::AI_CANONNAME;
+    
+    let mut list: *mut libc::addrinfo = std::ptr::null_mut();
+    if unsafe { libc::getaddrinfo(host.as_ptr(), std::ptr::null(), &hints, &mut list) } != 0 {
+        return None;
+    }
+    
+    let mut result = DnsLookupResult { addresses: Vec::new(), canonical_name: None };
+    let mut entry = list;
+    while !entry.is_null() {
+        let info = unsafe { &*entry };
+        entry = info.ai_next;
+        if result.canonical_name.is_none() && !info.ai_canonname.is_null() {
+            result.canonical_name = Some(unsafe { std::ffi::CStr::from_ptr(info.ai_canonname) }.to_string_lossy().to_string());
+        }
+        let address = match info.ai_family {
+            libc::AF_INET => {
+                let sin = unsafe { &*(info.ai_addr as *const libc::sockaddr_in) };
+                std::net::IpAddr::V4(std::net::Ipv4Addr::from(u32::from_be(sin.sin_addr.s_addr)))
+            }
+            libc::AF_INET6 => {
+                let sin6 = unsafe { &*(info.ai_addr as *const libc::sockaddr_in6) };
+                std::net::IpAddr::V6(std::net::Ipv6Addr::from(sin6.sin6_addr.s6_addr))
+            }
+            _ => continue,
+        };
+        if !result.addresses.contains(&address) {
+            result.addresses.push(address);
+        }
+    }
+    unsafe { libc::freeaddrinfo(list) };
+    
+    Some(result)
+}
+
+/// std's resolver does not expose the canonical name, so it stays NULL
+#[cfg(not(unix))]
+fn resolve_hostname(hostname: &str) -> Option<DnsLookupResult> {
+    use std::net::ToSocketAddrs;
+    
+    let mut addresses = Vec::new();
+    for address in (hostname, 0).to_socket_addrs().ok()? {
+        if !addresses.contains(&address.ip()) {
+            addresses.push(address.ip());
+        }
+    }
+    Some(DnsLookupResult { addresses, canonical_name: None })
+}
+
+/// Resolve on a helper thread so a hung resolver cannot outlast `timeout`.
+/// Failures and timeouts both give no result.
+fn resolve_hostname_with_timeout(hostname: &str, timeout: std::time::Duration) -> Option<DnsLookupResult> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let hostname = hostname.to_string();
+    std::thread::spawn(move || {
+        let _ = sender.send(resolve_hostname(&hostname));
+    });
+    receiver.recv_timeout(timeout).ok().flatten()
+}
+
+struct DnsLookupVTab;
+
+impl VTab for DnsLookupVTab {
+    type InitData = DnsLookupInitData;
+    type BindData = DnsLookupBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("hostname", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("address", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("family", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("canonical_name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("resolve_ms", LogicalTypeHandle::from(LogicalTypeId::Double));
+        
+        let hostname = bind.get_parameter(0).to_string();
+        
+        let timeout_ms = bind.get_named_parameter("timeout_ms")
+            .map(|v| v.to_int64())
+            .unwrap_or(2000);
+        if timeout_ms <= 0 {
+            return Err(format!("sazgar_dns_lookup: timeout_ms must be positive, got {}", timeout_ms).into());
+        }
+        
+        Ok(DnsLookupBindData { hostname, timeout_ms: timeout_ms as u64 })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<DnsLookupBindData>();
+        let hostname = unsafe { (*bind_data).hostname.clone() };
+        let timeout_ms = unsafe { (*bind_data).timeout_ms };
+        
+        // NXDOMAIN, resolver errors and timeouts all yield zero rows
+        let start = std::time::Instant::now();
+        let result = resolve_hostname_with_timeout(&hostname, std::time::Duration::from_millis(timeout_ms))
+            .unwrap_or(DnsLookupResult { addresses: Vec::new(), canonical_name: None });
+        let resolve_ms = start.elapsed().as_secs_f64() * 1000.0;
+        
+        Ok(DnsLookupInitData {
+            current_idx: AtomicUsize::new(0),
+            hostname,
+            result,
+            resolve_ms,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        let address_count = init_data.result.addresses.len();
+        
+        if current >= address_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, address_count - current);
+        
+        for i in 0..batch_size {
+            let address = &init_data.result.addresses[current + i];
+            let family = if address.is_ipv6() { "ipv6" } else { "ipv4" };
+            
+            output.flat_vector(0).insert(i, CString::new(init_data.hostname.clone())?);
+            output.flat_vector(1).insert(i, CString::new(address.to_string())?);
+            output.flat_vector(2).insert(i, CString::new(family)?);
+            match &init_data.result.canonical_name {
+                Some(name) => output.flat_vector(3).insert(i, CString::new(name.clone())?),
+                None => output.flat_vector(3).set_null(i),
+            }
+            output.flat_vector(4).as_mut_slice::<f64>()[i] = init_data.resolve_ms;
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)])
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("timeout_ms".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+        ])
+    }
+}
+
+// ============================================================================
+// Ping Table Function - sazgar_ping(host)
+// Sends echo requests to a host, one row per probe
+// ============================================================================
+
+#[repr(C)]
+struct PingBindData {
+    host: String,
+    count: u16,
+    timeout_ms: u64,
+    port: u16,
+}
+
+struct PingResult {
+    seq: u16,
+    rtt_ms: Option<f64>,
+    ttl: Option<i32>,
+    method: &'static str,
+}
+
+#[repr(C)]
+struct PingInitData {
+    current_idx: AtomicUsize,
+    host: String,
+    result_count: usize,
+    result_data: Vec<PingResult>,
+}
+
+/// Minimum gap between probes, the same floor unprivileged `ping -i` enforces
+const PING_MIN_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// RFC 1071 ones' complement checksum
+#[cfg(any(target_os = "linux", target_os = "macos", test))]
+fn icmp_checksum(data: &[u8]) -> u16 {
+    let mut sum: u32 = data.chunks(2)
+        .map(|pair| u32::from(u16::from_be_bytes([pair[0], *pair.get(1).unwrap_or(&0)])))
+        .sum();
+    while sum > 0xffff {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+/// Build an ICMP (type 8) or ICMPv6 (type 128) echo request with a 16-byte payload.
+/// The kernel fills in the ICMPv6 checksum itself.
+#[cfg(any(target_os = "linux", target_os = "macos", test))]
+fn build_echo_request(ipv6: bool, id: u16, seq: u16) -> Vec<u8> {
+    let mut packet = vec![if ipv6 { 128 } else { 8 }, 0, 0, 0];
+    packet.extend(id.to_be_bytes());
+    packet.extend(seq.to_be_bytes());
+    packet.extend(b"sazgar-ping-0123");
+    if !ipv6 {
+        let checksum = icmp_checksum(&packet);
+        packet[2..4].copy_from_slice(&checksum.to_be_bytes());
+    }
+    packet
+}
+
+/// Parse an echo reply into (id, seq, ttl). macOS datagram ICMP sockets hand
+/// back the IPv4 header too, which is where the TTL comes from.
+#[cfg(any(target_os = "linux", target_os = "macos", test))]
+fn parse_echo_reply(packet: &[u8], ipv6: bool, has_ip_header: bool) -> Option<(u16, u16, Option<i32>)> {
+    let (icmp, ttl) = if has_ip_header {
+        let header_len = usize::from(packet.first()? & 0x0f) * 4;
+        (packet.get(header_len..)?, Some(i32::from(*packet.get(8)?)))
+    } else {
+        (packet, None)
+    };
+    if icmp.len() < 8 || icmp[0] != if ipv6 { 129 } else { 0 } {
+        return None;
+    }
+    Some((u16::from_be_bytes([icmp[4], icmp[5]]), u16::from_be_bytes([icmp[6], icmp[7]]), ttl))
+}
+
+/// Fill a sockaddr for `address` with port 0, as sendto wants for ICMP
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn raw_socket_address(address: std::net::IpAddr) -> (libc::sockaddr_storage, libc::socklen_t) {
+    let mut storage: libc::sockaddr_storage = unsafe { std::mem::zeroed() };
+    let len = match address {
+        std::net::IpAddr::V4(v4) => {
+            let sin = unsafe { &mut *(&mut storage as *mut _ as *mut libc::sockaddr_in) };
+            sin.sin_family = libc::AF_INET as libc::sa_family_t;
+            sin.sin_addr.s_addr = u32::from(v4).to_be();
+            std::mem::size_of::<libc::sockaddr_in>()
+        }
+        std::net::IpAddr::V6(v6) => {
+            let sin6 = unsafe { &mut *(&mut storage as *mut _ as *mut libc::sockaddr_in6) };
+            sin6.sin6_family = libc::AF_INET6 as libc::sa_family_t;
+            sin6.sin6_addr.s6_addr = v6.octets();
+            std::mem::size_of::<libc::sockaddr_in6>()
+        }
+    };
+    (storage, len as libc::socklen_t)
+}
+
+/// Probe with an unprivileged datagram ICMP socket. None when the socket cannot
+/// be opened (on Linux, the group is outside net.ipv4.ping_group_range).
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn icmp_ping(address: std::net::IpAddr, count: u16, timeout: std::time::Duration) -> Option<Vec<PingResult>> {
+    let ipv6 = address.is_ipv6();
+    let (domain, protocol) = if ipv6 { (libc::AF_INET6, libc::IPPROTO_ICMPV6) } else { (libc::AF_INET, libc::IPPROTO_ICMP) };
+    let fd = unsafe { libc::socket(domain, libc::SOCK_DGRAM, protocol) };
+    if fd < 0 {
+        return None;
+    }
+    
+    // Ask for the TTL / hop limit as ancillary data; macOS IPv4 has it in the IP header instead
+    let enable: libc::c_int = 1;
+    let has_ip_header = cfg!(target_os = "macos") && !ipv6;
+    let (level, option) = if ipv6 { (libc::IPPROTO_IPV6, libc::IPV6_RECVHOPLIMIT) } else { (libc::IPPROTO_IP, libc::IP_RECVTTL) };
+    if !has_ip_header {
+        unsafe {
+            libc::setsockopt(fd, level, option, &enable as *const _ as *const libc::c_void, std::mem::size_of::<libc::c_int>() as libc::socklen_t)
+        };
+    }
+    
+    let (target, target_len) = raw_socket_address(address);
+    // Linux replaces the id with the socket's own and filters replies for us; macOS does neither
+    let id = std::process::id() as u16;
+    let mut results = Vec::new();
+    
+    for seq in 1..=count {
+        let started = std::time::Instant::now();
+        let request = build_echo_request(ipv6, id, seq);
+        let sent = unsafe {
+            libc::sendto(fd, request.as_ptr() as *const libc::c_void, request.len(), 0, &target as *const _ as *const libc::sockaddr, target_len)
+        };
+        
+        let mut reply = None;
+        while sent == request.len() as isize {
+            let remaining = timeout.saturating_sub(started.elapsed());
+            let mut pollfd = libc::pollfd { fd, events: libc::POLLIN, revents: 0 };
+            if remaining.is_zero() || unsafe { libc::poll(&mut pollfd, 1, remaining.as_millis().max(1) as libc::c_int) } <= 0 {
+                break;
+            }
+            
+            let mut buffer = [0u8; 1500];
+            let mut control = [0u64; 16];
+            let mut iov = libc::iovec { iov_base: buffer.as_mut_ptr() as *mut libc::c_void, iov_len: buffer.len() };
+            let mut message: libc::msghdr = unsafe { std::mem::zeroed() };
+            message.msg_iov = &mut iov;
+            message.msg_iovlen = 1;
+            message.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+            message.msg_controllen = std::mem::size_of_val(&control) as _;
+            let len = unsafe { libc::recvmsg(fd, &mut message, 0) };
+            if len <= 0 {
+                break;
+            }
+            
+            let Some((reply_id, reply_seq, mut ttl)) = parse_echo_reply(&buffer[..len as usize], ipv6, has_ip_header) else {
+                continue;
+            };
+            if reply_seq != seq || (cfg!(target_os = "macos") && reply_id != id) {
+                continue;
+            }
+            let mut cmsg = unsafe { libc::CMSG_FIRSTHDR(&message) };
+            while !cmsg.is_null() {
+                let header = unsafe { &*cmsg };
+                let is_ttl = (header.cmsg_level == libc::IPPROTO_IP && (header.cmsg_type == libc::IP_TTL || header.cmsg_type == libc::IP_RECVTTL))
+                    || (header.cmsg_level == libc::IPPROTO_IPV6 && header.cmsg_type == libc::IPV6_HOPLIMIT);
+                if is_ttl {
+                    // Linux and IPv6 send an int; macOS IPv4 would send a single byte
+                    let data = unsafe { libc::CMSG_DATA(cmsg) };
+                    ttl = Some(if header.cmsg_len as usize >= unsafe { libc::CMSG_LEN(4) } as usize {
+                        unsafe { std::ptr::read_unaligned(data as *const libc::c_int) }
+                    } else {
+                        i32::from(unsafe { *data })
+                    });
+                }
+                cmsg = unsafe { libc::CMSG_NXTHDR(&message, cmsg) };
+            }
+            reply = Some((started.elapsed().as_secs_f64() * 1000.0, ttl));
+            break;
+        }
+        
+        results.push(PingResult {
+            seq,
+            rtt_ms: reply.map(|(rtt_ms, _)| rtt_ms),
+            ttl: reply.and_then(|(_, ttl)| ttl),
+            method: "icmp",
+        });
+        if seq < count {
+            std::thread::sleep(PING_MIN_INTERVAL.saturating_sub(started.elapsed()));
+        }
+    }
+    
+    unsafe { libc::close(fd) };
+    Some(results)
+}
+
+/// Probe with IcmpSendEcho, which needs no privileges. IPv4 only.
+#[cfg(windows)]
+fn icmp_ping(address: std::net::IpAddr, count: u16, timeout: std::time::Duration) -> Option<Vec<PingResult>> {
+    use windows_sys::Win32::NetworkManagement::IpHelper::{IcmpCloseHandle, IcmpCreateFile, IcmpSendEcho, ICMP_ECHO_REPLY};
+    use windows_sys::Win32::Foundation::INVALID_HANDLE_VALUE;
+    
+    let std::net::IpAddr::V4(v4) = address else {
+        return None;
+    };
+    let handle = unsafe { IcmpCreateFile() };
+    if handle == INVALID_HANDLE_VALUE {
+        return None;
+    }
+    
+    let payload = *b"sazgar-ping-0123";
+    let mut results = Vec::new();
+    for seq in 1..=count {
+        let started = std::time::Instant::now();
+        let mut reply = vec![0u8; std::mem::size_of::<ICMP_ECHO_REPLY>() + payload.len() + 8];
+        let replies = unsafe {
+            IcmpSendEcho(
+                handle,
+                u32::from_ne_bytes(v4.octets()),
+                payload.as_ptr() as *const _,
+                payload.len() as u16,
+                std::ptr::null(),
+                reply.as_mut_ptr() as *mut _,
+                reply.len() as u32,
+                timeout.as_millis() as u32,
+            )
+        };
+        let echo = unsafe { &*(reply.as_ptr() as *const ICMP_ECHO_REPLY) };
+        // Status 0 is IP_SUCCESS; anything else (timed out, unreachable) is a failed probe
+        let success = replies > 0 && echo.Status == 0;
+        results.push(PingResult {
+            seq,
+            rtt_ms: success.then(|| f64::from(echo.RoundTripTime)),
+            ttl: success.then(|| i32::from(echo.Options.Ttl)),
+            method: "icmp",
+        });
+        if seq < count {
+            std::thread::sleep(PING_MIN_INTERVAL.saturating_sub(started.elapsed()));
+        }
+    }
+    
+    unsafe { IcmpCloseHandle(handle) };
+    Some(results)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn icmp_ping(_: std::net::IpAddr, _: u16, _: std::time::Duration) -> Option<Vec<PingResult>> {
+    None
+}
+
+/// Time TCP connects to `port`. A refused connection still proves the host
+/// answered, so it counts as a reply.
+fn tcp_ping(address: std::net::IpAddr, port: u16, count: u16, timeout: std::time::Duration) -> Vec<PingResult> {
+    (1..=count).map(|seq| {
+        let started = std::time::Instant::now();
+        let answered = match std::net::TcpStream::connect_timeout(&std::net::SocketAddr::new(address, port), timeout) {
+            Ok(_) => true,
+            Err(e) => e.kind() == std::io::ErrorKind::ConnectionRefused,
+        };
+        let rtt_ms = answered.then(|| started.elapsed().as_secs_f64() * 1000.0);
+        if seq < count {
+            std::thread::sleep(PING_MIN_INTERVAL.saturating_sub(started.elapsed()));
+        }
+        PingResult { seq, rtt_ms, ttl: None, method: "tcp" }
+    }).collect()
+}
+
+struct PingVTab;
+
+impl VTab for PingVTab {
+    type InitData = PingInitData;
+    type BindData = PingBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("host", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("seq", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("rtt_ms", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("ttl", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("success", LogicalTypeHandle::from(LogicalTypeId::Boolean));
+        bind.add_result_column("method", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        
+        let host = bind.get_parameter(0).to_string();
+        
+        let count = bind.get_named_parameter("count").map(|v| v.to_int64()).unwrap_or(4);
+        if !(1..=1000).contains(&count) {
+            return Err(format!("sazgar_ping: count must be between 1 and 1000, got {}", count).into());
+        }
+        let timeout_ms = bind.get_named_parameter("timeout_ms").map(|v| v.to_int64()).unwrap_or(1000);
+        if timeout_ms <= 0 {
+            return Err(format!("sazgar_ping: timeout_ms must be positive, got {}", timeout_ms).into());
+        }
+        let port = bind.get_named_parameter("port").map(|v| v.to_int64()).unwrap_or(80);
+        if !(1..=65535).contains(&port) {
+            return Err(format!("sazgar_ping: port must be between 1 and 65535, got {}", port).into());
+        }
+        
+        Ok(PingBindData { host, count: count as u16, timeout_ms: timeout_ms as u64, port: port as u16 })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let bind_data = init.get_bind_data::<PingBindData>();
+        let (host, count, timeout_ms, port) = unsafe {
+            ((*bind_data).host.clone(), (*bind_data).count, (*bind_data).timeout_ms, (*bind_data).port)
+        };
+        let timeout = std::time::Duration::from_millis(timeout_ms);
+        
+        // A host that does not resolve has nothing to probe, so yields zero rows
+        let address = resolve_hostname_with_timeout(&host, timeout)
+            .and_then(|result| result.addresses.first().copied());
+        let result_data = match address {
+            Some(address) => icmp_ping(address, count, timeout).unwrap_or_else(|| tcp_ping(address, port, count, timeout)),
+            None => Vec::new(),
+        };
+        let result_count = result_data.len();
+        
+        Ok(PingInitData {
+            current_idx: AtomicUsize::new(0),
+            host,
+            result_count,
+            result_data,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn std::error::Error>> {
+        let init_data = func.get_init_data();
+        let current = init_data.current_idx.load(Ordering::Relaxed);
+        
+        if current >= init_data.result_count {
+            output.set_len(0);
+            return Ok(());
+        }
+        
+        let batch_size = std::cmp::min(2048, init_data.result_count - current);
+        
+        for i in 0..batch_size {
+            let result = &init_data.result_data[current + i];
+            
+            output.flat_vector(0).insert(i, CString::new(init_data.host.clone())?);
+            output.flat_vector(1).as_mut_slice::<i32>()[i] = i32::from(result.seq);
+            match result.rtt_ms {
+                Some(rtt_ms) => output.flat_vector(2).as_mut_slice::<f64>()[i] = rtt_ms,
+                None => output.flat_vector(2).set_null(i),
+            }
+            match result.ttl {
+                Some(ttl) => output.flat_vector(3).as_mut_slice::<i32>()[i] = ttl,
+                None => output.flat_vector(3).set_null(i),
+            }
+            output.flat_vector(4).as_mut_slice::<bool>()[i] = result.rtt_ms.is_some();
+            output.flat_vector(5).insert(i, CString::new(result.method)?);
+        }
+        
+        init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
+        output.set_len(batch_size);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        Some(vec![LogicalTypeHandle::from(LogicalTypeId::Varchar)])
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        Some(vec![
+            ("count".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+            ("timeout_ms".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+            ("port".to_string(), LogicalTypeHandle::from(LogicalTypeId::Bigint)),
+        ])
+    }
+}
+
+// ============================================================================
+// WiFi Table Function - sazgar_wifi()
+// Returns the association state of each wireless interface
+// ============================================================================
+
+#[repr(C)]
+struct WifiBindData;
+
+/// One wireless interface; everything but the name is None when not associated
+#[derive(Default)]
+struct WifiInfo {
+    interface: String,
+    ssid: Option<String>,
+    bssid: Option<String>,
+    signal_dbm: Option<i32>,
+    signal_percent: Option<f32>,
+    frequency_mhz: Option<i32>,
+    channel: Option<i32>,
+    tx_rate_mbps: Option<f64>,
+    security: Option<String>,
+}
+
+#[repr(C)]
+struct WifiInitData {
+    current_idx: AtomicUsize,
+    wifi_count: usize,
+    wifi_data: Vec<WifiInfo>,
+}
+
+/// Map a centre frequency to its 802.11 channel number (2.4, 5 and 6 GHz bands)
+#[cfg(any(target_os = "linux", test))]
+fn wifi_frequency_to_channel(frequency_mhz: i32) -> Option<i32> {
+    match frequency_mhz {
+        2484 => Some(14),
+        2412..=2472 => Some((frequency_mhz - 2407) / 5),
+        5955..=7115 => Some((frequency_mhz - 5950) / 5),
+        5000..=5900 => Some((frequency_mhz - 5000) / 5),
+        _ => None,
+    }
+}
+
+/// Map a channel back to its centre frequency. Channel numbers repeat across
+/// the 5 and 6 GHz bands, so the band is needed to tell them apart.
+#[cfg(any(target_os = "macos", windows, test))]
+fn wifi_channel_to_frequency(channel: i32, band_ghz: Option<f64>) -> Option<i32> {
+    match (channel, band_ghz) {
+        (14, _) => Some(2484),
+        (1..=13, None) => Some(2407 + channel * 5),
+        (_, Some(band)) if band < 3.0 => Some(2407 + channel * 5),
+        (_, Some(band)) if band > 5.9 => Some(5950 + channel * 5),
+        (32..=177, _) => Some(5000 + channel * 5),
+        _ => None,
+    }
+}
+
+/// Approximate signal quality from RSSI the way Windows does: -100 dBm is 0%
+/// and -50 dBm or better is 100%
+#[cfg(any(target_os = "linux", target_os = "macos", test))]
+fn wifi_signal_percent(signal_dbm: i32) -> f32 {
+    (2 * (signal_dbm + 100)).clamp(0, 100) as f32
+}
+
+/// Parse `iw dev <interface> link`, which reads "Not connected." when idle
+#[cfg(any(target_os = "linux", test))]
+fn parse_iw_link(output: &str, info: &mut WifiInfo) {
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("Connected to ") {
+            info.bssid = rest.split_whitespace().next().and_then(normalize_mac_address);
+        } else if let Some(ssid) = line.strip_prefix("SSID: ") {
+            info.ssid = Some(ssid.to_string());
+        } else if let Some(freq) = line.strip_prefix("freq: ") {
+            // Newer iw prints "5180.0"
+            info.frequency_mhz = freq.trim().parse::<f64>().ok().map(|mhz| mhz as i32);
+        } else if let Some(signal) = line.strip_prefix("signal: ") {
+            info.signal_dbm = signal.split_whitespace().next().and_then(|dbm| dbm.parse().ok());
+        } else if let Some(rate) = line.strip_prefix("tx bitrate: ") {
+            info.tx_rate_mbps = rate.split_whitespace().next().and_then(|mbps| mbps.parse().ok());
+        }
+    }
+}
+
+/// Work out the security of `bssid` from `iw dev <interface> scan dump`:
+/// RSN means WPA2 (SAE makes it WPA3), a WPA element alone means WPA, and the
+/// Privacy capability without either means WEP
+#[cfg(any(target_os = "linux", test))]
+fn parse_iw_scan_security(output: &str, bssid: &str) -> Option<String> {
+    let block = output.split("\nBSS ")
+        .map(|block| block.strip_prefix("BSS ").unwrap_or(block))
+        .find(|block| block.get(..17).is_some_and(|mac| mac.eq_ignore_ascii_case(bssid)))?;
+    
+    let (mut rsn, mut wpa, mut privacy) = (false, false, false);
+    let (mut sae, mut psk, mut enterprise) = (false, false, false);
+    for line in block.lines().map(str::trim) {
+        if line.starts_with("RSN:") {
+            rsn = true;
+        } else if line.starts_with("WPA:") {
+            wpa = true;
+        } else if line.starts_with("capability:") {
+            privacy = line.contains("Privacy");
+        } else if let Some(suites) = line.strip_prefix("* Authentication suites:") {
+            sae |= suites.contains("SAE");
+            psk |= suites.split_whitespace().any(|suite| suite == "PSK");
+            enterprise |= suites.contains("802.1X");
+        }
+    }
+    
+    let security = match (rsn, wpa, privacy) {
+        (true, _, _) if sae && psk => "wpa2-wpa3",
+        (true, _, _) if sae => "wpa3",
+        (true, _, _) => "wpa2",
+        (false, true, _) => "wpa",
+        (false, false, true) => "wep",
+        (false, false, false) => "open",
+    };
+    Some(if enterprise && !psk && !sae { format!("{}-enterprise", security) } else { security.to_string() })
+}
+
+/// Find wireless interfaces through sysfs and describe each with `iw`. Without
+/// `iw`, /proc/net/wireless still gives the signal level of associated links.
+#[cfg(target_os = "linux")]
+fn read_wifi_interfaces() -> Vec<WifiInfo> {
+    let mut interfaces: Vec<String> = std::fs::read_dir("/sys/class/net")
+        .map(|entries| {
+            entries.flatten()
+                .filter(|entry| entry.path().join("wireless").exists() || entry.path().join("phy80211").exists())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    interfaces.sort();
+    
+    // "wlan0: 0000   54.  -56.  -256 ..." after two header lines
+    let proc_signals: std::collections::HashMap<String, i32> = std::fs::read_to_string("/proc/net/wireless")
+        .map(|content| {
+            content.lines().skip(2).filter_map(|line| {
+                let (name, rest) = line.split_once(':')?;
+                let level = rest.split_whitespace().nth(2)?.trim_end_matches('.').parse().ok()?;
+                Some((name.trim().to_string(), level))
+            }).collect()
+        })
+        .unwrap_or_default();
+    
+    let timeout = std::time::Duration::from_secs(5);
+    interfaces.into_iter().map(|interface| {
+        let mut info = WifiInfo { interface, ..Default::default() };
+        match command_output_with_timeout("iw", &["dev", &info.interface, "link"], timeout) {
+            Some(link) => parse_iw_link(&link, &mut info),
+            None => info.signal_dbm = proc_signals.get(&info.interface).copied(),
+        }
+        if let Some(bssid) = &info.bssid {
+            info.security = command_output_with_timeout("iw", &["dev", &info.interface, "scan", "dump"], timeout)
+                .and_then(|scan| parse_iw_scan_security(&scan, bssid));
+        }
+        info.channel = info.frequency_mhz.and_then(wifi_frequency_to_channel);
+        info.signal_percent = info.signal_dbm.map(wifi_signal_percent);
+        info
+    }).collect()
+}
+
+/// Parse `system_profiler SPAirPortDataType -json`. It has no BSSID, and macOS
+/// hides the SSID from processes without Location Services permission.
+#[cfg(any(target_os = "macos", test))]
+fn parse_airport_json(report: &serde_json::Value) -> Vec<WifiInfo> {
+    let interfaces = report["SPAirPortDataType"].as_array().into_iter().flatten()
+        .flat_map(|entry| entry["spairport_airport_interfaces"].as_array().into_iter().flatten());
+    
+    // awdl0 and llw0 are listed too, but have no status
+    interfaces.filter(|interface| interface.get("spairport_status_information").is_some()).map(|interface| {
+        let mut info = WifiInfo {
+            interface: interface["_name"].as_str().unwrap_or_default().to_string(),
+            ..Default::default()
+        };
+        let network = &interface["spairport_current_network_information"];
+        if interface["spairport_status_information"] != "spairport_status_connected" || !network.is_object() {
+            return info;
+        }
+        
+        info.ssid = network["_name"].as_str().map(|ssid| ssid.to_string());
+        // "-52 dBm / -92 dBm" is signal / noise
+        info.signal_dbm = network["spairport_signal_noise"].as_str()
+            .and_then(|signal| signal.split_whitespace().next()?.parse().ok());
+        info.signal_percent = info.signal_dbm.map(wifi_signal_percent);
+        info.tx_rate_mbps = network["spairport_network_rate"].as_f64();
+        // "149 (5GHz, 80MHz)"
+        if let Some(channel) = network["spairport_network_channel"].as_str() {
+            info.channel = channel.split_whitespace().next().and_then(|c| c.parse().ok());
+            let band = channel.split_once('(')
+                .and_then(|(_, rest)| rest.split("GHz").next()?.parse::<f64>().ok());
+            info.frequency_mhz = info.channel.and_then(|channel| wifi_channel_to_frequency(channel, band));
+        }
+        // "spairport_security_mode_wpa2_personal" -> "wpa2"
+        info.security = network["spairport_security_mode"].as_str().map(|mode| {
+            match mode.trim_start_matches("spairport_security_mode_").trim_end_matches("_personal") {
+                "none" => "open".to_string(),
+                mode => mode.replace('_', "-"),
+            }
+        });
+        info
+    }).collect()
+}
+
+#[cfg(target_os = "macos")]
+fn read_wifi_interfaces() -> Vec<WifiInfo> {
+    command_output_with_timeout("system_profiler", &["SPAirPortDataType", "-json"], std::time::Duration::from_secs(10))
+        .and_then(|output| serde_json::from_str::<serde_json::Value>(&output).ok())
+        .map(|report| parse_airport_json(&report))
+        .unwrap_or_default()
+}
+
+/// Query each WLAN interface's current connection, RSSI and channel through the
+/// Native Wifi API
+#[cfg(windows)]
+fn read_wifi_interfaces() -> Vec<WifiInfo> {
+    use windows_sys::Win32::Foundation::HANDLE;
+    use windows_sys::Win32::NetworkManagement::IpHelper::ConvertInterfaceGuidToLuid;
+    use windows_sys::Win32::NetworkManagement::Ndis::NET_LUID_LH;
+    use windows_sys::Win32::NetworkManagement::WiFi::{
+        wlan_interface_state_connected, wlan_intf_opcode_channel_number, wlan_intf_opcode_current_connection,
+        wlan_intf_opcode_rssi, WlanCloseHandle, WlanEnumInterfaces, WlanFreeMemory, WlanOpenHandle,
+        WlanQueryInterface, WLAN_CONNECTION_ATTRIBUTES, WLAN_INTERFACE_INFO_LIST,
+    };
+    
+    let mut wifi = Vec::new();
+    let mut handle: HANDLE = std::ptr::null_mut();
+    let mut version = 0u32;
+    // No WLAN service (e.g. servers without wireless hardware) means no rows
+    if unsafe { WlanOpenHandle(2, std::ptr::null(), &mut version, &mut handle) } != 0 {
+        return wifi;
+    }
+    
+    let mut list: *mut WLAN_INTERFACE_INFO_LIST = std::ptr::null_mut();
+    if unsafe { WlanEnumInterfaces(handle, std::ptr::null(), &mut list) } == 0 {
+        let count = unsafe { (*list).dwNumberOfItems } as usize;
+        let entries = unsafe { std::slice::from_raw_parts((*list).InterfaceInfo.as_ptr(), count) };
+        
+        for entry in entries {
+            let guid = &entry.InterfaceGuid;
+            // Prefer the alias ("Wi-Fi") over the adapter description
+            let description = || {
+                let description = &entry.strInterfaceDescription;
+                let end = description.iter().position(|&c| c == 0).unwrap_or(description.len());
+                String::from_utf16_lossy(&description[..end])
+            };
+            let mut luid: NET_LUID_LH = unsafe { std::mem::zeroed() };
+            let interface = if unsafe { ConvertInterfaceGuidToLuid(guid, &mut luid) } == 0 {
+                interface_alias(&luid, description)
+            } else {
+                description()
+            };
+            let mut info = WifiInfo { interface, ..Default::default() };
+            
+            let query = |opcode| -> Option<Vec<u8>> {
+                let (mut size, mut data) = (0u32, std::ptr::null_mut());
+                if unsafe { WlanQueryInterface(handle, guid, opcode, std::ptr::null(), &mut size, &mut data, std::ptr::null_mut()) } != 0 {
+                    return None;
+                }
+                let bytes = unsafe { std::slice::from_raw_parts(data as *const u8, size as usize) }.to_vec();
+                unsafe { WlanFreeMemory(data) };
+                Some(bytes)
+            };
+            
+            if entry.isState == wlan_interface_state_connected {
+                if let Some(bytes) = query(wlan_intf_opcode_current_connection)
+                    .filter(|bytes| bytes.len() >= std::mem::size_of::<WLAN_CONNECTION_ATTRIBUTES>())
+                {
+                    let connection = unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const WLAN_CONNECTION_ATTRIBUTES) };
+                    let association = &connection.wlanAssociationAttributes;
+                    let ssid = &association.dot11Ssid;
+                    info.ssid = Some(String::from_utf8_lossy(&ssid.ucSSID[..(ssid.uSSIDLength as usize).min(32)]).to_string());
+                    let mac = association.dot11Bssid;
+                    info.bssid = normalize_mac_address(&format!(
+                        "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}", mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
+                    ));
+                    info.signal_percent = Some(association.wlanSignalQuality as f32);
+                    // Rates are reported in kbps
+                    info.tx_rate_mbps = Some(f64::from(association.ulTxRate) / 1000.0);
+                    
+                    // DOT11_AUTH_ALGORITHM and DOT11_CIPHER_ALGORITHM values
+                    let security = &connection.wlanSecurityAttributes;
+                    let wep = matches!(security.dot11CipherAlgorithm, 0x01 | 0x05 | 0x101);
+                    info.security = Some(match security.dot11AuthAlgorithm {
+                        1 if wep => "wep",
+                        1 => "open",
+                        2 => "wep",
+                        3 => "wpa-enterprise",
+                        4 => "wpa",
+                        6 => "wpa2-enterprise",
+                        7 => "wpa2",
+                        8 | 11 => "wpa3-enterprise",
+                        9 => "wpa3",
+                        10 => "owe",
+                        _ => "unknown",
+                    }.to_string());
+                    
+                    info.signal_dbm = query(wlan_intf_opcode_rssi)
+                        .and_then(|bytes| Some(i32::from_ne_bytes(bytes.get(..4)?.try_into().ok()?)));
+                    info.channel = query(wlan_intf_opcode_channel_number)
+                        .and_then(|bytes| Some(u32::from_ne_bytes(bytes.get(..4)?.try_into().ok()?) as i32));
+                    info.frequency_mhz = info.channel.and_then(|channel| wifi_channel_to_frequency(channel, None));
+                }
+            }
+            wifi.push(info);
+        }
+        unsafe { WlanFreeMemory(list as *const _) };
+    }
+    
+    unsafe { WlanCloseHandle(handle, std::ptr::null()) };
+    wifi
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn read_wifi_interfaces() -> Vec<WifiInfo> {
+    Vec::new()
+}
+
+struct WifiVTab;
+
+impl VTab for WifiVTab {
+    type InitData = WifiInitData;
+    type BindData = WifiBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
+        bind.add_result_column("interface", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("ssid", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("bssid", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("signal_dbm", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("signal_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("frequency_mhz", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("channel", LogicalTypeHandle::from(LogicalTypeId::Integer));
+        bind.add_result_column("tx_rate_mbps", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("security", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        Ok(WifiBindData)
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
+        let wifi_data = read_wifi_interfaces();
+        let wifi_count = wifi_data.len();
+        
+        Ok(WifiInitData {
+            current_idx: AtomicUsize::new(0),
+            wifi_count,
+            wifi_data,
         })
     }
 
@@ -2106,22 +12437,37 @@ impl VTab for DockerVTab {
         let init_data = func.get_init_data();
         let current = init_data.current_idx.load(Ordering::Relaxed);
         
-        if current >= init_data.container_count {
+        if current >= init_data.wifi_count {
             output.set_len(0);
             return Ok(());
         }
         
-        let batch_size = std::cmp::min(2048, init_data.container_count - current);
+        let batch_size = std::cmp::min(2048, init_data.wifi_count - current);
         
         for i in 0..batch_size {
-            let container = &init_data.container_data[current + i];
+            let wifi = &init_data.wifi_data[current + i];
             
-            output.flat_vector(0).insert(i, CString::new(container.id.clone())?);
-            output.flat_vector(1).insert(i, CString::new(container.name.clone())?);
-            output.flat_vector(2).insert(i, CString::new(container.image.clone())?);
-            output.flat_vector(3).insert(i, CString::new(container.status.clone())?);
-            output.flat_vector(4).insert(i, CString::new(container.state.clone())?);
-            output.flat_vector(5).insert(i, CString::new(container.created.clone())?);
+            output.flat_vector(0).insert(i, CString::new(wifi.interface.clone())?);
+            for (column, value) in [(1, &wifi.ssid), (2, &wifi.bssid), (8, &wifi.security)] {
+                match value {
+                    Some(value) => output.flat_vector(column).insert(i, CString::new(value.clone())?),
+                    None => output.flat_vector(column).set_null(i),
+                }
+            }
+            for (column, value) in [(3, wifi.signal_dbm), (5, wifi.frequency_mhz), (6, wifi.channel)] {
+                match value {
+                    Some(value) => output.flat_vector(column).as_mut_slice::<i32>()[i] = value,
+                    None => output.flat_vector(column).set_null(i),
+                }
+            }
+            match wifi.signal_percent {
+                Some(percent) => output.flat_vector(4).as_mut_slice::<f32>()[i] = percent,
+                None => output.flat_vector(4).set_null(i),
+            }
+            match wifi.tx_rate_mbps {
+                Some(rate) => output.flat_vector(7).as_mut_slice::<f64>()[i] = rate,
+                None => output.flat_vector(7).set_null(i),
+            }
         }
         
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
@@ -2135,97 +12481,89 @@ impl VTab for DockerVTab {
 }
 
 // ============================================================================
-// Services Table Function - sazgar_services()
-// Returns running system services (platform-specific)
+// Power Supply Table Function - sazgar_power_supply()
+// Returns AC adapters, batteries and USB supplies from sysfs (Linux only)
 // ============================================================================
 
 #[repr(C)]
-struct ServicesBindData;
+struct PowerSupplyBindData;
 
-struct ServiceInfo {
+struct PowerSupplyInfo {
     name: String,
-    status: String,
-    description: String,
+    supply_type: String,
+    online: Option<bool>,
+    status: Option<String>,
+    capacity_percent: Option<f32>,
+    power_watts: Option<f64>,
+    voltage_volts: Option<f64>,
 }
 
 #[repr(C)]
-struct ServicesInitData {
+struct PowerSupplyInitData {
     current_idx: AtomicUsize,
-    service_count: usize,
-    service_data: Vec<ServiceInfo>,
+    supply_count: usize,
+    supply_data: Vec<PowerSupplyInfo>,
 }
 
-struct ServicesVTab;
+/// Read every /sys/class/power_supply entry. Batteries report "present"
+/// rather than "online", and drivers without power_now report current_now.
+#[cfg(target_os = "linux")]
+fn read_power_supplies() -> Vec<PowerSupplyInfo> {
+    let mut names = read_sys_dir_names("/sys/class/power_supply");
+    names.sort();
+    
+    names.into_iter().map(|name| {
+        let base = format!("/sys/class/power_supply/{}", name);
+        let read = |file: &str| std::fs::read_to_string(format!("{}/{}", base, file)).ok().map(|s| s.trim().to_string());
+        let read_u64 = |file: &str| read_sys_u64(&format!("{}/{}", base, file));
+        
+        // Microvolts, microwatts and microamps
+        let voltage_volts = read_u64("voltage_now").map(|uv| uv as f64 / 1e6);
+        let power_watts = read_u64("power_now").map(|uw| uw as f64 / 1e6).or_else(|| {
+            Some(read_u64("current_now")? as f64 / 1e6 * voltage_volts?)
+        });
+        
+        PowerSupplyInfo {
+            supply_type: read("type").unwrap_or_default(),
+            online: read("online").or_else(|| read("present")).map(|value| value != "0"),
+            status: read("status").filter(|status| !status.is_empty()),
+            capacity_percent: read_u64("capacity").map(|percent| percent as f32),
+            power_watts,
+            voltage_volts,
+            name,
+        }
+    }).collect()
+}
 
-impl VTab for ServicesVTab {
-    type InitData = ServicesInitData;
-    type BindData = ServicesBindData;
+struct PowerSupplyVTab;
+
+impl VTab for PowerSupplyVTab {
+    type InitData = PowerSupplyInitData;
+    type BindData = PowerSupplyBindData;
 
     fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn std::error::Error>> {
         bind.add_result_column("name", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("type", LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        bind.add_result_column("online", LogicalTypeHandle::from(LogicalTypeId::Boolean));
         bind.add_result_column("status", LogicalTypeHandle::from(LogicalTypeId::Varchar));
-        bind.add_result_column("description", LogicalTypeHandle::from(LogicalTypeId::Varchar));
-        Ok(ServicesBindData)
+        bind.add_result_column("capacity_percent", LogicalTypeHandle::from(LogicalTypeId::Float));
+        bind.add_result_column("power_watts", LogicalTypeHandle::from(LogicalTypeId::Double));
+        bind.add_result_column("voltage_volts", LogicalTypeHandle::from(LogicalTypeId::Double));
+        Ok(PowerSupplyBindData)
     }
 
     fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn std::error::Error>> {
-        let mut service_data: Vec<ServiceInfo> = Vec::new();
-        
-        // macOS: Use launchctl
-        #[cfg(target_os = "macos")]
-        {
-            if let Ok(output) = std::process::Command::new("launchctl")
-                .args(["list"])
-                .output()
-            {
-                if output.status.success() {
-                    let stdout = String::from_utf8_lossy(&output.stdout);
-                    for line in stdout.lines().skip(1) {  // Skip header
-                        let parts: Vec<&str> = line.split_whitespace().collect();
-                        if parts.len() >= 3 {
-                            service_data.push(ServiceInfo {
-                                name: parts[2].to_string(),
-                                status: if parts[0] == "-" { "inactive".to_string() } else { "running".to_string() },
-                                description: "".to_string(),
-                            });
-                        }
-                    }
-                }
-            }
-        }
-        
-        // Linux: Use systemctl
         #[cfg(target_os = "linux")]
-        {
-            if let Ok(output) = std::process::Command::new("systemctl")
-                .args(["list-units", "--type=service", "--all", "--no-pager", "--plain"])
-                .output()
-            {
-                if output.status.success() {
-                    let stdout = String::from_utf8_lossy(&output.stdout);
-                    for line in stdout.lines().skip(1) {  // Skip header
-                        let parts: Vec<&str> = line.split_whitespace().collect();
-                        if parts.len() >= 4 {
-                            let name = parts[0].trim_end_matches(".service").to_string();
-                            let status = parts[3].to_string();
-                            let description = parts[4..].join(" ");
-                            service_data.push(ServiceInfo {
-                                name,
-                                status,
-                                description,
-                            });
-                        }
-                    }
-                }
-            }
-        }
+        let supply_data = read_power_supplies();
+        #[cfg(not(target_os = "linux"))]
+        let supply_data: Vec<PowerSupplyInfo> = Vec::new();
         
-        let service_count = service_data.len();
+        let supply_count = supply_data.len();
         
-        Ok(ServicesInitData {
+        Ok(PowerSupplyInitData {
             current_idx: AtomicUsize::new(0),
-            service_count,
-            service_data,
+            supply_count,
+            supply_data,
         })
     }
 
@@ -2233,19 +12571,36 @@ impl VTab for ServicesVTab {
         let init_data = func.get_init_data();
         let current = init_data.current_idx.load(Ordering::Relaxed);
         
-        if current >= init_data.service_count {
+        if current >= init_data.supply_count {
             output.set_len(0);
             return Ok(());
         }
         
-        let batch_size = std::cmp::min(2048, init_data.service_count - current);
+        let batch_size = std::cmp::min(2048, init_data.supply_count - current);
         
         for i in 0..batch_size {
-            let service = &init_data.service_data[current + i];
+            let supply = &init_data.supply_data[current + i];
             
-            output.flat_vector(0).insert(i, CString::new(service.name.clone())?);
-            output.flat_vector(1).insert(i, CString::new(service.status.clone())?);
-            output.flat_vector(2).insert(i, CString::new(service.description.clone())?);
+            output.flat_vector(0).insert(i, CString::new(supply.name.clone())?);
+            output.flat_vector(1).insert(i, CString::new(supply.supply_type.clone())?);
+            match supply.online {
+                Some(online) => output.flat_vector(2).as_mut_slice::<bool>()[i] = online,
+                None => output.flat_vector(2).set_null(i),
+            }
+            match &supply.status {
+                Some(status) => output.flat_vector(3).insert(i, CString::new(status.clone())?),
+                None => output.flat_vector(3).set_null(i),
+            }
+            match supply.capacity_percent {
+                Some(percent) => output.flat_vector(4).as_mut_slice::<f32>()[i] = percent,
+                None => output.flat_vector(4).set_null(i),
+            }
+            for (column, value) in [(5, supply.power_watts), (6, supply.voltage_volts)] {
+                match value {
+                    Some(value) => output.flat_vector(column).as_mut_slice::<f64>()[i] = value,
+                    None => output.flat_vector(column).set_null(i),
+                }
+            }
         }
         
         init_data.current_idx.store(current + batch_size, Ordering::Relaxed);
@@ -2311,6 +12666,10 @@ impl VTab for VersionVTab {
 // Extension Entry Point
 // ============================================================================
 
+/// # Safety
+///
+/// Called by DuckDB when the extension is loaded; `con` must be a valid connection
+/// provided by the DuckDB C API.
 #[duckdb_entrypoint_c_api()]
 pub unsafe fn extension_entrypoint(con: Connection) -> Result<(), Box<dyn Error>> {
     // Register all table functions
@@ -2357,6 +12716,9 @@ pub unsafe fn extension_entrypoint(con: Connection) -> Result<(), Box<dyn Error>
     con.register_table_function::<PortsVTab>("sazgar_ports")
         .expect("Failed to register sazgar_ports table function");
     
+    con.register_table_function::<PortsLegacyVTab>("sazgar_ports_legacy")
+        .expect("Failed to register sazgar_ports_legacy table function");
+    
     con.register_table_function::<GpuVTab>("sazgar_gpu")
         .expect("Failed to register sazgar_gpu table function");
     
@@ -2375,5 +12737,882 @@ pub unsafe fn extension_entrypoint(con: Connection) -> Result<(), Box<dyn Error>
     con.register_table_function::<ServicesVTab>("sazgar_services")
         .expect("Failed to register sazgar_services table function");
     
+    con.register_table_function::<HugepagesVTab>("sazgar_hugepages")
+        .expect("Failed to register sazgar_hugepages table function");
+    
+    con.register_table_function::<SysLimitsVTab>("sazgar_sys_limits")
+        .expect("Failed to register sazgar_sys_limits table function");
+    
+    con.register_table_function::<SessionsVTab>("sazgar_sessions")
+        .expect("Failed to register sazgar_sessions table function");
+    
+    con.register_table_function::<PressureVTab>("sazgar_pressure")
+        .expect("Failed to register sazgar_pressure table function");
+    
+    con.register_table_function::<ModulesVTab>("sazgar_kernel_modules")
+        .expect("Failed to register sazgar_kernel_modules table function");
+    
+    con.register_table_function::<ServiceRestartsVTab>("sazgar_service_restarts")
+        .expect("Failed to register sazgar_service_restarts table function");
+    
+    con.register_table_function::<NumaVTab>("sazgar_numa")
+        .expect("Failed to register sazgar_numa table function");
+    
+    con.register_table_function::<MountsVTab>("sazgar_mounts")
+        .expect("Failed to register sazgar_mounts table function");
+    
+    con.register_table_function::<TmpfsVTab>("sazgar_tmpfs")
+        .expect("Failed to register sazgar_tmpfs table function");
+    
+    con.register_table_function::<OpenFilesVTab>("sazgar_open_files")
+        .expect("Failed to register sazgar_open_files table function");
+    
+    con.register_table_function::<EnvSecretsVTab>("sazgar_env_secrets")
+        .expect("Failed to register sazgar_env_secrets table function");
+    
+    con.register_table_function::<ZramVTab>("sazgar_zram")
+        .expect("Failed to register sazgar_zram table function");
+    
+    con.register_table_function::<SchedStatsVTab>("sazgar_sched_stats")
+        .expect("Failed to register sazgar_sched_stats table function");
+    
+    con.register_table_function::<DeviceTreeVTab>("sazgar_device_tree")
+        .expect("Failed to register sazgar_device_tree table function");
+    
+    con.register_table_function::<DiskIoVTab>("sazgar_diskio")
+        .expect("Failed to register sazgar_diskio table function");
+    
+    con.register_table_function::<PortStatesVTab>("sazgar_port_states")
+        .expect("Failed to register sazgar_port_states table function");
+    
+    con.register_table_function::<FdSummaryVTab>("sazgar_fd_summary")
+        .expect("Failed to register sazgar_fd_summary table function");
+    
+    con.register_table_function::<SmartVTab>("sazgar_smart")
+        .expect("Failed to register sazgar_smart table function");
+    
+    con.register_table_function::<BlockDevicesVTab>("sazgar_block_devices")
+        .expect("Failed to register sazgar_block_devices table function");
+    
+    con.register_table_function::<DuVTab>("sazgar_du")
+        .expect("Failed to register sazgar_du table function");
+    
+    con.register_table_function::<DnsVTab>("sazgar_dns")
+        .expect("Failed to register sazgar_dns table function");
+    
+    con.register_table_function::<FilesVTab>("sazgar_files")
+        .expect("Failed to register sazgar_files table function");
+    
+    con.register_table_function::<HostsVTab>("sazgar_hosts")
+        .expect("Failed to register sazgar_hosts table function");
+    
+    con.register_scalar_function::<FileStatScalar>("sazgar_file_stat")
+        .expect("Failed to register sazgar_file_stat scalar function");
+    
+    con.register_table_function::<ArpVTab>("sazgar_arp")
+        .expect("Failed to register sazgar_arp table function");
+    
+    con.register_table_function::<RoutesVTab>("sazgar_routes")
+        .expect("Failed to register sazgar_routes table function");
+    
+    con.register_table_function::<TripPointsVTab>("sazgar_trip_points")
+        .expect("Failed to register sazgar_trip_points table function");
+    
+    con.register_table_function::<ListeningSummaryVTab>("sazgar_listening_summary")
+        .expect("Failed to register sazgar_listening_summary table function");
+    
+    con.register_table_function::<NetworkRateVTab>("sazgar_network_rate")
+        .expect("Failed to register sazgar_network_rate table function");
+    
+    con.register_scalar_function::<BatteryMinutesScalar>("sazgar_battery_minutes_remaining")
+        .expect("Failed to register sazgar_battery_minutes_remaining scalar function");
+    
+    con.register_table_function::<CpuTimesVTab>("sazgar_cpu_times")
+        .expect("Failed to register sazgar_cpu_times table function");
+    
+    con.register_table_function::<InterruptsVTab>("sazgar_interrupts")
+        .expect("Failed to register sazgar_interrupts table function");
+    
+    con.register_table_function::<TopIoWaitVTab>("sazgar_top_io_wait")
+        .expect("Failed to register sazgar_top_io_wait table function");
+    
+    con.register_table_function::<LimitsVTab>("sazgar_limits")
+        .expect("Failed to register sazgar_limits table function");
+    
+    con.register_scalar_function::<HostReportScalar>("sazgar_host_report")
+        .expect("Failed to register sazgar_host_report scalar function");
+    
+    con.register_table_function::<VmstatVTab>("sazgar_vmstat")
+        .expect("Failed to register sazgar_vmstat table function");
+    
+    con.register_table_function::<MeminfoVTab>("sazgar_meminfo")
+        .expect("Failed to register sazgar_meminfo table function");
+    
+    con.register_table_function::<NetAddressesVTab>("sazgar_net_addresses")
+        .expect("Failed to register sazgar_net_addresses table function");
+    
+    con.register_table_function::<SwapDevicesVTab>("sazgar_swap_devices")
+        .expect("Failed to register sazgar_swap_devices table function");
+    
+    con.register_table_function::<DnsConfigVTab>("sazgar_dns_config")
+        .expect("Failed to register sazgar_dns_config table function");
+    
+    con.register_table_function::<DnsLookupVTab>("sazgar_dns_lookup")
+        .expect("Failed to register sazgar_dns_lookup table function");
+    
+    con.register_table_function::<PingVTab>("sazgar_ping")
+        .expect("Failed to register sazgar_ping table function");
+    
+    con.register_table_function::<WifiVTab>("sazgar_wifi")
+        .expect("Failed to register sazgar_wifi table function");
+    
+    con.register_table_function::<SocketSummaryVTab>("sazgar_socket_summary")
+        .expect("Failed to register sazgar_socket_summary table function");
+    
+    con.register_table_function::<ThermalZonesVTab>("sazgar_thermal_zones")
+        .expect("Failed to register sazgar_thermal_zones table function");
+    
+    con.register_table_function::<ConnectionsByRemoteVTab>("sazgar_connections_by_remote")
+        .expect("Failed to register sazgar_connections_by_remote table function");
+    
+    con.register_table_function::<PowerSupplyVTab>("sazgar_power_supply")
+        .expect("Failed to register sazgar_power_supply table function");
+    
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disk(name: &str, mount_point: &str, file_system: &str, total_bytes: u64) -> DiskInfo {
+        DiskInfo {
+            name: name.to_string(),
+            mount_point: mount_point.to_string(),
+            file_system: file_system.to_string(),
+            total_bytes,
+            available_bytes: 0,
+            is_removable: false,
+            kind: "SSD".to_string(),
+            is_readonly: false,
+            mount_options: String::new(),
+            device_model: None,
+            device_serial: None,
+            real_device: None,
+            pool_id: String::new(),
+        }
+    }
+
+    fn pool_ids(mut disks: Vec<DiskInfo>) -> Vec<String> {
+        assign_pool_ids(&mut disks);
+        disks.into_iter().map(|d| d.pool_id).collect()
+    }
+
+    #[test]
+    fn apfs_volumes_share_their_container() {
+        let ids = pool_ids(vec![
+            disk("/dev/disk3s1s1", "/", "apfs", 494_384_795_648),
+            disk("/dev/disk3s5", "/System/Volumes/Data", "apfs", 494_384_795_648),
+            disk("/dev/disk3s6", "/System/Volumes/VM", "apfs", 494_384_795_648),
+        ]);
+        assert_eq!(ids, vec!["/dev/disk3"; 3]);
+    }
+
+    #[test]
+    fn apfs_containers_stay_separate() {
+        let ids = pool_ids(vec![
+            disk("/dev/disk3s1", "/", "apfs", 494_384_795_648),
+            disk("/dev/disk3s5", "/System/Volumes/Data", "apfs", 494_384_795_648),
+            disk("/dev/disk5s1", "/Volumes/External", "apfs", 1_000_000_000_000),
+        ]);
+        assert_eq!(ids, vec!["/dev/disk3", "/dev/disk3", "/dev/disk5s1"]);
+    }
+
+    #[test]
+    fn btrfs_subvolumes_share_their_device() {
+        let ids = pool_ids(vec![
+            disk("/dev/sda2", "/", "btrfs", 255_000_000_000),
+            disk("/dev/sda2", "/home", "btrfs", 255_000_000_000),
+            disk("/dev/sda1", "/boot", "ext4", 1_000_000_000),
+        ]);
+        assert_eq!(ids, vec!["/dev/sda2", "/dev/sda2", "/dev/sda1"]);
+    }
+
+    #[test]
+    fn same_device_with_different_totals_is_not_a_pool() {
+        let ids = pool_ids(vec![
+            disk("/dev/disk2s1", "/Volumes/A", "apfs", 100),
+            disk("/dev/disk2s2", "/Volumes/B", "apfs", 200),
+        ]);
+        assert_eq!(ids, vec!["/dev/disk2s1", "/dev/disk2s2"]);
+    }
+
+    #[test]
+    fn virtual_mount_prefixes_match_whole_components() {
+        assert!(is_virtual_filesystem("/run", "ext4"));
+        assert!(is_virtual_filesystem("/run/media/usb", "ext4"));
+        assert!(is_virtual_filesystem("/proc", "proc"));
+        assert!(!is_virtual_filesystem("/running_data", "ext4"));
+        assert!(!is_virtual_filesystem("/snapshots", "btrfs"));
+        assert!(!is_virtual_filesystem("/devel", "xfs"));
+        assert!(!is_virtual_filesystem("/system", "ext4"));
+    }
+
+    #[test]
+    fn virtual_fs_types_match_exactly() {
+        assert!(is_virtual_filesystem("/mnt/ram", "tmpfs"));
+        assert!(is_virtual_filesystem("/mnt/ram", "TMPFS"));
+        assert!(!is_virtual_filesystem("/mnt/layer", "overlayfs2"));
+        assert!(!is_virtual_filesystem("/home", "fuse-overlayfs"));
+        assert!(!is_virtual_filesystem("/mnt/proc_data", "procfs_backup"));
+    }
+
+    #[test]
+    fn exclusion_lists_can_be_overridden() {
+        let none: [&str; 0] = [];
+        assert!(!is_excluded_mount("/run/user/1000", "tmpfs", &none, &none));
+        assert!(!is_excluded_mount("/run/user/1000", "tmpfs", &["/proc", "/sys"], &none));
+        assert!(is_excluded_mount("/data/scratch", "ext4", &["/data/"], &none));
+        assert!(is_excluded_mount("/anything", "ext4", &["/"], &none));
+        assert!(is_excluded_mount("/home", "zfs", &none, &["zfs"]));
+    }
+
+    #[test]
+    fn varchar_list_rendering_round_trips() {
+        assert_eq!(parse_varchar_list("[]"), Vec::<String>::new());
+        assert_eq!(parse_varchar_list("[tmpfs, overlay]"), vec!["tmpfs", "overlay"]);
+        assert_eq!(parse_varchar_list("['/mnt/a, b', '', NULL, 'NULL']"), vec!["/mnt/a, b", "", "NULL"]);
+        assert_eq!(parse_varchar_list(r"['it\'s', 'back\\slash']"), vec!["it's", r"back\slash"]);
+    }
+
+    #[test]
+    fn varchar_list_elements_with_commas_and_brackets() {
+        assert_eq!(parse_varchar_list("['/mnt/a, b', '/mnt/[c]', 'd]']"), vec!["/mnt/a, b", "/mnt/[c]", "d]"]);
+        assert_eq!(parse_varchar_list("['a,b,c', ',']"), vec!["a,b,c", ","]);
+        // Unquoted, a comma always separates elements (see parse_varchar_list)
+        assert_eq!(parse_varchar_list("[a, b]"), vec!["a", "b"]);
+    }
+
+    /// Unmounts and removes the scratch loopback filesystem even if an assertion fails
+    #[cfg(target_os = "linux")]
+    struct ScratchMount(std::path::PathBuf);
+
+    #[cfg(target_os = "linux")]
+    impl Drop for ScratchMount {
+        fn drop(&mut self) {
+            let _ = std::process::Command::new("umount").arg(self.0.join("mnt")).status();
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn mountinfo_read_only_comes_from_per_mount_options() {
+        let content = "\
+22 1 8:1 / / rw,relatime shared:1 - ext4 /dev/sda1 rw,errors=remount-ro
+35 22 7:0 / /mnt/scratch\\040disk ro,relatime shared:20 - ext4 /dev/loop0 rw
+36 22 8:1 /srv/data /data rw,relatime shared:1 - ext4 /dev/sda1 rw,errors=remount-ro
+";
+        let mounts = parse_mountinfo(content);
+        assert_eq!(mounts.len(), 3);
+        assert!(!mounts[0].is_readonly);
+        // A remount,ro flips the per-mount options while the superblock still says rw
+        assert_eq!((mounts[1].mount_point.as_str(), mounts[1].is_readonly), ("/mnt/scratch disk", true));
+        assert_eq!(mounts[1].options, vec!["ro", "relatime", "rw"]);
+        assert_eq!((mounts[2].is_readonly, mounts[2].is_bind_mount), (false, Some(true)));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[ignore = "mounts a loopback filesystem; run as root with --ignored"]
+    fn read_only_loopback_mount_is_reported() {
+        let run = |program: &str, args: &[&std::ffi::OsStr]| {
+            std::process::Command::new(program).args(args).output().is_ok_and(|output| output.status.success())
+        };
+        // Mounting needs root and loop devices; skip quietly where they are missing
+        if unsafe { libc::geteuid() } != 0 {
+            return;
+        }
+        let scratch = ScratchMount(std::env::temp_dir().join(format!("sazgar-ro-{}", std::process::id())));
+        let (image, mount_point) = (scratch.0.join("fs.img"), scratch.0.join("mnt"));
+        std::fs::create_dir_all(&mount_point).unwrap();
+        std::fs::File::create(&image).unwrap().set_len(4 << 20).unwrap();
+        if !run("mkfs.ext4", &["-q".as_ref(), "-F".as_ref(), image.as_os_str()])
+            || !run("mount", &["-o".as_ref(), "loop".as_ref(), image.as_os_str(), mount_point.as_os_str()])
+        {
+            return;
+        }
+        
+        let mount_point_str = mount_point.to_string_lossy().to_string();
+        let find = || read_mounts().into_iter().rfind(|mount| mount.mount_point == mount_point_str).unwrap();
+        assert!(!find().is_readonly);
+        
+        // Flipping to read-only, as the kernel does after an I/O error, shows on the next read
+        assert!(run("mount", &["-o".as_ref(), "remount,ro".as_ref(), mount_point.as_os_str()]));
+        let mount = find();
+        assert!(mount.is_readonly);
+        assert!(mount.options.iter().any(|option| option == "ro"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn proc_limits_map_unlimited_to_none() {
+        let content = "\
+Limit                     Soft Limit           Hard Limit           Units     
+Max cpu time              unlimited            unlimited            seconds   
+Max stack size            8388608              unlimited            bytes     
+Max open files            1024                 524288               files     
+Max nice priority         0                    0                    
+";
+        let limits = parse_proc_limits(content);
+        assert_eq!(limits.len(), 4);
+        assert_eq!(limits[0].resource, "Max cpu time");
+        assert_eq!((limits[0].soft_limit, limits[0].hard_limit), (None, None));
+        assert_eq!((limits[1].soft_limit, limits[1].hard_limit), (Some(8388608), None));
+        assert_eq!(limits[2].resource, "Max open files");
+        assert_eq!((limits[2].soft_limit, limits[2].hard_limit), (Some(1024), Some(524288)));
+        assert_eq!(limits[2].unit.as_deref(), Some("files"));
+        assert_eq!(limits[3].unit, None);
+    }
+
+    #[test]
+    fn host_report_has_a_fixed_schema() {
+        let report = host_report();
+        for key in ["hostname", "collected_at", "os", "cpu", "memory", "load", "process_count", "top_processes"] {
+            assert!(report.get(key).is_some(), "missing key {}", key);
+        }
+        assert!(report["top_processes"].as_array().is_some_and(|procs| procs.len() <= 5));
+        assert!(report["memory"]["total_bytes"].as_u64().is_some());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn proc_vmstat_keeps_unknown_counters() {
+        let content = "nr_dirty 42\npgmajfault 7\nnr_some_future_counter 18446744073709551615\nbogus\n";
+        let entries = parse_proc_vmstat(content);
+        let pairs: Vec<(&str, u64)> = entries.iter().map(|e| (e.key.as_str(), e.value)).collect();
+        assert_eq!(pairs, vec![("nr_dirty", 42), ("pgmajfault", 7), ("nr_some_future_counter", u64::MAX)]);
+    }
+
+    #[test]
+    fn mac_addresses_normalize_to_lowercase_colons() {
+        assert_eq!(normalize_mac_address("AA:BB:CC:0D:0E:0F").as_deref(), Some("aa:bb:cc:0d:0e:0f"));
+        assert_eq!(normalize_mac_address("aa-bb-cc-d-e-f").as_deref(), Some("aa:bb:cc:0d:0e:0f"));
+        assert_eq!(normalize_mac_address("00:00:00:00:00:00"), None);
+        assert_eq!(normalize_mac_address("FF:FF:FF:FF:FF:FF"), None);
+        assert_eq!(normalize_mac_address(""), None);
+        assert_eq!(normalize_mac_address("aa:bb:cc:dd:ee"), None);
+        assert_eq!(normalize_mac_address("aa:bb:cc:dd:ee:gg"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn proc_meminfo_converts_kb_to_bytes() {
+        let content = "MemTotal:       16318876 kB\nSReclaimable:     412000 kB\nHugePages_Total:       4\n";
+        let entries = parse_proc_meminfo(content);
+        assert_eq!(entries.len(), 3);
+        assert_eq!((entries[0].key.as_str(), entries[0].value, entries[0].is_bytes), ("MemTotal", 16318876 * 1024, true));
+        assert_eq!((entries[1].key.as_str(), entries[1].value), ("SReclaimable", 412000 * 1024));
+        assert_eq!((entries[2].key.as_str(), entries[2].value, entries[2].is_bytes), ("HugePages_Total", 4, false));
+    }
+
+    fn net_address(interface_name: &str, address: &str, prefix_len: u8) -> NetAddressInfo {
+        NetAddressInfo {
+            interface_name: interface_name.to_string(),
+            address: address.parse().unwrap(),
+            prefix_len: Some(prefix_len),
+            zone_index: None,
+            is_secondary: false,
+        }
+    }
+
+    #[test]
+    fn secondary_addresses_share_an_earlier_subnet() {
+        let mut addresses = vec![
+            net_address("eth0", "10.0.0.5", 24),
+            net_address("eth0", "10.0.0.6", 24),
+            net_address("eth0", "192.168.1.1", 24),
+            net_address("eth1", "10.0.0.7", 24),
+            net_address("eth0", "fe80::1", 64),
+            net_address("eth0", "fe80::2", 64),
+        ];
+        mark_secondary_addresses(&mut addresses);
+        let secondary: Vec<bool> = addresses.iter().map(|a| a.is_secondary).collect();
+        assert_eq!(secondary, vec![false, true, false, false, false, false]);
+    }
+
+    #[test]
+    fn address_scopes_follow_ip_addr() {
+        let scope = |address: &str| address_scope(&address.parse().unwrap());
+        assert_eq!(scope("127.0.0.1"), "host");
+        assert_eq!(scope("169.254.3.4"), "link");
+        assert_eq!(scope("10.1.2.3"), "global");
+        assert_eq!(scope("::1"), "host");
+        assert_eq!(scope("fe80::1"), "link");
+        assert_eq!(scope("febf::1"), "link");
+        assert_eq!(scope("fec0::1"), "global");
+        assert_eq!(scope("2001:db8::1"), "global");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn proc_swaps_parses_partitions_and_escaped_files() {
+        let content = "\
+Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority
+/dev/nvme0n1p3                          partition\t8388604\t\t1024\t\t-2
+/var/swap\\040file                       file\t\t1048572\t\t0\t\t10
+";
+        let devices = parse_proc_swaps(content);
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].filename, "/dev/nvme0n1p3");
+        assert_eq!(devices[0].swap_type, "partition");
+        assert_eq!((devices[0].size_bytes, devices[0].used_bytes, devices[0].priority), (8388604 * 1024, 1024 * 1024, -2));
+        assert_eq!(devices[1].filename, "/var/swap file");
+        assert_eq!((devices[1].swap_type.as_str(), devices[1].priority), ("file", 10));
+    }
+
+    #[test]
+    fn netstat_routes_expand_abbreviated_destinations() {
+        let output = "\
+Routing tables
+
+Internet:
+Destination        Gateway            Flags               Netif Expire
+default            192.168.1.1        UGScg                 en0       
+127                127.0.0.1          UCS                   lo0       
+192.168.1          link#4             UCS                   en0      !
+192.168.1.1/32     link#4             UCS                   en0      !
+
+Internet6:
+Destination                             Gateway                                 Flags               Netif Expire
+default                                 fe80::%utun0                            UGcIg               utun0       
+fe80::%lo0/64                           fe80::1%lo0                             UcI                   lo0       
+";
+        let routes = parse_netstat_routes(output);
+        let summary: Vec<(String, u8, String, &str)> = routes.iter()
+            .map(|r| (r.destination.clone(), r.prefix_len, r.gateway.clone(), r.scope))
+            .collect();
+        assert_eq!(summary, vec![
+            ("0.0.0.0".to_string(), 0, "192.168.1.1".to_string(), "global"),
+            ("127.0.0.0".to_string(), 8, "127.0.0.1".to_string(), "host"),
+            ("192.168.1.0".to_string(), 24, "0.0.0.0".to_string(), "link"),
+            ("192.168.1.1".to_string(), 32, "0.0.0.0".to_string(), "link"),
+            ("::".to_string(), 0, "fe80::".to_string(), "global"),
+            ("fe80::".to_string(), 64, "fe80::1".to_string(), "host"),
+        ]);
+        assert_eq!(routes[0].mask, "0.0.0.0");
+        assert_eq!(routes[2].mask, "255.255.255.0");
+        assert_eq!(routes[0].protocol, Some("static"));
+        assert_eq!(routes[4].address_family, "IPv6");
+    }
+
+    /// Encode one rtattr, padded to 4 bytes, as the kernel sends it
+    #[cfg(target_os = "linux")]
+    fn netlink_attribute(kind: u16, value: &[u8]) -> Vec<u8> {
+        let mut bytes = ((4 + value.len()) as u16).to_ne_bytes().to_vec();
+        bytes.extend(kind.to_ne_bytes());
+        bytes.extend(value);
+        bytes.resize((bytes.len() + 3) & !3, 0);
+        bytes
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn neighbor_messages_decode_state_and_attributes() {
+        fn message(state: u16, attributes: &[Vec<u8>]) -> Vec<u8> {
+            let mut bytes = vec![libc::AF_INET as u8, 0, 0, 0];
+            bytes.extend(3u32.to_ne_bytes());
+            bytes.extend(state.to_ne_bytes());
+            bytes.extend([0, 1]);
+            attributes.iter().for_each(|attribute| bytes.extend(attribute));
+            bytes
+        }
+        
+        let stale = message(libc::NUD_STALE, &[
+            netlink_attribute(libc::NDA_DST, &[192, 168, 1, 1]),
+            netlink_attribute(libc::NDA_LLADDR, &[0xaa, 0xbb, 0xcc, 0x0d, 0x0e, 0x0f]),
+        ]);
+        let (ifindex, entry) = parse_neighbor_message(&stale).unwrap();
+        assert_eq!(ifindex, 3);
+        assert_eq!(entry.ip_address, "192.168.1.1");
+        assert_eq!(entry.mac_address.as_deref(), Some("aa:bb:cc:0d:0e:0f"));
+        assert_eq!((entry.state.as_str(), entry.is_permanent), ("stale", false));
+        
+        let mut v6 = [0u8; 16];
+        v6[0] = 0xfe;
+        v6[1] = 0x80;
+        v6[15] = 1;
+        let (_, entry) = parse_neighbor_message(&message(libc::NUD_INCOMPLETE, &[netlink_attribute(libc::NDA_DST, &v6)])).unwrap();
+        assert_eq!((entry.ip_address.as_str(), entry.mac_address, entry.state.as_str()), ("fe80::1", None, "incomplete"));
+        
+        assert!(parse_neighbor_message(&message(libc::NUD_NOARP, &[netlink_attribute(libc::NDA_DST, &[224, 0, 0, 1])])).is_none());
+        assert!(parse_neighbor_message(&message(libc::NUD_PERMANENT, &[])).is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn route_messages_decode_key_and_protocol() {
+        fn message(family: i32, prefix_len: u8, protocol: u8, attributes: &[Vec<u8>]) -> Vec<u8> {
+            let mut bytes = vec![family as u8, prefix_len, 0, 0, libc::RT_TABLE_MAIN, protocol, 0, libc::RTN_UNICAST];
+            bytes.extend(0u32.to_ne_bytes());
+            attributes.iter().for_each(|attribute| bytes.extend(attribute));
+            bytes
+        }
+        
+        let dhcp_default = message(libc::AF_INET, 0, 16, &[
+            netlink_attribute(libc::RTA_OIF, &2u32.to_ne_bytes()),
+            netlink_attribute(libc::RTA_PRIORITY, &100u32.to_ne_bytes()),
+        ]);
+        assert_eq!(parse_route_message(&dhcp_default), Some(NetlinkRoute {
+            destination: "0.0.0.0".parse().unwrap(),
+            prefix_len: 0,
+            output_ifindex: Some(2),
+            metric: 100,
+            table: u32::from(libc::RT_TABLE_MAIN),
+            protocol: "dhcp",
+        }));
+        
+        let mut v6 = [0u8; 16];
+        v6[0] = 0xfd;
+        let local = message(libc::AF_INET6, 64, libc::RTPROT_KERNEL, &[
+            netlink_attribute(libc::RTA_TABLE, &255u32.to_ne_bytes()),
+            netlink_attribute(libc::RTA_DST, &v6),
+        ]);
+        let route = parse_route_message(&local).unwrap();
+        assert_eq!((route.destination.to_string(), route.prefix_len, route.output_ifindex), ("fd00::".to_string(), 64, None));
+        assert_eq!((route.table, route.protocol), (255, "kernel"));
+        
+        assert!(parse_route_message(&message(libc::AF_INET, 24, 200, &[])).is_none());
+        assert!(parse_route_message(&message(libc::AF_PACKET, 0, libc::RTPROT_STATIC, &[])).is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn dmi_strings_name_the_hypervisor() {
+        let dmi = |strings: &[&str]| strings.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(classify_dmi_hypervisor(&dmi(&["QEMU", "Standard PC (Q35 + ICH9, 2009)"])), Some("qemu"));
+        assert_eq!(classify_dmi_hypervisor(&dmi(&["VMware, Inc.", "VMware7,1"])), Some("vmware"));
+        assert_eq!(classify_dmi_hypervisor(&dmi(&["Microsoft Corporation", "Virtual Machine"])), Some("microsoft"));
+        assert_eq!(classify_dmi_hypervisor(&dmi(&["Microsoft Corporation", "Surface Laptop 5"])), None);
+        assert_eq!(classify_dmi_hypervisor(&dmi(&["Dell Inc.", "PowerEdge R740"])), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn cgroup_paths_name_the_container_runtime() {
+        assert_eq!(classify_cgroup_container("12:memory:/docker/3f2a9c\n"), Some("docker"));
+        assert_eq!(classify_cgroup_container("0::/system.slice/docker-3f2a9c.scope\n"), Some("docker"));
+        assert_eq!(classify_cgroup_container("0::/kubepods/besteffort/pod1234/abcd\n"), Some("kubernetes"));
+        assert_eq!(classify_cgroup_container("0::/lxc.payload.web01\n"), Some("lxc"));
+        assert_eq!(classify_cgroup_container("0::/init.scope\n"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolv_conf_keeps_the_last_search_line() {
+        let content = "# generated\nnameserver 10.0.0.2\ndomain old.example\nsearch corp.example example.com\nnameserver 10.0.0.3\n; nameserver 10.0.0.9\noptions edns0 ndots:5\n";
+        let resolv = parse_resolv_conf(content);
+        assert_eq!(resolv.nameservers, vec!["10.0.0.2", "10.0.0.3"]);
+        assert_eq!((resolv.search_keyword, resolv.search.as_slice()), ("search", ["corp.example", "example.com"].map(String::from).as_slice()));
+        assert_eq!(resolv.options, vec!["edns0", "ndots:5"]);
+        assert_eq!(resolv.ndots(), Some(5));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn resolvectl_output_maps_servers_to_links() {
+        let dns = "Global: 1.1.1.1#cloudflare-dns.com [2606:4700::1111]:853\nLink 2 (eth0): 192.168.1.1 fe80::1%eth0\nLink 3 (docker0):\n";
+        let domain = "Global:\nLink 2 (eth0): lan ~.\nLink 3 (docker0):\n";
+        let servers = parse_resolvectl(dns, domain, Some(1));
+        let summary: Vec<(&str, u16, Option<&str>, Vec<String>)> = servers.iter()
+            .map(|s| (s.nameserver.as_str(), s.port, s.interface.as_deref(), s.search_domains.clone()))
+            .collect();
+        assert_eq!(summary, vec![
+            ("1.1.1.1", 53, None, vec![]),
+            ("2606:4700::1111", 853, None, vec![]),
+            ("192.168.1.1", 53, Some("eth0"), vec!["lan".to_string()]),
+            ("fe80::1%eth0", 53, Some("eth0"), vec!["lan".to_string()]),
+        ]);
+        assert!(servers.iter().all(|s| s.source == "systemd-resolved"));
+    }
+
+    #[test]
+    fn scutil_dns_skips_the_scoped_section() {
+        let output = "\
+DNS configuration
+
+resolver #1
+  search domain[0] : lan
+  nameserver[0] : 192.168.1.1
+  nameserver[1] : fe80::1%en0
+  if_index : 6 (en0)
+  flags    : Request A records
+
+resolver #2
+  domain   : local
+  options  : mdns
+
+DNS configuration (for scoped queries)
+
+resolver #1
+  nameserver[0] : 192.168.1.1
+  if_index : 6 (en0)
+";
+        let servers = parse_scutil_dns(output);
+        assert_eq!(servers.len(), 2);
+        assert_eq!(servers[0].nameserver, "192.168.1.1");
+        assert_eq!(servers[0].search_domains, vec!["lan"]);
+        assert_eq!(servers[0].interface.as_deref(), Some("en0"));
+        assert_eq!(servers[1].nameserver, "fe80::1%en0");
+        assert_eq!((servers[1].port, servers[1].source), (53, "scutil"));
+    }
+
+    #[test]
+    fn ioreg_output_yields_the_platform_uuid() {
+        let output = "+-o J314sAP  <class IOPlatformExpertDevice>\n    {\n      \"IOPlatformSerialNumber\" = \"C02XYZ\"\n      \"IOPlatformUUID\" = \"564D2A3C-1B2F-4C3D-9E8F-0A1B2C3D4E5F\"\n    }\n";
+        assert_eq!(parse_ioreg_platform_uuid(output).as_deref(), Some("564D2A3C-1B2F-4C3D-9E8F-0A1B2C3D4E5F"));
+        assert_eq!(parse_ioreg_platform_uuid("{}\n"), None);
+    }
+
+    #[test]
+    fn smbios_type_1_uuid_is_mixed_endian() {
+        // Type 0 (BIOS) with one string, then type 1 (System), then end-of-table
+        let mut table = vec![0, 4, 0, 0, b'A', 0, 0];
+        let mut system = vec![1, 0x19, 1, 0, 0, 0, 0, 0];
+        system.extend([0x3c, 0x2a, 0x4d, 0x56, 0x2f, 0x1b, 0x3d, 0x4c, 0x9e, 0x8f, 0x0a, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f]);
+        system.push(6);
+        system.extend([0, 0]);
+        table.extend(system);
+        table.extend([127, 4, 2, 0, 0, 0]);
+        assert_eq!(parse_smbios_system_uuid(&table).as_deref(), Some("564d2a3c-1b2f-4c3d-9e8f-0a1b2c3d4e5f"));
+        
+        let mut unset = vec![1, 0x19, 1, 0, 0, 0, 0, 0];
+        unset.extend([0xff; 16]);
+        unset.extend([6, 0, 0]);
+        assert_eq!(parse_smbios_system_uuid(&unset), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn cpuinfo_features_are_read_per_processor() {
+        let x86 = "processor\t: 0\nflags\t\t: fpu sse4_2 avx2\n\nprocessor\t: 1\nflags\t\t: fpu sse4_2\n\n";
+        assert_eq!(parse_cpuinfo_features(x86), vec![vec!["fpu", "sse4_2", "avx2"], vec!["fpu", "sse4_2"]]);
+        let arm = "processor\t: 0\nBogoMIPS\t: 48.00\nFeatures\t: fp asimd aes crc32\n";
+        assert_eq!(parse_cpuinfo_features(arm), vec![vec!["fp", "asimd", "aes", "crc32"]]);
+    }
+
+    #[test]
+    fn echo_request_checksum_verifies() {
+        let request = build_echo_request(false, 0x1234, 7);
+        assert_eq!(&request[..2], &[8, 0]);
+        assert_eq!(&request[4..8], &[0x12, 0x34, 0, 7]);
+        // Summing a packet that includes its own checksum gives zero
+        assert_eq!(icmp_checksum(&request), 0);
+        assert_eq!(build_echo_request(true, 1, 1)[..4], [128, 0, 0, 0]);
+    }
+
+    #[test]
+    fn echo_replies_parse_with_and_without_ip_header() {
+        let mut reply = build_echo_request(false, 0x1234, 7);
+        reply[0] = 0;
+        assert_eq!(parse_echo_reply(&reply, false, false), Some((0x1234, 7, None)));
+        
+        let mut with_header = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 57, 1, 0, 0, 10, 0, 0, 1, 10, 0, 0, 2];
+        with_header.extend(&reply);
+        assert_eq!(parse_echo_reply(&with_header, false, true), Some((0x1234, 7, Some(57))));
+        
+        // An echo request looped back to us is not a reply
+        assert_eq!(parse_echo_reply(&build_echo_request(false, 0x1234, 7), false, false), None);
+    }
+
+    #[test]
+    fn wifi_channels_and_frequencies_round_trip() {
+        for (frequency, channel, band) in [(2412, 1, 2.4), (2484, 14, 2.4), (5180, 36, 5.0), (5745, 149, 5.0), (5975, 5, 6.0)] {
+            assert_eq!(wifi_frequency_to_channel(frequency), Some(channel));
+            assert_eq!(wifi_channel_to_frequency(channel, Some(band)), Some(frequency));
+        }
+        assert_eq!(wifi_channel_to_frequency(36, None), Some(5180));
+        assert_eq!(wifi_signal_percent(-52), 96.0);
+        assert_eq!(wifi_signal_percent(-105), 0.0);
+    }
+
+    #[test]
+    fn iw_link_and_scan_describe_the_association() {
+        let link = "Connected to AA:BB:CC:DD:EE:FF (on wlan0)\n\tSSID: Home Net\n\tfreq: 5180.0\n\tRX: 1234 bytes (10 packets)\n\tsignal: -52 dBm\n\trx bitrate: 866.7 MBit/s VHT-MCS 9\n\ttx bitrate: 433.3 MBit/s VHT-MCS 9 80MHz short GI\n";
+        let mut info = WifiInfo::default();
+        parse_iw_link(link, &mut info);
+        assert_eq!(info.bssid.as_deref(), Some("aa:bb:cc:dd:ee:ff"));
+        assert_eq!(info.ssid.as_deref(), Some("Home Net"));
+        assert_eq!((info.frequency_mhz, info.signal_dbm, info.tx_rate_mbps), (Some(5180), Some(-52), Some(433.3)));
+        
+        let mut idle = WifiInfo::default();
+        parse_iw_link("Not connected.\n", &mut idle);
+        assert!(idle.ssid.is_none() && idle.bssid.is_none());
+        
+        let scan = "BSS 11:22:33:44:55:66(on wlan0)\n\tcapability: ESS Privacy (0x0011)\n\tWPA:\t * Version: 1\n\
+BSS aa:bb:cc:dd:ee:ff(on wlan0) -- associated\n\tcapability: ESS Privacy ShortSlotTime (0x0411)\n\tRSN:\t * Version: 1\n\t\t * Authentication suites: PSK SAE\n\
+BSS 99:88:77:66:55:44(on wlan0)\n\tcapability: ESS (0x0001)\n";
+        assert_eq!(parse_iw_scan_security(scan, "aa:bb:cc:dd:ee:ff").as_deref(), Some("wpa2-wpa3"));
+        assert_eq!(parse_iw_scan_security(scan, "11:22:33:44:55:66").as_deref(), Some("wpa"));
+        assert_eq!(parse_iw_scan_security(scan, "99:88:77:66:55:44").as_deref(), Some("open"));
+        assert_eq!(parse_iw_scan_security(scan, "00:00:00:00:00:01"), None);
+    }
+
+    #[test]
+    fn airport_report_keeps_wireless_interfaces_only() {
+        let report: serde_json::Value = serde_json::from_str(r#"{"SPAirPortDataType": [{"spairport_airport_interfaces": [
+            {"_name": "en0", "spairport_status_information": "spairport_status_connected",
+             "spairport_current_network_information": {"_name": "Office", "spairport_network_channel": "149 (5GHz, 80MHz)",
+              "spairport_network_rate": 866, "spairport_security_mode": "spairport_security_mode_wpa2_personal",
+              "spairport_signal_noise": "-58 dBm / -92 dBm"}},
+            {"_name": "en1", "spairport_status_information": "spairport_status_disconnected"},
+            {"_name": "awdl0"}
+        ]}]}"#).unwrap();
+        let wifi = parse_airport_json(&report);
+        assert_eq!(wifi.len(), 2);
+        assert_eq!((wifi[0].ssid.as_deref(), wifi[0].channel, wifi[0].frequency_mhz), (Some("Office"), Some(149), Some(5745)));
+        assert_eq!((wifi[0].signal_dbm, wifi[0].security.as_deref()), (Some(-58), Some("wpa2")));
+        assert_eq!((wifi[1].interface.as_str(), wifi[1].ssid.as_deref()), ("en1", None));
+    }
+
+    fn test_port() -> PortInfo {
+        PortInfo {
+            protocol: "TCP".to_string(),
+            local_address: String::new(),
+            local_port: 0,
+            remote_address: String::new(),
+            remote_port: 0,
+            state: String::new(),
+            pid: None,
+            process_name: String::new(),
+            address_family: "IPv4",
+            all_pids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn socket_summary_counts_states_and_totals() {
+        let port = |protocol: &str, state: &str, address_family| PortInfo {
+            protocol: protocol.to_string(),
+            state: state.to_string(),
+            address_family,
+            ..test_port()
+        };
+        let ports = [
+            port("TCP", "Listen", "IPv4"),
+            port("TCP", "Listen", "IPv6"),
+            port("TCP", "Established", "IPv4"),
+            port("TCP", "TimeWait", "IPv4"),
+            port("UDP", UDP_SOCKET_STATE, "IPv6"),
+        ];
+        let summary = summarize_sockets(&ports);
+        let rows: Vec<(&str, &str, u64, u64)> = summary.iter()
+            .map(|s| (s.protocol.as_str(), s.state.as_str(), s.ipv4_count, s.ipv6_count))
+            .collect();
+        assert_eq!(rows, vec![
+            ("TCP", "Established", 1, 0),
+            ("TCP", "Listen", 1, 1),
+            ("TCP", "TimeWait", 1, 0),
+            ("UDP", "Bound", 0, 1),
+            ("TOTAL", "Listen", 1, 2),
+            ("TOTAL", "Established", 1, 0),
+        ]);
+    }
+
+    #[test]
+    fn connections_group_by_remote_busiest_first() {
+        let connection = |remote: &str, local_port, state: &str, process: &str| PortInfo {
+            remote_address: remote.to_string(),
+            local_port,
+            state: state.to_string(),
+            process_name: process.to_string(),
+            ..test_port()
+        };
+        let ports = [
+            connection("10.0.0.5", 5432, "Established", "postgres"),
+            connection("10.0.0.5", 5432, "TimeWait", ""),
+            connection("10.0.0.5", 8080, "Established", "api"),
+            connection("10.0.0.9", 443, "Established", "nginx"),
+            connection("0.0.0.0", 22, "Listen", "sshd"),
+            connection("::", 0, "Close", ""),
+        ];
+        let remotes = group_connections_by_remote(&ports, 1);
+        assert_eq!(remotes.len(), 2);
+        let busiest = &remotes[0];
+        assert_eq!(busiest.remote_address, "10.0.0.5");
+        assert_eq!((busiest.connection_count, busiest.established_count, busiest.time_wait_count, busiest.distinct_local_ports), (3, 2, 1, 2));
+        assert_eq!(busiest.processes, vec!["api", "postgres"]);
+        assert_eq!(group_connections_by_remote(&ports, 2).len(), 1);
+    }
+
+    #[test]
+    fn port_filter_accepts_single_ports_and_ranges() {
+        assert_eq!(parse_port_filter("port", "5432"), Ok(5432..=5432));
+        assert_eq!(parse_port_filter("port", " 8000 - 8100 "), Ok(8000..=8100));
+        assert_eq!(parse_port_filter("port", "65535"), Ok(65535..=65535));
+        assert!(parse_port_filter("port", "0").unwrap_err().contains("between 1 and 65535"));
+        assert!(parse_port_filter("remote_port", "1-70000").unwrap_err().contains("remote_port"));
+        assert!(parse_port_filter("port", "8100-8000").is_err());
+        assert!(parse_port_filter("port", "http").is_err());
+    }
+
+    #[test]
+    fn protocol_filter_is_validated() {
+        assert_eq!(parse_protocol_filter("tcp"), Ok(Some("TCP".to_string())));
+        assert_eq!(parse_protocol_filter("\"UDP\""), Ok(Some("UDP".to_string())));
+        assert_eq!(parse_protocol_filter(""), Ok(None));
+        assert!(parse_protocol_filter("tpc").unwrap_err().contains("'tpc'"));
+    }
+
+    #[test]
+    fn docker_cli_labels_decode_json() {
+        let docker = parse_docker_cli_labels(r#""com.example.hosts=a.example,b.example,tier=web,quote=say \"hi\" & go""#);
+        assert_eq!(docker, vec![
+            ("com.example.hosts".to_string(), "a.example,b.example".to_string()),
+            ("tier".to_string(), "web".to_string()),
+            ("quote".to_string(), "say \"hi\" & go".to_string()),
+        ]);
+        let podman = parse_docker_cli_labels(r#"{"hosts":"a.example,b=c","tier":"web"}"#);
+        assert_eq!(podman, vec![
+            ("hosts".to_string(), "a.example,b=c".to_string()),
+            ("tier".to_string(), "web".to_string()),
+        ]);
+        assert!(parse_docker_cli_labels(r#""""#).is_empty());
+        assert!(parse_docker_cli_labels("null").is_empty());
+        
+        let labels = vec![
+            ("z".to_string(), "tab\there \u{1}".to_string()),
+            ("a".to_string(), "say \"hi\" 🐳".to_string()),
+        ];
+        assert_eq!(labels_to_json(&labels), r#"{"a":"say \"hi\" 🐳","z":"tab\there \u0001"}"#);
+        assert_eq!(parse_docker_cli_labels(&labels_to_json(&labels)), vec![labels[1].clone(), labels[0].clone()]);
+    }
+
+    #[test]
+    fn globs_share_one_dialect() {
+        let glob = |pattern: &str| compile_glob("test", pattern).unwrap();
+        assert!(glob("veth*").is_match("veth0a1b"));
+        assert!(!glob("veth*").is_match("eth0"));
+        assert!(glob("eth?").is_match("eth0") && !glob("eth?").is_match("eth10"));
+        assert!(glob("*.{log,gz}").is_match("syslog.gz"));
+        assert!(glob("tty[0-9]").is_match("tty3") && !glob("tty[!0-9]").is_match("tty3"));
+        assert!(glob(r"a\*b").is_match("a*b") && !glob(r"a\*b").is_match("axb"));
+        assert!(!glob("PATH").is_match("path"));
+        assert!(compile_glob("sazgar_files", "[").unwrap_err().starts_with("sazgar_files: invalid pattern '['"));
+    }
+
+    #[test]
+    fn list_units_lines_drop_the_failure_marker() {
+        let unit = parse_list_units_line("● flaky.service     loaded failed failed Flaky Worker").unwrap();
+        assert_eq!(unit.unit, "flaky.service");
+        assert_eq!((unit.load_state.as_str(), unit.active_state.as_str(), unit.sub_state.as_str()), ("loaded", "failed", "failed"));
+        assert_eq!(unit.description, "Flaky Worker");
+        
+        let unit = parse_list_units_line("* gone.service not-found inactive dead gone.service").unwrap();
+        assert_eq!((unit.unit.as_str(), unit.load_state.as_str()), ("gone.service", "not-found"));
+        
+        let unit = parse_list_units_line("  ssh.service loaded active running OpenBSD Secure Shell server").unwrap();
+        assert_eq!((unit.unit.as_str(), unit.description.as_str()), ("ssh.service", "OpenBSD Secure Shell server"));
+        assert!(parse_list_units_line("●").is_none());
+    }
+}